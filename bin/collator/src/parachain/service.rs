@@ -18,7 +18,7 @@
 
 //! Parachain Service and ServiceFactory implementation.
 
-use astar_primitives::*;
+use astar_primitives::{dapp_staking::SmartContract, *};
 use cumulus_client_cli::CollatorOptions;
 use cumulus_client_consensus_aura::collators::lookahead::{self as aura, Params as AuraParams};
 use cumulus_client_consensus_common::ParachainBlockImport;
@@ -281,6 +281,8 @@ where
         + sp_block_builder::BlockBuilder<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_v3_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + price_aggregator_rpc::PriceAggregatorRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>
@@ -600,6 +602,8 @@ where
         + sp_block_builder::BlockBuilder<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_v3_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + price_aggregator_rpc::PriceAggregatorRuntimeApi<Block>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
@@ -18,6 +18,7 @@
 
 //! Astar RPCs implementation.
 
+use dapp_staking_v3_rpc::{DappStaking, DappStakingApiServer};
 use fc_rpc::{
     Eth, EthApiServer, EthBlockDataCacheTask, EthFilter, EthFilterApiServer, EthPubSub,
     EthPubSubApiServer, Net, NetApiServer, Web3, Web3ApiServer,
@@ -26,6 +27,7 @@ use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use fc_storage::StorageOverride;
 use jsonrpsee::RpcModule;
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+use price_aggregator_rpc::{PriceAggregator, PriceAggregatorApiServer};
 
 use sc_client_api::{
     AuxStore, Backend, BlockchainEvents, StateBackend, StorageProvider, UsageProvider,
@@ -54,7 +56,7 @@ use moonbeam_rpc_trace::{Trace, TraceServer};
 #[cfg(feature = "evm-tracing")]
 use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
-use astar_primitives::*;
+use astar_primitives::{dapp_staking::SmartContract, *};
 
 #[cfg(feature = "evm-tracing")]
 pub mod tracing;
@@ -168,6 +170,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_v3_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + price_aggregator_rpc::PriceAggregatorRuntimeApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -233,6 +237,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_v3_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + price_aggregator_rpc::PriceAggregatorRuntimeApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -270,6 +276,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_v3_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + price_aggregator_rpc::PriceAggregatorRuntimeApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -302,6 +310,8 @@ where
 
     io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(DappStaking::new(client.clone()).into_rpc())?;
+    io.merge(PriceAggregator::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
     #[cfg(feature = "manual-seal")]
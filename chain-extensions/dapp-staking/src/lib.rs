@@ -0,0 +1,308 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{
+    dapp_staking::{EraNumber, SmartContractHandle},
+    Balance,
+};
+pub use dapp_staking_chain_extension_types::Command::{self, *};
+use dapp_staking_chain_extension_types::{
+    handle_result, Outcome, ProtocolState as ProtocolStateInfo, Subperiod as SubperiodInfo,
+    LOG_TARGET,
+};
+use frame_support::{
+    dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
+    traits::Get,
+    DefaultNoBound,
+};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+    BufInBufOutState, ChainExtension, ChargedAmount, Environment, Ext, InitState, RetVal,
+};
+use pallet_dapp_staking_v3::{
+    ActiveProtocolState, IntegratedDApps, Ledger, Pallet as DappStaking, StakerInfo, Subperiod,
+    WeightInfo,
+};
+use parity_scale_codec::Encode;
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+type Weight<T> = <T as pallet_dapp_staking_v3::Config>::WeightInfo;
+
+/// dApp Staking chain extension.
+#[derive(DefaultNoBound)]
+pub struct DappStakingExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for DappStakingExtension<T>
+where
+    T: pallet_dapp_staking_v3::Config + pallet_contracts::Config,
+{
+    fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env.func_id().try_into().map_err(|_| {
+            DispatchError::Other("Unsupported func id in dApp Staking Chain Extension")
+        })? {
+            Lock => {
+                let charged = env.charge_weight(
+                    Weight::<T>::lock_new_account().max(Weight::<T>::lock_existing_account()),
+                )?;
+
+                let amount: Balance = env.read_as()?;
+
+                log::trace!(target: LOG_TARGET, "lock: raw arguments: amount: {:?}", amount);
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::lock(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    amount,
+                );
+                refund_weight(&mut env, charged, &call_result);
+                handle_result!(call_result.map_err(|e| e.error));
+            }
+            Unlock => {
+                env.charge_weight(Weight::<T>::unlock())?;
+
+                let amount: Balance = env.read_as()?;
+
+                log::trace!(target: LOG_TARGET, "unlock: raw arguments: amount: {:?}", amount);
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::unlock(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    amount,
+                );
+                handle_result!(call_result);
+            }
+            ClaimUnlocked => {
+                let charged = env.charge_weight(
+                    pallet_dapp_staking_v3::Call::<T>::claim_unlocked {}
+                        .get_dispatch_info()
+                        .weight,
+                )?;
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_unlocked(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                );
+                refund_weight(&mut env, charged, &call_result);
+                handle_result!(call_result.map_err(|e| e.error));
+            }
+            Stake => {
+                let (contract, amount): (T::AccountId, Balance) = env.read_as()?;
+
+                let charged = env.charge_weight(
+                    pallet_dapp_staking_v3::Call::<T>::stake {
+                        smart_contract: T::SmartContract::wasm(contract.clone()),
+                        amount,
+                    }
+                    .get_dispatch_info()
+                    .weight,
+                )?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "stake: raw arguments: contract: {:?}, amount: {:?}",
+                    contract,
+                    amount
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::stake(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    T::SmartContract::wasm(contract),
+                    amount,
+                );
+                refund_weight(&mut env, charged, &call_result);
+                handle_result!(call_result.map_err(|e| e.error));
+            }
+            Unstake => {
+                let (contract, amount): (T::AccountId, Balance) = env.read_as()?;
+
+                let charged = env.charge_weight(
+                    pallet_dapp_staking_v3::Call::<T>::unstake {
+                        smart_contract: T::SmartContract::wasm(contract.clone()),
+                        amount,
+                    }
+                    .get_dispatch_info()
+                    .weight,
+                )?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "unstake: raw arguments: contract: {:?}, amount: {:?}",
+                    contract,
+                    amount
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::unstake(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    T::SmartContract::wasm(contract),
+                    amount,
+                );
+                refund_weight(&mut env, charged, &call_result);
+                handle_result!(call_result.map_err(|e| e.error));
+            }
+            ClaimStakerRewards => {
+                let charged = env.charge_weight(
+                    pallet_dapp_staking_v3::Call::<T>::claim_staker_rewards {}
+                        .get_dispatch_info()
+                        .weight,
+                )?;
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_staker_rewards(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                );
+                refund_weight(&mut env, charged, &call_result);
+                handle_result!(call_result.map_err(|e| e.error));
+            }
+            ClaimBonusReward => {
+                let contract: T::AccountId = env.read_as()?;
+
+                let charged = env.charge_weight(
+                    pallet_dapp_staking_v3::Call::<T>::claim_bonus_reward {
+                        smart_contract: T::SmartContract::wasm(contract.clone()),
+                    }
+                    .get_dispatch_info()
+                    .weight,
+                )?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "claim_bonus_reward: raw arguments: contract: {:?}",
+                    contract
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_bonus_reward(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    T::SmartContract::wasm(contract),
+                );
+                refund_weight(&mut env, charged, &call_result);
+                handle_result!(call_result.map_err(|e| e.error));
+            }
+            ClaimDappReward => {
+                env.charge_weight(Weight::<T>::claim_dapp_reward())?;
+
+                let (contract, era): (T::AccountId, EraNumber) = env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "claim_dapp_reward: raw arguments: contract: {:?}, era: {:?}",
+                    contract,
+                    era
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_dapp_reward(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    T::SmartContract::wasm(contract),
+                    era,
+                );
+                handle_result!(call_result);
+            }
+            ProtocolState => {
+                env.charge_weight(T::DbWeight::get().reads(1_u64))?;
+
+                let protocol_state = ActiveProtocolState::<T>::get();
+                let subperiod = match protocol_state.subperiod() {
+                    Subperiod::Voting => SubperiodInfo::Voting,
+                    Subperiod::BuildAndEarn => SubperiodInfo::BuildAndEarn,
+                };
+
+                ProtocolStateInfo {
+                    era: protocol_state.era,
+                    period: protocol_state.period_number(),
+                    subperiod,
+                }
+                .using_encoded(|r| env.write(r, false, None))?;
+            }
+            LockedAmount => {
+                env.charge_weight(T::DbWeight::get().reads(1_u64))?;
+
+                let account: T::AccountId = env.read_as()?;
+
+                Ledger::<T>::get(&account)
+                    .active_locked_amount()
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            StakedAmount => {
+                env.charge_weight(T::DbWeight::get().reads(2_u64))?;
+
+                let account: T::AccountId = env.read_as()?;
+
+                // Past period stakes are reset to zero.
+                let current_period = ActiveProtocolState::<T>::get().period_number();
+
+                Ledger::<T>::get(&account)
+                    .staked_amount(current_period)
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            StakedAmountOnContract => {
+                env.charge_weight(T::DbWeight::get().reads(3_u64))?;
+
+                let (account, contract): (T::AccountId, T::AccountId) = env.read_as()?;
+                let smart_contract =
+                    DappStaking::<T>::resolve_smart_contract(T::SmartContract::wasm(contract));
+
+                let current_period = ActiveProtocolState::<T>::get().period_number();
+                let staked_amount = match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(staking_info) if staking_info.period_number() == current_period => {
+                        staking_info.total_staked_amount()
+                    }
+                    _ => Balance::default(),
+                };
+
+                staked_amount.using_encoded(|r| env.write(r, false, None))?;
+            }
+            ContractStake => {
+                env.charge_weight(T::DbWeight::get().reads(4_u64))?;
+
+                let contract: T::AccountId = env.read_as()?;
+                let smart_contract =
+                    DappStaking::<T>::resolve_smart_contract(T::SmartContract::wasm(contract));
+
+                let current_period = ActiveProtocolState::<T>::get().period_number();
+                let staked_amount = match IntegratedDApps::<T>::get(&smart_contract) {
+                    Some(dapp_info) => {
+                        pallet_dapp_staking_v3::ContractStake::<T>::get(&dapp_info.id)
+                            .total_staked_amount(current_period)
+                    }
+                    None => Balance::default(),
+                };
+
+                staked_amount.using_encoded(|r| env.write(r, false, None))?;
+            }
+        }
+
+        Ok(RetVal::Converging(Outcome::Success as u32))
+    }
+}
+
+/// Refund the difference between the charged weight and the actual weight of the call, if it's known.
+fn refund_weight<E: Ext>(
+    env: &mut Environment<E, BufInBufOutState>,
+    charged: ChargedAmount,
+    call_result: &DispatchResultWithPostInfo,
+) {
+    let actual_weight = match call_result {
+        Ok(post_info) => post_info.actual_weight,
+        Err(e) => e.post_info.actual_weight,
+    };
+    if let Some(actual_weight) = actual_weight {
+        env.adjust_weight(charged, actual_weight);
+    }
+}
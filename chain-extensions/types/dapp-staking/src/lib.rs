@@ -0,0 +1,197 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-dapp-staking";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Lock the given amount for dApp staking
+    Lock = 0,
+    /// Start unlocking the given amount
+    Unlock = 1,
+    /// Claim the unlocked chunks which have finished unlocking
+    ClaimUnlocked = 2,
+    /// Stake the given amount on a smart contract
+    Stake = 3,
+    /// Unstake the given amount from a smart contract
+    Unstake = 4,
+    /// Claim the staker rewards
+    ClaimStakerRewards = 5,
+    /// Claim the bonus reward for a smart contract
+    ClaimBonusReward = 6,
+    /// Claim the dApp reward for a smart contract, for the given era
+    ClaimDappReward = 7,
+    /// Get the current protocol state
+    ProtocolState = 8,
+    /// Get the amount locked by an account, excluding the unlocking chunks
+    LockedAmount = 9,
+    /// Get the amount staked by an account in the ongoing period
+    StakedAmount = 10,
+    /// Get the amount staked by an account on a smart contract in the ongoing period
+    StakedAmountOnContract = 11,
+    /// Get the total amount staked on a smart contract in the ongoing period
+    ContractStake = 12,
+}
+
+/// Subperiod of a dApp staking period.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Subperiod {
+    /// Voting subperiod, stakers vote for dApps.
+    #[codec(index = 0)]
+    Voting,
+    /// Build&Earn subperiod, dApps earn rewards based on the stake.
+    #[codec(index = 1)]
+    BuildAndEarn,
+}
+
+/// General information about the state of the dApp staking protocol.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ProtocolState {
+    /// Ongoing era number.
+    pub era: u32,
+    /// Ongoing period number.
+    pub period: u32,
+    /// Ongoing subperiod.
+    pub subperiod: Subperiod,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// Pallet is disabled/in maintenance mode.
+    Disabled = 1,
+    /// Smart contract doesn't exist.
+    ContractNotFound = 2,
+    /// Performing locking or staking with 0 amount.
+    ZeroAmount = 3,
+    /// Total locked amount for staker is below minimum threshold.
+    LockedAmountBelowThreshold = 4,
+    /// Account is not allowed to participate in dApp staking due to some external reason (e.g. account is already a collator).
+    AccountNotAvailableForDappStaking = 5,
+    /// Cannot add additional unlocking chunks due to capacity limit.
+    TooManyUnlockingChunks = 6,
+    /// Remaining stake prevents entire balance of starting the unlocking process.
+    RemainingStakePreventsFullUnlock = 7,
+    /// There are no eligible unlocked chunks to claim. This can happen either if no eligible chunks exist, or if user has no chunks at all.
+    NoUnlockedChunksToClaim = 8,
+    /// The amount being staked is too large compared to what's available for staking.
+    UnavailableStakeFunds = 9,
+    /// There are unclaimed rewards remaining from past eras or periods. They should be claimed before attempting any stake modification again.
+    UnclaimedRewards = 10,
+    /// Total staked amount on contract is below the minimum required value.
+    InsufficientStakeAmount = 11,
+    /// Stake operation is rejected since period ends in the next era.
+    PeriodEndsInNextEra = 12,
+    /// Unstaking is rejected since the period in which past stake was active has passed.
+    UnstakeFromPastPeriod = 13,
+    /// Unstake amount is greater than the staked amount.
+    UnstakeAmountTooLarge = 14,
+    /// Account has no staking information for the contract.
+    NoStakingInfo = 15,
+    /// The era for which rewards are being claimed has expired.
+    RewardExpired = 16,
+    /// Reward payout has failed due to an unexpected reason.
+    RewardPayoutFailed = 17,
+    /// There are no claimable rewards.
+    NoClaimableRewards = 18,
+    /// Account is not eligible for bonus rewards.
+    NotEligibleForBonusReward = 19,
+    /// Claim era is invalid - it must be in history, and rewards must exist for it.
+    InvalidClaimEra = 20,
+    /// No dApp tier info exists for the specified era. This can be because era has expired
+    /// or because during the specified era there were no eligible rewards or protocol wasn't active.
+    NoDAppTierInfo = 21,
+    /// There are too many contract stake entries for the account.
+    TooManyStakedContracts = 22,
+    /// Account has no locked funds in dApp staking.
+    NoLockedFunds = 23,
+    /// Stake cannot be moved to the same smart contract it's moved from.
+    SameSourceAndDestination = 24,
+    /// Maximum number of contract aliases for the dApp has been reached.
+    TooManyContractAliases = 26,
+    /// Specified contract alias doesn't exist for the dApp.
+    ContractAliasNotFound = 27,
+    /// Claim bounty rate exceeds the maximum allowed rate.
+    ClaimBountyTooLarge = 28,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("Disabled") => Outcome::Disabled,
+            Some("ContractNotFound") => Outcome::ContractNotFound,
+            Some("ZeroAmount") => Outcome::ZeroAmount,
+            Some("LockedAmountBelowThreshold") => Outcome::LockedAmountBelowThreshold,
+            Some("AccountNotAvailableForDappStaking") => Outcome::AccountNotAvailableForDappStaking,
+            Some("TooManyUnlockingChunks") => Outcome::TooManyUnlockingChunks,
+            Some("RemainingStakePreventsFullUnlock") => Outcome::RemainingStakePreventsFullUnlock,
+            Some("NoUnlockedChunksToClaim") => Outcome::NoUnlockedChunksToClaim,
+            Some("UnavailableStakeFunds") => Outcome::UnavailableStakeFunds,
+            Some("UnclaimedRewards") => Outcome::UnclaimedRewards,
+            Some("InsufficientStakeAmount") => Outcome::InsufficientStakeAmount,
+            Some("PeriodEndsInNextEra") => Outcome::PeriodEndsInNextEra,
+            Some("UnstakeFromPastPeriod") => Outcome::UnstakeFromPastPeriod,
+            Some("UnstakeAmountTooLarge") => Outcome::UnstakeAmountTooLarge,
+            Some("NoStakingInfo") => Outcome::NoStakingInfo,
+            Some("RewardExpired") => Outcome::RewardExpired,
+            Some("RewardPayoutFailed") => Outcome::RewardPayoutFailed,
+            Some("NoClaimableRewards") => Outcome::NoClaimableRewards,
+            Some("NotEligibleForBonusReward") => Outcome::NotEligibleForBonusReward,
+            Some("InvalidClaimEra") => Outcome::InvalidClaimEra,
+            Some("NoDAppTierInfo") => Outcome::NoDAppTierInfo,
+            Some("TooManyStakedContracts") => Outcome::TooManyStakedContracts,
+            Some("NoLockedFunds") => Outcome::NoLockedFunds,
+            Some("SameSourceAndDestination") => Outcome::SameSourceAndDestination,
+            Some("TooManyContractAliases") => Outcome::TooManyContractAliases,
+            Some("ContractAliasNotFound") => Outcome::ContractAliasNotFound,
+            Some("ClaimBountyTooLarge") => Outcome::ClaimBountyTooLarge,
+            _ => Outcome::RuntimeError,
+        };
+    }
+}
+
+#[macro_export]
+macro_rules! handle_result {
+    ($call_result:expr) => {{
+        return match $call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                let mapped_error = Outcome::from(e);
+                Ok(RetVal::Converging(mapped_error as u32))
+            }
+            Ok(_) => Ok(RetVal::Converging(Outcome::Success as u32)),
+        };
+    }};
+}
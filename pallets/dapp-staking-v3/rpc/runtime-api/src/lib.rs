@@ -19,16 +19,163 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use astar_primitives::dapp_staking::{DAppId, EraNumber, PeriodNumber, RankedTier, TierId};
-use astar_primitives::BlockNumber;
+use astar_primitives::{Balance, BlockNumber};
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{Get, RuntimeDebug};
 pub use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec::Vec;
+
+pub use pallet_dapp_staking_v3::{
+    AccountLedger, ContractStakeAmount, DAppCategory, DAppMetadata, DAppPeriodSummary,
+    DAppTierRewards, ProtocolState, SingularStakingInfo, StakeAmount, StakerPreferences,
+    UnlockingChunk,
+};
+
+/// General info about an account's lock & stakes.
+///
+/// Mirrors the `AccountLedger` type, but without the bound on the number of unlocking chunks,
+/// since the bound differs between runtimes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountLedgerInfo {
+    /// How much active locked amount an account has. This can be used for staking.
+    pub locked: Balance,
+    /// Vector of all the unlocking chunks.
+    pub unlocking: Vec<UnlockingChunk>,
+    /// Primary field used to store how much was staked in a particular era.
+    pub staked: StakeAmount,
+    /// Secondary field used to store 'stake' information for the 'next era'.
+    pub staked_future: Option<StakeAmount>,
+    /// Number of contract stake entries in storage.
+    pub contract_stake_count: u32,
+    /// Whether claimed staker rewards are automatically re-staked.
+    pub auto_restake: bool,
+}
+
+impl AccountLedgerInfo {
+    /// Creates the ledger info from the account ledger and the staker preferences.
+    pub fn new<UnlockingLen: Get<u32>>(
+        ledger: AccountLedger<UnlockingLen>,
+        preferences: StakerPreferences,
+    ) -> Self {
+        Self {
+            locked: ledger.locked,
+            unlocking: ledger.unlocking.into_inner(),
+            staked: ledger.staked,
+            staked_future: ledger.staked_future,
+            contract_stake_count: ledger.contract_stake_count,
+            auto_restake: preferences.auto_restake,
+        }
+    }
+}
+
+/// Summary of dApp performance during a single period.
+///
+/// Mirrors the `DAppPeriodSummary` type, but without the bound on the number of tiers,
+/// since the bound differs between runtimes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DAppPeriodSummaryInfo {
+    /// Period to which the summary refers.
+    pub period: PeriodNumber,
+    /// Number of eras the dApp spent in each tier. First entry refers to the first tier, and so on.
+    pub eras_per_tier: Vec<EraNumber>,
+    /// Total dApp reward earned during the period.
+    pub reward: Balance,
+    /// Highest total amount staked on the dApp in any of the eras.
+    pub peak_stake: Balance,
+}
+
+impl<NumberOfTiers: Get<u32>> From<DAppPeriodSummary<NumberOfTiers>> for DAppPeriodSummaryInfo {
+    fn from(summary: DAppPeriodSummary<NumberOfTiers>) -> Self {
+        Self {
+            period: summary.period,
+            eras_per_tier: summary.eras_per_tier.into_inner(),
+            reward: summary.reward,
+            peak_stake: summary.peak_stake,
+        }
+    }
+}
+
+/// dApp tier assignment & tier rewards for an era.
+///
+/// Mirrors the `DAppTierRewards` type, but without the bounds on the number of dApps & tiers,
+/// since the bounds differ between runtimes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DAppTierRewardsInfo {
+    /// dApps and their corresponding ranked tiers.
+    pub dapps: BTreeMap<DAppId, RankedTier>,
+    /// Rewards for each tier. First entry refers to the first tier, and so on.
+    pub rewards: Vec<Balance>,
+    /// Period to which the tier assignment refers.
+    pub period: PeriodNumber,
+    /// Rank reward for each tier. First entry refers to the first tier, and so on.
+    pub rank_rewards: Vec<Balance>,
+}
+
+impl<MaxDApps: Get<u32>, NumberOfTiers: Get<u32>> From<DAppTierRewards<MaxDApps, NumberOfTiers>>
+    for DAppTierRewardsInfo
+{
+    fn from(dapp_tier_rewards: DAppTierRewards<MaxDApps, NumberOfTiers>) -> Self {
+        Self {
+            dapps: dapp_tier_rewards.dapps.into_inner(),
+            rewards: dapp_tier_rewards.rewards.into_inner(),
+            period: dapp_tier_rewards.period,
+            rank_rewards: dapp_tier_rewards.rank_rewards.into_inner(),
+        }
+    }
+}
+
+/// Metadata of a dApp.
+///
+/// Mirrors the `DAppMetadata` type, but without the bounds on the field lengths,
+/// since the bounds differ between runtimes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DAppMetadataInfo {
+    /// Name of the dApp.
+    pub name: Vec<u8>,
+    /// Website of the dApp.
+    pub website: Vec<u8>,
+    /// IPFS CID of additional dApp information.
+    pub ipfs_cid: Vec<u8>,
+    /// Category of the dApp.
+    pub category: DAppCategory,
+    /// Deposit reserved for storing the metadata.
+    pub deposit: Balance,
+}
+
+impl<AccountId, FieldLen, CidLen> From<DAppMetadata<AccountId, FieldLen, CidLen>>
+    for DAppMetadataInfo
+where
+    AccountId: sp_std::fmt::Debug + PartialEq + Eq + Clone,
+    FieldLen: Get<u32>,
+    CidLen: Get<u32>,
+{
+    fn from(metadata: DAppMetadata<AccountId, FieldLen, CidLen>) -> Self {
+        Self {
+            name: metadata.name.into_inner(),
+            website: metadata.website.into_inner(),
+            ipfs_cid: metadata.ipfs_cid.into_inner(),
+            category: metadata.category,
+            deposit: metadata.deposit,
+        }
+    }
+}
 
 sp_api::decl_runtime_apis! {
 
     /// dApp Staking Api.
     ///
     /// Used to provide information otherwise not available via RPC.
-    #[api_version(2)]
-    pub trait DappStakingApi {
+    #[api_version(8)]
+    pub trait DappStakingApi<AccountId, SmartContract>
+    where
+        AccountId: Codec,
+        SmartContract: Codec,
+    {
 
         /// How many periods are there in one cycle.
         fn periods_per_cycle() -> PeriodNumber;
@@ -48,5 +195,44 @@ sp_api::decl_runtime_apis! {
 
         /// Get dApp ranked tier assignment for the given dApp.
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier>;
+
+        /// Get the current protocol state.
+        fn protocol_state() -> ProtocolState;
+
+        /// Get the ledger of the specified account.
+        fn account_ledger(account: AccountId) -> AccountLedgerInfo;
+
+        /// Get the staking info of the specified account for the specified smart contract, if it exists.
+        fn staker_info(account: AccountId, smart_contract: SmartContract) -> Option<SingularStakingInfo>;
+
+        /// Get the stake info of the specified smart contract, if it's registered.
+        fn contract_stake(smart_contract: SmartContract) -> Option<ContractStakeAmount>;
+
+        /// Get the total staker reward amount the specified account would receive by claiming staker rewards.
+        fn claimable_staker_rewards(account: AccountId) -> Balance;
+
+        /// Get the claimable staker rewards of the specified account, per era, across all the required claim calls.
+        fn claimable_staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)>;
+
+        /// Get the claimable bonus reward of the specified account, per smart contract.
+        fn claimable_bonus_rewards(account: AccountId) -> Vec<(SmartContract, Balance)>;
+
+        /// Get the unclaimed rewards of the specified smart contract, per era.
+        fn claimable_dapp_rewards(smart_contract: SmartContract) -> Vec<(EraNumber, Balance)>;
+
+        /// Get the number of unique stakers of the specified smart contract, in the ongoing period.
+        fn contract_staker_count(smart_contract: SmartContract) -> u32;
+
+        /// Get the archived per-period performance summaries of the specified dApp, from the oldest to the latest one.
+        ///
+        /// dApp is identified by its Id, so the archive remains accessible after the dApp has been unregistered.
+        fn dapp_period_archive(dapp_id: DAppId) -> Vec<DAppPeriodSummaryInfo>;
+
+        /// Simulate the dApp tier assignment & rewards for the next era, as if the specified stake deltas were applied.
+        /// Positive delta increases the stake on the smart contract, while negative delta decreases it.
+        fn simulate_dapp_tier_assignment(stake_deltas: Vec<(SmartContract, i128)>) -> DAppTierRewardsInfo;
+
+        /// Get the metadata of the specified smart contract, if it exists.
+        fn dapp_metadata(smart_contract: SmartContract) -> Option<DAppMetadataInfo>;
     }
 }
@@ -0,0 +1,268 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! dApp staking JSON-RPC interface.
+//!
+//! Exposes the `dappStaking_*` methods, which are thin wrappers around the `DappStakingApi` runtime API.
+
+use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use parity_scale_codec::Codec;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use astar_primitives::{
+    dapp_staking::{DAppId, EraNumber, Rank, TierId},
+    Balance,
+};
+pub use dapp_staking_v3_runtime_api::{
+    AccountLedgerInfo, ContractStakeAmount, DappStakingApi as DappStakingRuntimeApi, ProtocolState,
+    SingularStakingInfo,
+};
+
+/// Tier & rank of a dApp, as returned by `dappStaking_tierAssignment`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct RankedTierInfo {
+    /// Tier into which the dApp was assigned.
+    pub tier: TierId,
+    /// Rank of the dApp within the tier.
+    pub rank: Rank,
+}
+
+#[rpc(server)]
+pub trait DappStakingApi<BlockHash, AccountId, SmartContract> {
+    /// Current protocol state - era, period, subperiod & maintenance mode.
+    #[method(name = "dappStaking_protocolState")]
+    fn protocol_state(&self, at: Option<BlockHash>) -> RpcResult<ProtocolState>;
+
+    /// Tier assignment the dApps would get if the era ended at the specified block.
+    #[method(name = "dappStaking_tierAssignment")]
+    fn tier_assignment(&self, at: Option<BlockHash>)
+        -> RpcResult<BTreeMap<DAppId, RankedTierInfo>>;
+
+    /// Lock & stake ledger of the specified account.
+    #[method(name = "dappStaking_accountLedger")]
+    fn account_ledger(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountLedgerInfo>;
+
+    /// Stake of the specified account on the specified smart contract.
+    #[method(name = "dappStaking_stakerInfo")]
+    fn staker_info(
+        &self,
+        account: AccountId,
+        smart_contract: SmartContract,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SingularStakingInfo>>;
+
+    /// Total stake on the specified smart contract.
+    #[method(name = "dappStaking_contractStake")]
+    fn contract_stake(
+        &self,
+        smart_contract: SmartContract,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ContractStakeAmount>>;
+
+    /// Amount the specified account would receive by claiming staker rewards.
+    #[method(name = "dappStaking_claimableStakerRewards")]
+    fn claimable_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Balance>;
+
+    /// Bonus rewards the specified account can claim, per smart contract.
+    #[method(name = "dappStaking_claimableBonusRewards")]
+    fn claimable_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(SmartContract, Balance)>>;
+
+    /// Unclaimed rewards of the specified smart contract, per era.
+    #[method(name = "dappStaking_claimableDappRewards")]
+    fn claimable_dapp_rewards(
+        &self,
+        smart_contract: SmartContract,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to query dApp staking state.
+pub struct DappStaking<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> DappStaking<C, Block> {
+    /// Create new `DappStaking` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error.
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        message.to_string(),
+        Some(format!("{:?}", e)),
+    )
+}
+
+impl<C, Block, AccountId, SmartContract>
+    DappStakingApiServer<<Block as BlockT>::Hash, AccountId, SmartContract>
+    for DappStaking<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappStakingRuntimeApi<Block, AccountId, SmartContract>,
+    AccountId: Codec + Send + Sync + for<'de> Deserialize<'de> + 'static,
+    SmartContract: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    fn protocol_state(&self, at: Option<Block::Hash>) -> RpcResult<ProtocolState> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .protocol_state(at)
+            .map_err(|e| runtime_error("Unable to query protocol state.", e))
+    }
+
+    fn tier_assignment(
+        &self,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<BTreeMap<DAppId, RankedTierInfo>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let assignment = self
+            .client
+            .runtime_api()
+            .get_dapp_tier_assignment(at)
+            .map_err(|e| runtime_error("Unable to query dApp tier assignment.", e))?;
+
+        Ok(assignment
+            .into_iter()
+            .map(|(dapp_id, ranked_tier)| {
+                let (tier, rank) = ranked_tier.deconstruct();
+                (dapp_id, RankedTierInfo { tier, rank })
+            })
+            .collect())
+    }
+
+    fn account_ledger(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<AccountLedgerInfo> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .account_ledger(at, account)
+            .map_err(|e| runtime_error("Unable to query account ledger.", e))
+    }
+
+    fn staker_info(
+        &self,
+        account: AccountId,
+        smart_contract: SmartContract,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<SingularStakingInfo>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .staker_info(at, account, smart_contract)
+            .map_err(|e| runtime_error("Unable to query staker info.", e))
+    }
+
+    fn contract_stake(
+        &self,
+        smart_contract: SmartContract,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ContractStakeAmount>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .contract_stake(at, smart_contract)
+            .map_err(|e| runtime_error("Unable to query contract stake.", e))
+    }
+
+    fn claimable_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Balance> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .claimable_staker_rewards(at, account)
+            .map_err(|e| runtime_error("Unable to query claimable staker rewards.", e))
+    }
+
+    fn claimable_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(SmartContract, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .claimable_bonus_rewards(at, account)
+            .map_err(|e| runtime_error("Unable to query claimable bonus rewards.", e))
+    }
+
+    fn claimable_dapp_rewards(
+        &self,
+        smart_contract: SmartContract,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .claimable_dapp_rewards(at, smart_contract)
+            .map_err(|e| runtime_error("Unable to query claimable dApp rewards.", e))
+    }
+}
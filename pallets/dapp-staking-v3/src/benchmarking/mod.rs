@@ -855,7 +855,10 @@ mod benchmarks {
 
         #[block]
         {
-            DappStaking::<T>::era_and_period_handler(state.next_era_start, TierAssignment::Dummy);
+            DappStaking::<T>::era_and_period_handler(
+                state.next_era_start,
+                TierAssignmentMode::Dummy,
+            );
         }
 
         assert_eq!(
@@ -915,7 +918,10 @@ mod benchmarks {
 
         #[block]
         {
-            DappStaking::<T>::era_and_period_handler(new_era_start_block, TierAssignment::Dummy);
+            DappStaking::<T>::era_and_period_handler(
+                new_era_start_block,
+                TierAssignmentMode::Dummy,
+            );
         }
 
         assert_eq!(
@@ -962,7 +968,10 @@ mod benchmarks {
 
         #[block]
         {
-            DappStaking::<T>::era_and_period_handler(new_era_start_block, TierAssignment::Dummy);
+            DappStaking::<T>::era_and_period_handler(
+                new_era_start_block,
+                TierAssignmentMode::Dummy,
+            );
         }
 
         assert_eq!(
@@ -996,6 +1005,20 @@ mod benchmarks {
         let reward_period = ActiveProtocolState::<T>::get().period_number();
         let reward_pool = Balance::from(10_000 * UNIT as u128);
 
+        // Worst case for the archive update is when each dApp's archive is full, and the oldest summary must be removed.
+        let full_archive: BoundedVec<_, T::MaxArchivedPeriods> = BoundedVec::truncate_from(vec![
+                DAppPeriodSummary::new(0);
+                T::MaxArchivedPeriods::get() as usize
+            ]);
+        for (_, dapp_info) in IntegratedDApps::<T>::iter() {
+            DAppPeriodArchive::<T>::insert(dapp_info.id, full_archive.clone());
+        }
+
+        // Worst case for the shared rewards update is when each dApp shares its rewards with the stakers.
+        for (smart_contract, _) in IntegratedDApps::<T>::iter() {
+            DAppRewardShares::<T>::insert(&smart_contract, Perbill::from_percent(50));
+        }
+
         #[block]
         {
             let (dapp_tiers, _count) = Pallet::<T>::get_dapp_tier_assignment_and_rewards(
@@ -1004,6 +1027,9 @@ mod benchmarks {
                 reward_pool,
             );
             assert_eq!(dapp_tiers.dapps.len(), x as usize);
+
+            Pallet::<T>::update_dapp_period_archive(reward_era, &dapp_tiers);
+            Pallet::<T>::update_dapp_shared_rewards(reward_era, &dapp_tiers);
         }
     }
 
@@ -1070,6 +1096,497 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_auto_restake() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), true);
+
+        assert_last_event::<T>(
+            Event::<T>::AutoRestakeSet {
+                account: staker,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_claim_bounty() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        let rate = T::MaxClaimBountyRate::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), rate);
+
+        assert_last_event::<T>(
+            Event::<T>::ClaimBountySet {
+                account: staker,
+                rate,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn restake(x: Linear<1, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let stake_amount = T::MinimumStakeAmount::get();
+        let reward = T::MinimumStakeAmount::get() * Into::<Balance>::into(x);
+        let amount = T::MinimumLockedAmount::get()
+            + stake_amount * Into::<Balance>::into(T::MaxNumberOfStakedContracts::get());
+        T::BenchmarkHelper::set_balance(&staker, amount + reward);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Register & stake on dApps, so the reward is split among all of them.
+        for idx in 0..x {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract,
+                stake_amount,
+            ));
+        }
+
+        #[block]
+        {
+            assert_eq!(DappStaking::<T>::restake_rewards(&staker, reward), Ok(x));
+        }
+
+        assert_eq!(Ledger::<T>::get(&staker).locked, amount + reward);
+    }
+
+    #[benchmark]
+    fn move_stake() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let source_contract = T::BenchmarkHelper::get_smart_contract(1);
+        let destination_contract = T::BenchmarkHelper::get_smart_contract(2);
+        for smart_contract in [&source_contract, &destination_contract] {
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+        }
+
+        // Worst case is when both contracts already have a stake entry, and neither is removed.
+        let stake_amount = T::MinimumStakeAmount::get() + 1;
+        let amount = T::MinimumLockedAmount::get().max(stake_amount * 2);
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        for smart_contract in [&source_contract, &destination_contract] {
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract.clone(),
+                stake_amount,
+            ));
+        }
+
+        let move_amount = 1;
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(staker.clone()),
+            source_contract.clone(),
+            destination_contract.clone(),
+            move_amount,
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::StakeMoved {
+                account: staker,
+                source_contract,
+                destination_contract,
+                amount: move_amount,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn queue_tier_params() {
+        initial_config::<T>();
+
+        let params = StaticTierParams::<T>::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, params.clone());
+
+        assert_last_event::<T>(Event::<T>::TierParamsQueued { params }.into());
+    }
+
+    #[benchmark]
+    fn stake_many(x: Linear<1, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get()
+            + T::MinimumStakeAmount::get()
+                * Into::<Balance>::into(T::MaxNumberOfStakedContracts::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Register dApps up to the limit
+        let mut stakes = Vec::new();
+        for idx in 0..x {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+            stakes.push((smart_contract, T::MinimumStakeAmount::get()));
+        }
+        let (last_smart_contract, last_amount) =
+            stakes.last().cloned().expect("At least one entry exists.");
+        let stakes: BoundedVec<_, _> = stakes.try_into().expect("Must fit into the bound.");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), stakes);
+
+        assert_last_event::<T>(
+            Event::<T>::Stake {
+                account: staker,
+                smart_contract: last_smart_contract,
+                amount: last_amount,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn unstake_many(x: Linear<1, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let stake_amount = T::MinimumStakeAmount::get() + 1;
+        let amount = T::MinimumLockedAmount::get()
+            + stake_amount * Into::<Balance>::into(T::MaxNumberOfStakedContracts::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Register & stake on dApps up to the limit
+        let unstake_amount = 1;
+        let mut unstakes = Vec::new();
+        for idx in 0..x {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract.clone(),
+                stake_amount,
+            ));
+            unstakes.push((smart_contract, unstake_amount));
+        }
+        let last_smart_contract = unstakes
+            .last()
+            .map(|(smart_contract, _)| smart_contract.clone())
+            .expect("At least one entry exists.");
+        let unstakes: BoundedVec<_, _> = unstakes.try_into().expect("Must fit into the bound.");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), unstakes);
+
+        assert_last_event::<T>(
+            Event::<T>::Unstake {
+                account: staker,
+                smart_contract: last_smart_contract,
+                amount: unstake_amount,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_dapp_reward_share() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let share = Perbill::from_percent(20);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone(), share);
+
+        assert_last_event::<T>(
+            Event::<T>::DAppRewardShareUpdated {
+                smart_contract,
+                share,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn claim_shared_dapp_rewards() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            smart_contract.clone(),
+            amount,
+        ));
+
+        // Advance into the next period, so the staked period has ended & its end info has to be read.
+        for _ in 0..T::EraRewardSpanLength::get() {
+            force_advance_to_next_era::<T>();
+        }
+        force_advance_to_next_period::<T>();
+
+        // Fill up the entire span of shared rewards, covering the first staked era.
+        let first_era = StakerInfo::<T>::get(&staker, &smart_contract)
+            .expect("Entry must exist since it was just created.")
+            .first_tracked_era();
+        let span_index = DappStaking::<T>::era_reward_span_index(first_era);
+        let mut span = EraRewardSpan::new();
+        for era in span_index..span_index + T::EraRewardSpanLength::get() {
+            assert_ok!(span.push(
+                era,
+                EraReward {
+                    staker_reward_pool: 1_000_000,
+                    staked: amount,
+                    dapp_reward_pool: 0,
+                },
+            ));
+        }
+        DAppSharedRewards::<T>::insert(&span_index, &smart_contract, span);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), smart_contract.clone());
+
+        assert!(SharedRewardClaimMarkers::<T>::get(&staker, &smart_contract) >= first_era);
+    }
+
+    #[benchmark]
+    fn set_dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let field: BoundedVec<u8, T::MaxMetadataFieldLength> =
+            BoundedVec::truncate_from(vec![b'a'; T::MaxMetadataFieldLength::get() as usize]);
+        let ipfs_cid: BoundedVec<u8, T::MaxIpfsCidLength> =
+            BoundedVec::truncate_from(vec![b'c'; T::MaxIpfsCidLength::get() as usize]);
+        let deposit = T::MetadataDepositBase::get().saturating_add(
+            T::MetadataDepositPerByte::get().saturating_mul(
+                (2 * T::MaxMetadataFieldLength::get() + T::MaxIpfsCidLength::get()).into(),
+            ),
+        );
+        T::BenchmarkHelper::set_balance(&owner, deposit * 10);
+
+        // Worst case is when the existing metadata deposit has to be released first.
+        assert_ok!(DappStaking::<T>::set_dapp_metadata(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            field.clone(),
+            field.clone(),
+            ipfs_cid.clone(),
+            DAppCategory::Defi,
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            smart_contract.clone(),
+            field.clone(),
+            field,
+            ipfs_cid,
+            DAppCategory::Gaming,
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::DAppMetadataSet {
+                smart_contract,
+                deposit,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn clear_dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let field: BoundedVec<u8, T::MaxMetadataFieldLength> =
+            BoundedVec::truncate_from(vec![b'a'; T::MaxMetadataFieldLength::get() as usize]);
+        let ipfs_cid: BoundedVec<u8, T::MaxIpfsCidLength> =
+            BoundedVec::truncate_from(vec![b'c'; T::MaxIpfsCidLength::get() as usize]);
+        let deposit = T::MetadataDepositBase::get().saturating_add(
+            T::MetadataDepositPerByte::get().saturating_mul(
+                (2 * T::MaxMetadataFieldLength::get() + T::MaxIpfsCidLength::get()).into(),
+            ),
+        );
+        T::BenchmarkHelper::set_balance(&owner, deposit * 10);
+        assert_ok!(DappStaking::<T>::set_dapp_metadata(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            field.clone(),
+            field,
+            ipfs_cid,
+            DAppCategory::Defi,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), smart_contract.clone());
+
+        assert_last_event::<T>(Event::<T>::DAppMetadataCleared { smart_contract }.into());
+    }
+
+    #[benchmark]
+    fn add_contract_alias() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Worst case is when all but one alias slots are occupied.
+        let max_aliases = T::MaxContractAliases::get();
+        for idx in 1..max_aliases {
+            assert_ok!(DappStaking::<T>::add_contract_alias(
+                RawOrigin::Root.into(),
+                smart_contract.clone(),
+                T::BenchmarkHelper::get_smart_contract(1 + idx),
+            ));
+        }
+        let alias = T::BenchmarkHelper::get_smart_contract(1 + max_aliases);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, smart_contract.clone(), alias.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::ContractAliasAdded {
+                smart_contract,
+                alias,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn remove_contract_alias() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Worst case is when all alias slots are occupied, and the last one is removed.
+        let max_aliases = T::MaxContractAliases::get();
+        for idx in 1..=max_aliases {
+            assert_ok!(DappStaking::<T>::add_contract_alias(
+                RawOrigin::Root.into(),
+                smart_contract.clone(),
+                T::BenchmarkHelper::get_smart_contract(1 + idx),
+            ));
+        }
+        let alias = T::BenchmarkHelper::get_smart_contract(1 + max_aliases);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            smart_contract.clone(),
+            alias.clone(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::ContractAliasRemoved {
+                smart_contract,
+                alias,
+            }
+            .into(),
+        );
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
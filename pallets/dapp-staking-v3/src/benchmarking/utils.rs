@@ -194,6 +194,7 @@ pub(super) fn init_tier_settings<T: Config>() {
             },
         ])
         .unwrap(),
+        min_stakers: BoundedVec::default(),
     };
 
     let total_issuance = 1000 * MIN_TIER_THRESHOLD;
@@ -37,8 +37,12 @@
 
 use frame_support::{
     pallet_prelude::*,
+    storage::with_storage_layer,
     traits::{
-        fungible::{Inspect as FunInspect, MutateFreeze as FunMutateFreeze},
+        fungible::{
+            Inspect as FunInspect, MutateFreeze as FunMutateFreeze, MutateHold as FunMutateHold,
+        },
+        tokens::Precision,
         StorageVersion,
     },
     weights::Weight,
@@ -54,8 +58,8 @@ pub use sp_std::vec::Vec;
 use astar_primitives::{
     dapp_staking::{
         AccountCheck, CycleConfiguration, DAppId, EraNumber, Observer as DAppStakingObserver,
-        PeriodNumber, Rank, RankedTier, SmartContractHandle, StakingRewardHandler, TierId,
-        TierSlots as TierSlotFunc,
+        PeriodNumber, Rank, RankedTier, RewardKind, SmartContractHandle, StakingRewardHandler,
+        TierId, TierSlots as TierSlotFunc,
     },
     oracle::PriceProvider,
     Balance, BlockNumber,
@@ -72,6 +76,9 @@ mod benchmarking;
 mod types;
 pub use types::*;
 
+mod tier_assignment;
+pub use tier_assignment::*;
+
 pub mod migration;
 pub mod weights;
 
@@ -80,7 +87,7 @@ pub use weights::WeightInfo;
 const LOG_TARGET: &str = "dapp-staking";
 
 /// Helper enum for benchmarking.
-pub(crate) enum TierAssignment {
+pub(crate) enum TierAssignmentMode {
     /// Real tier assignment calculation should be done.
     Real,
     /// Dummy tier assignment calculation should be done, e.g. default value should be returned.
@@ -94,7 +101,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -117,13 +124,13 @@ pub mod pallet {
         /// The overarching freeze reason.
         type RuntimeFreezeReason: From<FreezeReason>;
 
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
         /// Currency used for staking.
         /// Reference: <https://github.com/paritytech/substrate/pull/12951/>
-        type Currency: FunMutateFreeze<
-            Self::AccountId,
-            Id = Self::RuntimeFreezeReason,
-            Balance = Balance,
-        >;
+        type Currency: FunMutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason, Balance = Balance>
+            + FunMutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
 
         /// Describes smart contract in the context required by dApp staking.
         type SmartContract: Parameter
@@ -211,6 +218,44 @@ pub mod pallet {
         #[pallet::constant]
         type RankingEnabled: Get<bool>;
 
+        /// Algorithm used to assign dApps into tiers, based on their stake.
+        type TierAssignment: TierAssignment<Self>;
+
+        /// Maximum share of the claimed staker or bonus reward a staker can offer as a claim bounty,
+        /// paid out to the account claiming the reward on behalf of the staker.
+        /// Incentivizes third parties to claim rewards of inactive stakers before they expire.
+        #[pallet::constant]
+        type MaxClaimBountyRate: Get<Perbill>;
+
+        /// Maximum amount paid out as claim bounty for a single claim call.
+        #[pallet::constant]
+        type MaxClaimBounty: Get<Balance>;
+
+        /// Maximum number of past period summaries kept in the archive for each dApp.
+        /// Once the limit is reached, the oldest summary is removed to make room for the new one.
+        #[pallet::constant]
+        type MaxArchivedPeriods: Get<u32>;
+
+        /// Base deposit reserved for storing dApp metadata.
+        #[pallet::constant]
+        type MetadataDepositBase: Get<Balance>;
+
+        /// Additional deposit reserved per byte of the dApp metadata fields.
+        #[pallet::constant]
+        type MetadataDepositPerByte: Get<Balance>;
+
+        /// Maximum length of the dApp metadata name & website fields.
+        #[pallet::constant]
+        type MaxMetadataFieldLength: Get<u32>;
+
+        /// Maximum length of the dApp metadata IPFS CID.
+        #[pallet::constant]
+        type MaxIpfsCidLength: Get<u32>;
+
+        /// Maximum number of contract aliases which can be registered under a single dApp.
+        #[pallet::constant]
+        type MaxContractAliases: Get<u32>;
+
         /// Weight info for various calls & operations in the pallet.
         type WeightInfo: WeightInfo;
 
@@ -316,6 +361,69 @@ pub mod pallet {
         ExpiredEntriesRemoved { account: T::AccountId, count: u16 },
         /// Privileged origin has forced a new era and possibly a subperiod to start from next block.
         Force { forcing_type: ForcingType },
+        /// Account has changed its auto-restake preference.
+        AutoRestakeSet {
+            account: T::AccountId,
+            enabled: bool,
+        },
+        /// Claimed staker reward couldn't be re-staked, so it was paid out as liquid funds instead.
+        RestakeSkipped {
+            account: T::AccountId,
+            amount: Balance,
+        },
+        /// Account has moved some stake from one smart contract to another.
+        StakeMoved {
+            account: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            amount: Balance,
+        },
+        /// Claim bounty has been paid out to the account which claimed rewards on behalf of the staker.
+        ClaimBountyPaid {
+            claimer: T::AccountId,
+            account: T::AccountId,
+            amount: Balance,
+        },
+        /// New tier parameters have been queued, to be applied at the start of the next period.
+        TierParamsQueued {
+            params: TierParameters<T::NumberOfTiers>,
+        },
+        /// Queued tier parameters have been applied, at the start of the specified period.
+        TierParamsApplied { period: PeriodNumber },
+        /// dApp reward share, which is shared with the stakers, has been updated.
+        DAppRewardShareUpdated {
+            smart_contract: T::SmartContract,
+            share: Perbill,
+        },
+        /// Account has claimed its part of the dApp rewards shared with the stakers, up to & including the specified era.
+        SharedDAppReward {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            era: EraNumber,
+            amount: Balance,
+        },
+        /// dApp metadata has been set or updated.
+        DAppMetadataSet {
+            smart_contract: T::SmartContract,
+            deposit: Balance,
+        },
+        /// dApp metadata has been cleared and the deposit released.
+        DAppMetadataCleared { smart_contract: T::SmartContract },
+        /// Contract alias has been added to the dApp.
+        ContractAliasAdded {
+            smart_contract: T::SmartContract,
+            alias: T::SmartContract,
+        },
+        /// Contract alias has been removed from the dApp.
+        ContractAliasRemoved {
+            smart_contract: T::SmartContract,
+            alias: T::SmartContract,
+        },
+        /// Account has changed the claim bounty it offers to third parties claiming its rewards.
+        ClaimBountySet {
+            account: T::AccountId,
+            rate: Perbill,
+        },
     }
 
     #[pallet::error]
@@ -394,6 +502,20 @@ pub mod pallet {
         ForceNotAllowed,
         /// Account doesn't have the freeze inconsistency
         AccountNotInconsistent, // TODO: can be removed after call `fix_account` is removed
+        /// Account has no locked funds in dApp staking.
+        NoLockedFunds,
+        /// Stake cannot be moved to the same smart contract it's moved from.
+        SameSourceAndDestination,
+        /// Tier parameters aren't valid.
+        InvalidTierParams,
+        /// There's no metadata for the specified smart contract.
+        NoDAppMetadata,
+        /// Maximum number of contract aliases for the dApp has been reached.
+        TooManyContractAliases,
+        /// Specified contract alias doesn't exist for the dApp.
+        ContractAliasNotFound,
+        /// Claim bounty rate exceeds the maximum allowed rate.
+        ClaimBountyTooLarge,
     }
 
     /// General information about dApp staking protocol state.
@@ -423,6 +545,11 @@ pub mod pallet {
     pub type Ledger<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, AccountLedgerFor<T>, ValueQuery>;
 
+    /// Reward claiming preferences of each staker.
+    #[pallet::storage]
+    pub type StakerPreferencesOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, StakerPreferences, ValueQuery>;
+
     /// Information about how much each staker has staked for each smart contract in some period.
     #[pallet::storage]
     pub type StakerInfo<T: Config> = StorageDoubleMap<
@@ -445,6 +572,18 @@ pub mod pallet {
         MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
     >;
 
+    /// Number of unique stakers on a smart contract, in the ongoing period.
+    ///
+    /// Counting started lazily with storage version 9, so counts are accurate only from the period following the upgrade.
+    #[pallet::storage]
+    pub type ContractStakers<T: Config> = StorageMap<
+        Hasher = Twox64Concat,
+        Key = DAppId,
+        Value = StakerCount,
+        QueryKind = ValueQuery,
+        MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
+    >;
+
     /// General information about the current era.
     #[pallet::storage]
     pub type CurrentEraInfo<T: Config> = StorageValue<_, EraInfo, ValueQuery>;
@@ -472,6 +611,11 @@ pub mod pallet {
     pub type StaticTierParams<T: Config> =
         StorageValue<_, TierParameters<T::NumberOfTiers>, ValueQuery>;
 
+    /// Tier parameters queued by the privileged origin, to be applied at the start of the next period.
+    #[pallet::storage]
+    pub type QueuedTierParams<T: Config> =
+        StorageValue<_, TierParameters<T::NumberOfTiers>, OptionQuery>;
+
     /// Tier configuration user for current & preceding eras.
     #[pallet::storage]
     pub type TierConfig<T: Config> = StorageValue<
@@ -485,6 +629,76 @@ pub mod pallet {
     pub type DAppTiers<T: Config> =
         StorageMap<_, Twox64Concat, EraNumber, DAppTierRewardsFor<T>, OptionQuery>;
 
+    /// Rolling archive of per-period performance summaries for each dApp.
+    ///
+    /// Unlike `DAppTiers`, entries aren't removed once rewards expire, nor when the dApp is unregistered.
+    /// Only the latest `MaxArchivedPeriods` summaries are kept per dApp.
+    #[pallet::storage]
+    pub type DAppPeriodArchive<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        DAppId,
+        BoundedVec<DAppPeriodSummary<T::NumberOfTiers>, T::MaxArchivedPeriods>,
+        ValueQuery,
+    >;
+
+    /// Part of each dApp reward which is shared with the dApp stakers, if the dApp opted in.
+    #[pallet::storage]
+    pub type DAppRewardShares<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, Perbill, OptionQuery>;
+
+    /// dApp rewards shared with the stakers, alongside the total stake on the dApp, for each era.
+    ///
+    /// Keys follow the same rules as `EraRewards` keys, with the smart contract as the second key.
+    /// Only the `staker_reward_pool` & `staked` fields of each `EraReward` are used.
+    #[pallet::storage]
+    pub type DAppSharedRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraNumber,
+        Blake2_128Concat,
+        T::SmartContract,
+        EraRewardSpan<T::EraRewardSpanLength>,
+        OptionQuery,
+    >;
+
+    /// Last era in which a non-zero reward was shared with the stakers of the dApp.
+    #[pallet::storage]
+    pub type DAppLastSharedEra<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, EraNumber, ValueQuery>;
+
+    /// Last era up to which the account has claimed its part of the shared dApp rewards.
+    #[pallet::storage]
+    pub type SharedRewardClaimMarkers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::SmartContract,
+        EraNumber,
+        ValueQuery,
+    >;
+
+    /// Deposit-backed metadata of the registered dApps.
+    #[pallet::storage]
+    pub type DAppMetadataOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, DAppMetadataFor<T>, OptionQuery>;
+
+    /// Contract aliases, mapping each alias to the registered smart contract it belongs to.
+    #[pallet::storage]
+    pub type ContractAliases<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, T::SmartContract, OptionQuery>;
+
+    /// Contract aliases registered under each dApp.
+    #[pallet::storage]
+    pub type DAppContractAliases<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        DAppId,
+        BoundedVec<T::SmartContract, T::MaxContractAliases>,
+        ValueQuery,
+    >;
+
     /// History cleanup marker - holds information about which DB entries should be cleaned up next, when applicable.
     #[pallet::storage]
     pub type HistoryCleanupMarker<T: Config> = StorageValue<_, CleanupMarker, ValueQuery>;
@@ -508,6 +722,7 @@ pub mod pallet {
         pub slot_distribution: Vec<Permill>,
         pub tier_thresholds: Vec<TierThreshold>,
         pub slots_per_tier: Vec<u16>,
+        pub min_stakers: Vec<u32>,
         pub safeguard: Option<bool>,
         pub _config: PhantomData<T>,
     }
@@ -526,6 +741,7 @@ pub mod pallet {
                     })
                     .collect(),
                 slots_per_tier: vec![100; num_tiers as usize],
+                min_stakers: Vec::new(),
                 safeguard: None,
                 _config: Default::default(),
             }
@@ -549,6 +765,10 @@ pub mod pallet {
                     self.tier_thresholds.clone(),
                 )
                 .expect("Invalid number of tier thresholds provided."),
+                min_stakers: BoundedVec::<u32, T::NumberOfTiers>::try_from(
+                    self.min_stakers.clone(),
+                )
+                .expect("Invalid number of minimum stakers entries provided."),
             };
             assert!(
                 tier_params.is_valid(),
@@ -608,7 +828,7 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
             let now = now.saturated_into();
-            Self::era_and_period_handler(now, TierAssignment::Real)
+            Self::era_and_period_handler(now, TierAssignmentMode::Real)
         }
 
         fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
@@ -629,6 +849,9 @@ pub mod pallet {
             assert!(T::MinimumStakeAmount::get() > 0);
             assert!(T::MinimumLockedAmount::get() >= T::MinimumStakeAmount::get());
 
+            // Claim bounty must only be a small share of the claimed reward
+            assert!(T::MaxClaimBountyRate::get() <= Perbill::from_percent(10));
+
             // Cycle config
             assert!(T::CycleConfiguration::periods_per_cycle() > 0);
             assert!(T::CycleConfiguration::eras_per_voting_subperiod() > 0);
@@ -645,6 +868,14 @@ pub mod pallet {
         DAppStaking,
     }
 
+    /// A reason for placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held as a deposit for the dApp metadata.
+        #[codec(index = 0)]
+        DAppMetadata,
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Wrapper around _legacy-like_ `unbond_and_unstake`.
@@ -698,7 +929,8 @@ pub mod pallet {
             T::ContractRegisterOrigin::ensure_origin(origin)?;
 
             ensure!(
-                !IntegratedDApps::<T>::contains_key(&smart_contract),
+                !IntegratedDApps::<T>::contains_key(&smart_contract)
+                    && !ContractAliases::<T>::contains_key(&smart_contract),
                 Error::<T>::ContractAlreadyExists,
             );
 
@@ -816,11 +1048,12 @@ pub mod pallet {
         ///
         /// Can be called by dApp staking manager origin.
         #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::unregister())]
+        #[pallet::weight(T::WeightInfo::unregister()
+            .saturating_add(T::DbWeight::get().writes(T::MaxContractAliases::get().into())))]
         pub fn unregister(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             T::ContractUnregisterOrigin::ensure_origin(origin)?;
 
@@ -828,6 +1061,14 @@ pub mod pallet {
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
             ContractStake::<T>::remove(&dapp_info.id);
+            ContractStakers::<T>::remove(&dapp_info.id);
+            let aliases = DAppContractAliases::<T>::take(&dapp_info.id);
+            let removed_aliases = aliases.len() as u64;
+            for alias in aliases {
+                ContractAliases::<T>::remove(&alias);
+            }
+            DAppRewardShares::<T>::remove(&smart_contract);
+            Self::remove_dapp_metadata(&smart_contract);
             IntegratedDApps::<T>::remove(&smart_contract);
 
             let current_era = ActiveProtocolState::<T>::get().era;
@@ -836,7 +1077,11 @@ pub mod pallet {
                 era: current_era,
             });
 
-            Ok(())
+            Ok(Some(
+                T::WeightInfo::unregister()
+                    .saturating_add(T::DbWeight::get().writes(removed_aliases)),
+            )
+            .into())
         }
 
         /// Locks additional funds into dApp staking.
@@ -1003,120 +1248,22 @@ pub mod pallet {
         ///
         /// Staked amount is only eligible for rewards from the next era onwards.
         #[pallet::call_index(11)]
-        #[pallet::weight(T::WeightInfo::stake())]
+        #[pallet::weight(T::WeightInfo::stake().saturating_add(Pallet::<T>::shared_rewards_payout_weight()))]
         pub fn stake(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] amount: Balance,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            ensure!(amount > 0, Error::<T>::ZeroAmount);
-
-            let dapp_info =
-                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
-
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
-            ensure!(
-                !protocol_state
-                    .period_info
-                    .is_next_period(current_era.saturating_add(1)),
-                Error::<T>::PeriodEndsInNextEra
-            );
-
-            let mut ledger = Ledger::<T>::get(&account);
-
-            // In case old stake rewards are unclaimed & have expired, clean them up.
-            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
-            let _ignore = ledger.maybe_cleanup_expired(threshold_period);
-
-            // 1.
-            // Increase stake amount for the next era & current period in staker's ledger
-            ledger
-                .add_stake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    AccountLedgerError::UnavailableStakeFunds => Error::<T>::UnavailableStakeFunds,
-                    // Defensive check, should never happen
-                    _ => Error::<T>::InternalStakeError,
-                })?;
-
-            // 2.
-            // Update `StakerInfo` storage with the new stake amount on the specified contract.
-            //
-            // There are two distinct scenarios:
-            // 1. Existing entry matches the current period number - just update it.
-            // 2. Entry doesn't exist or it's for an older period - create a new one.
-            //
-            // This is ok since we only use this storage entry to keep track of how much each staker
-            // has staked on each contract in the current period. We only ever need the latest information.
-            // This is because `AccountLedger` is the one keeping information about how much was staked when.
-            let (mut new_staking_info, is_new_entry) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    // Entry with matching period exists
-                    Some(staking_info)
-                        if staking_info.period_number() == protocol_state.period_number() =>
-                    {
-                        (staking_info, false)
-                    }
-                    // Entry exists but period doesn't match. Bonus reward might still be claimable.
-                    Some(staking_info)
-                        if staking_info.period_number() >= threshold_period
-                            && staking_info.is_loyal() =>
-                    {
-                        return Err(Error::<T>::UnclaimedRewards.into());
-                    }
-                    // No valid entry exists
-                    _ => (
-                        SingularStakingInfo::new(
-                            protocol_state.period_number(),
-                            protocol_state.subperiod(),
-                        ),
-                        true,
-                    ),
-                };
-            new_staking_info.stake(amount, current_era, protocol_state.subperiod());
-            ensure!(
-                new_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
-                Error::<T>::InsufficientStakeAmount
-            );
-
-            if is_new_entry {
-                ledger.contract_stake_count.saturating_inc();
-                ensure!(
-                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
-                    Error::<T>::TooManyStakedContracts
-                );
-            }
-
-            // 3.
-            // Update `ContractStake` storage with the new stake amount on the specified contract.
-            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
-            contract_stake_info.stake(amount, protocol_state.period_info, current_era);
-
-            // 4.
-            // Update total staked amount for the next era.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.add_stake_amount(amount, protocol_state.subperiod());
-            });
-
-            // 5.
-            // Update remaining storage entries
-            Self::update_ledger(&account, ledger)?;
-            StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
-            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
-
-            Self::deposit_event(Event::<T>::Stake {
-                account,
-                smart_contract,
-                amount,
-            });
+            let paid_spans = Self::internal_stake(account, smart_contract, amount)?;
 
-            Ok(())
+            Ok(Some(
+                T::WeightInfo::stake()
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans)),
+            )
+            .into())
         }
 
         /// Unstake the specified amount from a smart contract.
@@ -1129,179 +1276,89 @@ pub mod pallet {
         /// In case amount is unstaked during `Build&Earn` subperiod, first the `build_and_earn` is reduced,
         /// and any spillover is subtracted from the `voting` amount.
         #[pallet::call_index(12)]
-        #[pallet::weight(T::WeightInfo::unstake())]
+        #[pallet::weight(T::WeightInfo::unstake().saturating_add(Pallet::<T>::shared_rewards_payout_weight()))]
         pub fn unstake(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] amount: Balance,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            ensure!(amount > 0, Error::<T>::ZeroAmount);
+            let paid_spans = Self::internal_unstake(account, smart_contract, amount)?;
+
+            Ok(Some(
+                T::WeightInfo::unstake()
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans)),
+            )
+            .into())
+        }
+
+        /// Claims some staker rewards, if user has any.
+        /// In the case of a successful call, at least one era will be claimed, with the possibility of multiple claims happening.
+        #[pallet::call_index(13)]
+        #[pallet::weight({
+            let max_span_length = T::EraRewardSpanLength::get();
+            T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::restake(T::MaxNumberOfStakedContracts::get()))
+        })]
+        pub fn claim_staker_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            Self::internal_claim_staker_rewards_for(account.clone(), account)
+        }
+
+        /// Used to claim bonus reward for a smart contract, if eligible.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::claim_bonus_reward().saturating_add(Pallet::<T>::shared_rewards_payout_weight()))]
+        pub fn claim_bonus_reward(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            Self::internal_claim_bonus_reward_for(account.clone(), account, smart_contract)
+        }
+
+        /// Used to claim dApp reward for the specified era.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::claim_dapp_reward())]
+        pub fn claim_dapp_reward(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] era: EraNumber,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+
+            // To keep in line with legacy behavior, dApp rewards can be claimed by anyone.
+            let _ = ensure_signed(origin)?;
 
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
+            // Make sure provided era has ended
             let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
+            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
 
-            let mut ledger = Ledger::<T>::get(&account);
-
-            // 1.
-            // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
-            let (new_staking_info, amount, era_and_amount_pairs) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    Some(mut staking_info) => {
-                        ensure!(
-                            staking_info.period_number() == protocol_state.period_number(),
-                            Error::<T>::UnstakeFromPastPeriod
-                        );
-                        ensure!(
-                            staking_info.total_staked_amount() >= amount,
-                            Error::<T>::UnstakeAmountTooLarge
-                        );
-
-                        // If unstaking would take the total staked amount below the minimum required value,
-                        // unstake everything.
-                        let amount = if staking_info.total_staked_amount().saturating_sub(amount)
-                            < T::MinimumStakeAmount::get()
-                        {
-                            staking_info.total_staked_amount()
-                        } else {
-                            amount
-                        };
-
-                        let era_and_amount_pairs =
-                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
-
-                        (staking_info, amount, era_and_amount_pairs)
-                    }
-                    None => {
-                        return Err(Error::<T>::NoStakingInfo.into());
-                    }
-                };
-
-            // 2.
-            // Reduce stake amount
-            ledger
-                .unstake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    // This is a defensive check, which should never happen since we calculate the correct value above.
-                    AccountLedgerError::UnstakeAmountLargerThanStake => {
-                        Error::<T>::UnstakeAmountTooLarge
-                    }
-                    _ => Error::<T>::InternalUnstakeError,
-                })?;
-
-            // 3.
-            // Update `ContractStake` storage with the reduced stake amount on the specified contract.
-            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
-            contract_stake_info.unstake(
-                era_and_amount_pairs,
-                protocol_state.period_info,
-                current_era,
-            );
-
-            // 4.
-            // Update total staked amount for the next era.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.unstake_amount(amount);
-            });
-
-            // 5.
-            // Update remaining storage entries
-            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
-
-            if new_staking_info.is_empty() {
-                ledger.contract_stake_count.saturating_dec();
-                StakerInfo::<T>::remove(&account, &smart_contract);
-            } else {
-                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
-            }
-
-            Self::update_ledger(&account, ledger)?;
-
-            Self::deposit_event(Event::<T>::Unstake {
-                account,
-                smart_contract,
-                amount,
-            });
-
-            Ok(())
-        }
-
-        /// Claims some staker rewards, if user has any.
-        /// In the case of a successful call, at least one era will be claimed, with the possibility of multiple claims happening.
-        #[pallet::call_index(13)]
-        #[pallet::weight({
-            let max_span_length = T::EraRewardSpanLength::get();
-            T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
-                .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
-        })]
-        pub fn claim_staker_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            Self::ensure_pallet_enabled()?;
-            let account = ensure_signed(origin)?;
-
-            Self::internal_claim_staker_rewards_for(account)
-        }
-
-        /// Used to claim bonus reward for a smart contract, if eligible.
-        #[pallet::call_index(14)]
-        #[pallet::weight(T::WeightInfo::claim_bonus_reward())]
-        pub fn claim_bonus_reward(
-            origin: OriginFor<T>,
-            smart_contract: T::SmartContract,
-        ) -> DispatchResult {
-            Self::ensure_pallet_enabled()?;
-            let account = ensure_signed(origin)?;
-
-            Self::internal_claim_bonus_reward_for(account, smart_contract)
-        }
-
-        /// Used to claim dApp reward for the specified era.
-        #[pallet::call_index(15)]
-        #[pallet::weight(T::WeightInfo::claim_dapp_reward())]
-        pub fn claim_dapp_reward(
-            origin: OriginFor<T>,
-            smart_contract: T::SmartContract,
-            #[pallet::compact] era: EraNumber,
-        ) -> DispatchResult {
-            Self::ensure_pallet_enabled()?;
-
-            // To keep in line with legacy behavior, dApp rewards can be claimed by anyone.
-            let _ = ensure_signed(origin)?;
-
-            let dapp_info =
-                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
-
-            // Make sure provided era has ended
-            let protocol_state = ActiveProtocolState::<T>::get();
-            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
-
-            // 'Consume' dApp reward for the specified era, if possible.
-            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
-            ensure!(
-                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            let (amount, ranked_tier) =
-                dapp_tiers
-                    .try_claim(dapp_info.id)
-                    .map_err(|error| match error {
-                        DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
-                        _ => Error::<T>::InternalClaimDAppError,
-                    })?;
+            // 'Consume' dApp reward for the specified era, if possible.
+            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
+            let (amount, ranked_tier) = Self::calculate_dapp_reward(
+                dapp_info.id,
+                &mut dapp_tiers,
+                protocol_state.period_number(),
+            )?;
 
             let (tier_id, rank) = ranked_tier.deconstruct();
 
+            // Part of the reward might have been shared with the stakers.
+            let amount = amount.saturating_sub(Self::shared_dapp_reward(&smart_contract, era));
+
             // Get reward destination, and deposit the reward.
             let beneficiary = dapp_info.reward_beneficiary();
-            T::StakingRewardHandler::payout_reward(&beneficiary, amount)
+            T::StakingRewardHandler::payout_reward(&beneficiary, amount, RewardKind::DApp)
                 .map_err(|_| Error::<T>::RewardPayoutFailed)?;
 
             // Write back updated struct to prevent double reward claims
@@ -1322,13 +1379,14 @@ pub mod pallet {
         /// Used to unstake funds from a contract that was unregistered after an account staked on it.
         /// This is required if staker wants to re-stake these funds on another active contract during the ongoing period.
         #[pallet::call_index(16)]
-        #[pallet::weight(T::WeightInfo::unstake_from_unregistered())]
+        #[pallet::weight(T::WeightInfo::unstake_from_unregistered().saturating_add(Pallet::<T>::shared_rewards_payout_weight()))]
         pub fn unstake_from_unregistered(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
+            let smart_contract = Self::resolve_smart_contract(smart_contract);
 
             ensure!(
                 !IntegratedDApps::<T>::contains_key(&smart_contract),
@@ -1339,14 +1397,19 @@ pub mod pallet {
             let current_era = protocol_state.era;
 
             // Extract total staked amount on the specified unregistered contract
-            let amount = match StakerInfo::<T>::get(&account, &smart_contract) {
+            let (amount, paid_spans) = match StakerInfo::<T>::get(&account, &smart_contract) {
                 Some(staking_info) => {
                     ensure!(
                         staking_info.period_number() == protocol_state.period_number(),
                         Error::<T>::UnstakeFromPastPeriod
                     );
+                    let paid_spans = Self::payout_pending_shared_rewards(
+                        &account,
+                        &smart_contract,
+                        &staking_info,
+                    )?;
 
-                    staking_info.total_staked_amount()
+                    (staking_info.total_staked_amount(), paid_spans)
                 }
                 None => {
                     return Err(Error::<T>::NoStakingInfo.into());
@@ -1375,6 +1438,7 @@ pub mod pallet {
             // Update remaining storage entries
             Self::update_ledger(&account, ledger)?;
             StakerInfo::<T>::remove(&account, &smart_contract);
+            SharedRewardClaimMarkers::<T>::remove(&account, &smart_contract);
 
             Self::deposit_event(Event::<T>::UnstakeFromUnregistered {
                 account,
@@ -1382,7 +1446,11 @@ pub mod pallet {
                 amount,
             });
 
-            Ok(())
+            Ok(Some(
+                T::WeightInfo::unstake_from_unregistered()
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans)),
+            )
+            .into())
         }
 
         /// Cleanup expired stake entries for the contract.
@@ -1422,6 +1490,7 @@ pub mod pallet {
             // Remove all expired entries.
             for smart_contract in to_be_deleted {
                 StakerInfo::<T>::remove(&account, &smart_contract);
+                SharedRewardClaimMarkers::<T>::remove(&account, &smart_contract);
             }
 
             // Remove expired stake entries from the ledger.
@@ -1484,629 +1553,1996 @@ pub mod pallet {
 
         /// Claims some staker rewards for the specified account, if they have any.
         /// In the case of a successful call, at least one era will be claimed, with the possibility of multiple claims happening.
+        ///
+        /// Caller receives a claim bounty, a share of the claimed rewards, in case the staker has opted into paying it
+        /// via `set_claim_bounty`. Bounty is capped at `MaxClaimBounty`.
         #[pallet::call_index(19)]
         #[pallet::weight({
             let max_span_length = T::EraRewardSpanLength::get();
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::restake(T::MaxNumberOfStakedContracts::get()))
+                .saturating_add(Pallet::<T>::claim_bounty_weight())
         })]
         pub fn claim_staker_rewards_for(
             origin: OriginFor<T>,
             account: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            ensure_signed(origin)?;
+            let claimer = ensure_signed(origin)?;
 
-            Self::internal_claim_staker_rewards_for(account)
+            Self::internal_claim_staker_rewards_for(claimer, account)
         }
 
         /// Used to claim bonus reward for a smart contract on behalf of the specified account, if eligible.
+        ///
+        /// Caller receives a claim bounty, a share of the claimed reward, in case the staker has opted into paying it
+        /// via `set_claim_bounty`. Bounty is capped at `MaxClaimBounty`.
         #[pallet::call_index(20)]
-        #[pallet::weight(T::WeightInfo::claim_bonus_reward())]
+        #[pallet::weight(T::WeightInfo::claim_bonus_reward()
+            .saturating_add(Pallet::<T>::claim_bounty_weight())
+            .saturating_add(Pallet::<T>::shared_rewards_payout_weight()))]
         pub fn claim_bonus_reward_for(
             origin: OriginFor<T>,
             account: T::AccountId,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            ensure_signed(origin)?;
+            let claimer = ensure_signed(origin)?;
 
-            Self::internal_claim_bonus_reward_for(account, smart_contract)
+            Self::internal_claim_bonus_reward_for(claimer, account, smart_contract)
         }
 
-        /// A call used to fix accounts with inconsistent state, where frozen balance is actually higher than what's available.
+        /// Sets whether claimed staker rewards should automatically be locked & re-staked.
         ///
-        /// The approach is as simple as possible:
-        /// 1. Caller provides an account to fix.
-        /// 2. If account is eligible for the fix, all unlocking chunks are modified to be claimable immediately.
-        /// 3. The `claim_unlocked` call is executed using the provided account as the origin.
-        /// 4. All states are updated accordingly, and the account is no longer in an inconsistent state.
+        /// Rewards are re-staked on the contracts the account has staked on during the ongoing period,
+        /// proportionally to the existing stake on each of them.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_auto_restake())]
+        pub fn set_auto_restake(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            ensure!(
+                !Ledger::<T>::get(&account).is_empty(),
+                Error::<T>::NoLockedFunds
+            );
+
+            StakerPreferencesOf::<T>::mutate(&account, |preferences| {
+                preferences.auto_restake = enabled;
+            });
+
+            Self::deposit_event(Event::<T>::AutoRestakeSet { account, enabled });
+
+            Ok(())
+        }
+
+        /// Move the specified amount of stake from the source smart contract to the destination smart contract.
         ///
-        /// The benchmarked weight of the `claim_unlocked` call is used as a base, and additional overestimated weight is added.
-        /// Call doesn't touch any storage items that aren't already touched by the `claim_unlocked` call, hence the simplified approach.
-        #[pallet::call_index(100)]
-        #[pallet::weight(T::DbWeight::get().reads_writes(4, 1))]
-        pub fn fix_account(
+        /// Unlike `unstake` followed by `stake`, the voting & build&earn split of the moved stake is preserved,
+        /// and moving stake doesn't forfeit the loyalty of the staker, making them still eligible for the bonus reward.
+        /// However, moving voting stake of a non-loyal entry makes the destination entry lose its loyalty.
+        ///
+        /// If moving the specified `amount` would take the source stake below the minimum stake threshold,
+        /// everything is moved.
+        ///
+        /// Moved stake stops counting towards the source contract immediately, but only counts towards the destination
+        /// contract from the next era onwards.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::move_stake().saturating_add(Pallet::<T>::shared_rewards_payout_weight().saturating_mul(2)))]
+        pub fn move_stake(
             origin: OriginFor<T>,
-            account: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
         ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
-            ensure_signed(origin)?;
+            let account = ensure_signed(origin)?;
+            let source_contract = Self::resolve_smart_contract(source_contract);
+            let destination_contract = Self::resolve_smart_contract(destination_contract);
 
-            let mut ledger = Ledger::<T>::get(&account);
-            let locked_amount_ledger = ledger.total_locked_amount();
-            let total_balance = T::Currency::total_balance(&account);
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+            ensure!(
+                source_contract != destination_contract,
+                Error::<T>::SameSourceAndDestination
+            );
 
-            if locked_amount_ledger > total_balance {
-                // 1. Modify all unlocking chunks so they can be unlocked immediately.
-                let current_block: BlockNumber =
-                    frame_system::Pallet::<T>::block_number().saturated_into();
-                ledger
-                    .unlocking
-                    .iter_mut()
-                    .for_each(|chunk| chunk.unlock_block = current_block);
-                Ledger::<T>::insert(&account, ledger);
+            let source_dapp_info =
+                IntegratedDApps::<T>::get(&source_contract).ok_or(Error::<T>::ContractNotFound)?;
+            let destination_dapp_info = IntegratedDApps::<T>::get(&destination_contract)
+                .ok_or(Error::<T>::ContractNotFound)?;
 
-                // 2. Execute the unlock call, clearing all of the unlocking chunks.
-                Self::internal_claim_unlocked(account)?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            ensure!(
+                !protocol_state
+                    .period_info
+                    .is_next_period(current_era.saturating_add(1)),
+                Error::<T>::PeriodEndsInNextEra
+            );
 
-                // 3. In case of success, ensure no fee is paid.
-                Ok(Pays::No.into())
-            } else {
-                // The above logic is designed for a specific scenario and cannot be used otherwise.
-                Err(Error::<T>::AccountNotInconsistent.into())
-            }
-        }
-    }
+            let mut ledger = Ledger::<T>::get(&account);
 
-    impl<T: Config> Pallet<T> {
-        /// `true` if the account is a staker, `false` otherwise.
-        pub fn is_staker(account: &T::AccountId) -> bool {
-            Ledger::<T>::contains_key(account)
-        }
+            // 1.
+            // Reduce the stake on the source contract, keeping track of how much of each subperiod stake was moved.
+            let mut source_staking_info = StakerInfo::<T>::get(&account, &source_contract)
+                .ok_or(Error::<T>::NoStakingInfo)?;
+            ensure!(
+                source_staking_info.period_number() == protocol_state.period_number(),
+                Error::<T>::UnstakeFromPastPeriod
+            );
+            let mut paid_spans = Self::payout_pending_shared_rewards(
+                &account,
+                &source_contract,
+                &source_staking_info,
+            )?;
+            ensure!(
+                source_staking_info.total_staked_amount() >= amount,
+                Error::<T>::UnstakeAmountTooLarge
+            );
 
-        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
-        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
-            if ActiveProtocolState::<T>::get().maintenance {
-                Err(Error::<T>::Disabled)
+            // If moving would take the source staked amount below the minimum required value, move everything.
+            let amount = if source_staking_info
+                .total_staked_amount()
+                .saturating_sub(amount)
+                < T::MinimumStakeAmount::get()
+            {
+                source_staking_info.total_staked_amount()
             } else {
-                Ok(())
-            }
-        }
+                amount
+            };
 
-        /// Update the account ledger, and dApp staking balance freeze.
-        ///
-        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
-        ///
-        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
-        /// runtime definition must ensure it supports necessary freezes.
-        pub(crate) fn update_ledger(
-            account: &T::AccountId,
-            ledger: AccountLedgerFor<T>,
-        ) -> Result<(), DispatchError> {
-            if ledger.is_empty() {
-                Ledger::<T>::remove(&account);
-                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
-            } else {
-                T::Currency::set_freeze(
-                    &FreezeReason::DAppStaking.into(),
-                    account,
-                    ledger.total_locked_amount(),
-                )?;
-                Ledger::<T>::insert(account, ledger);
-            }
+            let is_loyal = source_staking_info.is_loyal();
+            let staked_snapshot = source_staking_info.staked;
+            let era_and_amount_pairs =
+                source_staking_info.unstake(amount, current_era, protocol_state.subperiod());
+            let moved_voting = staked_snapshot
+                .voting
+                .saturating_sub(source_staking_info.staked.voting);
+            let moved_build_and_earn = amount.saturating_sub(moved_voting);
 
-            Ok(())
-        }
+            // Voting stake isn't lost, just moved, so loyalty is kept for whatever remains.
+            source_staking_info.loyal_staker =
+                is_loyal && !source_staking_info.staked.voting.is_zero();
 
-        /// Returns the number of blocks per voting period.
-        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era()
-                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
-        }
-
-        /// Calculates the `EraRewardSpan` index for the specified era.
-        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
-            era.saturating_sub(era % T::EraRewardSpanLength::get())
-        }
+            // 2.
+            // Increase the stake on the destination contract, preserving the subperiod split.
+            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
+            let existing_staking_info = StakerInfo::<T>::get(&account, &destination_contract);
+            if let Some(staking_info) = &existing_staking_info {
+                // Shared dApp rewards must be paid out before the entry is modified.
+                paid_spans.saturating_accrue(Self::payout_pending_shared_rewards(
+                    &account,
+                    &destination_contract,
+                    staking_info,
+                )?);
+            }
+            let (mut destination_staking_info, is_new_entry) = match existing_staking_info {
+                // Entry with matching period exists
+                Some(staking_info)
+                    if staking_info.period_number() == protocol_state.period_number() =>
+                {
+                    (staking_info, false)
+                }
+                // Entry exists but period doesn't match. Bonus reward might still be claimable.
+                Some(staking_info)
+                    if staking_info.period_number() >= threshold_period
+                        && staking_info.is_loyal() =>
+                {
+                    return Err(Error::<T>::UnclaimedRewards.into());
+                }
+                // No valid entry exists
+                _ => (
+                    SingularStakingInfo::new(
+                        protocol_state.period_number(),
+                        protocol_state.subperiod(),
+                    ),
+                    true,
+                ),
+            };
 
-        /// Return the oldest period for which rewards can be claimed.
-        /// All rewards before that period are considered to be expired.
-        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
-            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
-        }
+            // Loyal voting stake can only make the destination entry loyal if it had no voting stake of its own.
+            // Otherwise, its loyalty is determined by its own voting stake.
+            //
+            // Non-loyal voting stake makes the destination entry lose its loyalty, since forfeited
+            // bonus reward eligibility mustn't be regained by moving the stake to a loyal entry.
+            if !moved_voting.is_zero() {
+                if !is_loyal {
+                    destination_staking_info.loyal_staker = false;
+                } else if destination_staking_info.staked.voting.is_zero() {
+                    destination_staking_info.loyal_staker = true;
+                }
+            }
+            destination_staking_info.stake_split(moved_voting, moved_build_and_earn, current_era);
+            ensure!(
+                destination_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
+                Error::<T>::InsufficientStakeAmount
+            );
 
-        /// Unlocking period expressed in the number of blocks.
-        pub fn unlocking_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
-        }
+            if is_new_entry {
+                ledger.contract_stake_count.saturating_inc();
+            }
+            if source_staking_info.is_empty() {
+                ledger.contract_stake_count.saturating_dec();
+            }
+            ensure!(
+                ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
+                Error::<T>::TooManyStakedContracts
+            );
 
-        /// Returns the dApp tier assignment for the current era, based on the current stake amounts.
-        pub fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
-            let protocol_state = ActiveProtocolState::<T>::get();
+            // 3.
+            // Update the ledger. Total stake for the next era remains the same,
+            // but the current era stake is reduced in case moved stake was active in it.
+            let voting_period_info = PeriodInfo {
+                subperiod: Subperiod::Voting,
+                ..protocol_state.period_info
+            };
+            let build_and_earn_period_info = PeriodInfo {
+                subperiod: Subperiod::BuildAndEarn,
+                ..protocol_state.period_info
+            };
+            ledger
+                .unstake_amount(amount, current_era, protocol_state.period_info)
+                .and_then(|_| {
+                    ledger.add_stake_amount(moved_voting, current_era, voting_period_info)
+                })
+                .and_then(|_| {
+                    ledger.add_stake_amount(
+                        moved_build_and_earn,
+                        current_era,
+                        build_and_earn_period_info,
+                    )
+                })
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    // This is a defensive check, which should never happen since we calculate the correct value above.
+                    AccountLedgerError::UnstakeAmountLargerThanStake => {
+                        Error::<T>::UnstakeAmountTooLarge
+                    }
+                    _ => Error::<T>::InternalStakeError,
+                })?;
 
-            let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
-                protocol_state.era,
-                protocol_state.period_number(),
-                Balance::zero(),
+            // 4.
+            // Update `ContractStake` storage for both contracts.
+            let mut source_contract_stake = ContractStake::<T>::get(&source_dapp_info.id);
+            source_contract_stake.unstake(
+                era_and_amount_pairs,
+                protocol_state.period_info,
+                current_era,
             );
 
-            dapp_tiers.dapps.into_inner()
-        }
-
-        /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
-        ///
-        /// ### Algorithm
-        ///
-        /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
-        ///    This information is used to calculate 'score' per dApp, which is used to determine the tier.
-        ///
-        /// 2. Sort the entries by the score, in descending order - the top score dApp comes first.
-        ///
-        /// 3. Calculate rewards for each tier.
-        ///    This is done by dividing the total reward pool into tier reward pools,
-        ///    after which the tier reward pool is divided by the number of available slots in the tier.
-        ///
-        /// 4. Read in tier configuration. This contains information about how many slots per tier there are,
-        ///    as well as the threshold for each tier. Threshold is the minimum amount of stake required to be eligible for a tier.
-        ///    Iterate over tier thresholds & capacities, starting from the top tier, and assign dApps to them.
-        ///
-        ///    ```text
-        ////   for each tier:
-        ///        for each unassigned dApp:
-        ///            if tier has capacity && dApp satisfies the tier threshold:
-        ///                add dapp to the tier
-        ///            else:
-        ///               exit loop since no more dApps will satisfy the threshold since they are sorted by score
-        ///    ```
-        ///    (Sort the entries by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
-        ///
-        /// The returned object contains information about each dApp that made it into a tier.
-        /// Alongside tier assignment info, number of read DB contract stake entries is returned.
-        pub(crate) fn get_dapp_tier_assignment_and_rewards(
-            era: EraNumber,
-            period: PeriodNumber,
-            dapp_reward_pool: Balance,
-        ) -> (DAppTierRewardsFor<T>, DAppId) {
-            let mut dapp_stakes = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
+            let mut destination_contract_stake = ContractStake::<T>::get(&destination_dapp_info.id);
+            if !moved_voting.is_zero() {
+                destination_contract_stake.stake(moved_voting, voting_period_info, current_era);
+            }
+            if !moved_build_and_earn.is_zero() {
+                destination_contract_stake.stake(
+                    moved_build_and_earn,
+                    build_and_earn_period_info,
+                    current_era,
+                );
+            }
 
-            // 1.
-            // Iterate over all staked dApps.
-            // This is bounded by max amount of dApps we allow to be registered.
-            let mut counter = 0;
-            for (dapp_id, stake_amount) in ContractStake::<T>::iter() {
-                counter.saturating_inc();
+            // 5.
+            // Total staked amount for the next era doesn't change, only the current era amount is reduced.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unstake_current_era_amount(amount);
+            });
 
-                // Skip dApps which don't have ANY amount staked
-                if let Some(stake_amount) = stake_amount.get(era, period) {
-                    if !stake_amount.total().is_zero() {
-                        dapp_stakes.push((dapp_id, stake_amount.total()));
-                    }
-                }
+            // 6.
+            // Update remaining storage entries
+            ContractStake::<T>::insert(&source_dapp_info.id, source_contract_stake);
+            ContractStake::<T>::insert(&destination_dapp_info.id, destination_contract_stake);
+
+            if source_staking_info.is_empty() {
+                StakerInfo::<T>::remove(&account, &source_contract);
+                SharedRewardClaimMarkers::<T>::remove(&account, &source_contract);
+                ContractStakers::<T>::mutate(&source_dapp_info.id, |staker_count| {
+                    staker_count.decrease(protocol_state.period_number())
+                });
+            } else {
+                StakerInfo::<T>::insert(&account, &source_contract, source_staking_info);
+            }
+            StakerInfo::<T>::insert(&account, &destination_contract, destination_staking_info);
+            if is_new_entry {
+                ContractStakers::<T>::mutate(&destination_dapp_info.id, |staker_count| {
+                    staker_count.increase(protocol_state.period_number())
+                });
             }
 
-            // 2.
-            // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
-            dapp_stakes.sort_unstable_by(|(_, amount_1), (_, amount_2)| amount_2.cmp(amount_1));
-
-            let tier_config = TierConfig::<T>::get();
+            Self::update_ledger(&account, ledger)?;
 
-            // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
-            // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
-            //
-            // In the current implementation, the dApp with the lower dApp Id has the advantage.
-            // There is no guarantee this will persist in the future, so it's best for dApps to do their
-            // best to avoid getting themselves into such situations.
+            Self::deposit_event(Event::<T>::StakeMoved {
+                account,
+                source_contract,
+                destination_contract,
+                amount,
+            });
 
-            // 3. Calculate rewards.
-            let tier_rewards = tier_config
-                .reward_portion
-                .iter()
-                .zip(tier_config.slots_per_tier.iter())
-                .map(|(percent, slots)| {
-                    if slots.is_zero() {
-                        Zero::zero()
-                    } else {
-                        *percent * dapp_reward_pool / <u16 as Into<Balance>>::into(*slots)
-                    }
-                })
-                .collect::<Vec<_>>();
+            Ok(Some(
+                T::WeightInfo::move_stake()
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans)),
+            )
+            .into())
+        }
 
-            // 4.
-            // Iterate over configured tier and potential dApps.
-            // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
-            // and tier capacity hasn't been filled yet.
-            let mut dapp_tiers = BTreeMap::new();
-            let mut tier_slots = BTreeMap::new();
+        /// Queues new tier parameters, which replace the current ones at the start of the next period.
+        ///
+        /// Queuing new parameters while some are already queued overwrites the previously queued ones.
+        ///
+        /// Can only be called by the manager origin.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::queue_tier_params())]
+        pub fn queue_tier_params(
+            origin: OriginFor<T>,
+            params: TierParameters<T::NumberOfTiers>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ManagerOrigin::ensure_origin(origin)?;
 
-            let mut upper_bound = Balance::zero();
-            let mut rank_rewards = Vec::new();
+            ensure!(params.is_valid(), Error::<T>::InvalidTierParams);
 
-            for (tier_id, (tier_capacity, lower_bound)) in tier_config
-                .slots_per_tier
-                .iter()
-                .zip(tier_config.tier_thresholds.iter())
-                .enumerate()
-            {
-                // Iterate over dApps until one of two conditions has been met:
-                // 1. Tier has no more capacity
-                // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
-                for (dapp_id, staked_amount) in dapp_stakes
-                    .iter()
-                    .skip(dapp_tiers.len())
-                    .take_while(|(_, amount)| amount.ge(lower_bound))
-                    .take(*tier_capacity as usize)
-                {
-                    let rank = if T::RankingEnabled::get() {
-                        RankedTier::find_rank(*lower_bound, upper_bound, *staked_amount)
-                    } else {
-                        0
-                    };
-                    tier_slots.insert(*dapp_id, RankedTier::new_saturated(tier_id as u8, rank));
-                }
+            QueuedTierParams::<T>::put(params.clone());
 
-                // sum of all ranks for this tier
-                let ranks_sum = tier_slots
-                    .iter()
-                    .fold(0u32, |accum, (_, x)| accum.saturating_add(x.rank().into()));
+            Self::deposit_event(Event::<T>::TierParamsQueued { params });
 
-                let reward_per_rank = if ranks_sum.is_zero() {
-                    Balance::zero()
-                } else {
-                    // calculate reward per rank
-                    let tier_reward = tier_rewards.get(tier_id).copied().unwrap_or_default();
-                    let empty_slots = tier_capacity.saturating_sub(tier_slots.len() as u16);
-                    let remaining_reward = tier_reward.saturating_mul(empty_slots.into());
-                    // make sure required reward doesn't exceed remaining reward
-                    let reward_per_rank = tier_reward.saturating_div(RankedTier::MAX_RANK.into());
-                    let expected_reward_for_ranks =
-                        reward_per_rank.saturating_mul(ranks_sum.into());
-                    let reward_for_ranks = expected_reward_for_ranks.min(remaining_reward);
-                    // re-calculate reward per rank based on available reward
-                    reward_for_ranks.saturating_div(ranks_sum.into())
-                };
+            Ok(())
+        }
 
-                rank_rewards.push(reward_per_rank);
-                dapp_tiers.append(&mut tier_slots);
-                upper_bound = *lower_bound; // current threshold becomes upper bound for next tier
+        /// Stake the specified amounts on multiple smart contracts, in a single call.
+        ///
+        /// Each entry is handled the same way as a separate `stake` call.
+        /// Stakes are applied all-or-nothing - in case any of them fails, none are applied.
+        /// At least one entry must be provided.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::stake_many(stakes.len() as u32)
+            .saturating_add(Pallet::<T>::shared_rewards_payout_weight().saturating_mul(stakes.len() as u64)))]
+        pub fn stake_many(
+            origin: OriginFor<T>,
+            stakes: BoundedVec<(T::SmartContract, Balance), T::MaxNumberOfStakedContracts>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+            ensure!(!stakes.is_empty(), Error::<T>::ZeroAmount);
+            let len = stakes.len() as u32;
+
+            // Dispatchable is executed in its own storage layer, so in case of an error, all of the changes are reverted.
+            let mut paid_spans = 0_u32;
+            for (smart_contract, amount) in stakes {
+                paid_spans.saturating_accrue(Self::internal_stake(
+                    account.clone(),
+                    smart_contract,
+                    amount,
+                )?);
             }
 
-            // 5.
-            // Prepare and return tier & rewards info.
-            // In case rewards creation fails, we just write the default value. This should never happen though.
-            (
-                DAppTierRewards::<T::MaxNumberOfContracts, T::NumberOfTiers>::new(
-                    dapp_tiers,
-                    tier_rewards,
-                    period,
-                    rank_rewards,
-                )
-                .unwrap_or_default(),
-                counter,
+            Ok(Some(
+                T::WeightInfo::stake_many(len)
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans)),
             )
+            .into())
         }
 
-        /// Used to handle era & period transitions.
-        pub(crate) fn era_and_period_handler(
-            now: BlockNumber,
-            tier_assignment: TierAssignment,
-        ) -> Weight {
-            let mut protocol_state = ActiveProtocolState::<T>::get();
+        /// Unstake the specified amounts from multiple smart contracts, in a single call.
+        ///
+        /// Each entry is handled the same way as a separate `unstake` call.
+        /// Unstakes are applied all-or-nothing - in case any of them fails, none are applied.
+        /// At least one entry must be provided.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::unstake_many(unstakes.len() as u32)
+            .saturating_add(Pallet::<T>::shared_rewards_payout_weight().saturating_mul(unstakes.len() as u64)))]
+        pub fn unstake_many(
+            origin: OriginFor<T>,
+            unstakes: BoundedVec<(T::SmartContract, Balance), T::MaxNumberOfStakedContracts>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+            ensure!(!unstakes.is_empty(), Error::<T>::ZeroAmount);
+            let len = unstakes.len() as u32;
+
+            // Dispatchable is executed in its own storage layer, so in case of an error, all of the changes are reverted.
+            let mut paid_spans = 0_u32;
+            for (smart_contract, amount) in unstakes {
+                paid_spans.saturating_accrue(Self::internal_unstake(
+                    account.clone(),
+                    smart_contract,
+                    amount,
+                )?);
+            }
 
-            // `ActiveProtocolState` is whitelisted, so we need to account for its read.
-            let mut consumed_weight = T::DbWeight::get().reads(1);
+            Ok(Some(
+                T::WeightInfo::unstake_many(len)
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans)),
+            )
+            .into())
+        }
 
-            // We should not modify pallet storage while in maintenance mode.
-            // This is a safety measure, since maintenance mode is expected to be
-            // enabled in case some misbehavior or corrupted storage is detected.
-            if protocol_state.maintenance {
-                return consumed_weight;
-            }
+        /// Used to set the part of each dApp reward which is shared with the dApp stakers.
+        ///
+        /// Caller has to be dApp owner.
+        /// The share is applied to rewards of eras which end after this call. Setting it to zero disables reward sharing.
+        /// Stakers can claim their part, pro rata to their stake on the dApp, using `claim_shared_dapp_rewards`.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_dapp_reward_share())]
+        pub fn set_dapp_reward_share(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            share: Perbill,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
-            // Inform observers about the upcoming new era, if it's the case.
-            if protocol_state.next_era_start == now.saturating_add(1) {
-                consumed_weight
-                    .saturating_accrue(Self::notify_block_before_new_era(&protocol_state));
-            }
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
 
-            // Nothing to do if it's not new era
-            if !protocol_state.is_new_era(now) {
-                return consumed_weight;
+            if share.is_zero() {
+                DAppRewardShares::<T>::remove(&smart_contract);
+            } else {
+                DAppRewardShares::<T>::insert(&smart_contract, share);
             }
 
-            // At this point it's clear that an era change will happen
-            let mut era_info = CurrentEraInfo::<T>::get();
+            Self::deposit_event(Event::<T>::DAppRewardShareUpdated {
+                smart_contract,
+                share,
+            });
 
-            let current_era = protocol_state.era;
-            let next_era = current_era.saturating_add(1);
-            let (maybe_period_event, era_reward) = match protocol_state.subperiod() {
-                // Voting subperiod only lasts for one 'prolonged' era
-                Subperiod::Voting => {
-                    // For the sake of consistency, we put zero reward into storage. There are no rewards for the voting subperiod.
-                    let era_reward = EraReward {
-                        staker_reward_pool: Balance::zero(),
-                        staked: era_info.total_staked_amount(),
-                        dapp_reward_pool: Balance::zero(),
-                    };
+            Ok(())
+        }
 
-                    let next_subperiod_start_era = next_era
-                        .saturating_add(T::CycleConfiguration::eras_per_build_and_earn_subperiod());
-                    let build_and_earn_start_block =
-                        now.saturating_add(T::CycleConfiguration::blocks_per_era());
-                    protocol_state.advance_to_next_subperiod(
-                        next_subperiod_start_era,
-                        build_and_earn_start_block,
-                    );
+        /// Claims the caller's part of the dApp rewards shared with the stakers of the specified smart contract.
+        ///
+        /// Rewards are claimed for the eras covered by a single era reward span.
+        /// In case more eras are claimable, the call has to be repeated.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::claim_shared_dapp_rewards())]
+        pub fn claim_shared_dapp_rewards(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+            let smart_contract = Self::resolve_smart_contract(smart_contract);
 
-                    era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+            let staking_info = StakerInfo::<T>::get(&account, &smart_contract)
+                .ok_or(Error::<T>::NoClaimableRewards)?;
+            let (first_era, last_era) =
+                Self::shared_reward_claim_range(&account, &smart_contract, &staking_info)
+                    .ok_or(Error::<T>::NoClaimableRewards)?;
 
-                    consumed_weight
-                        .saturating_accrue(T::WeightInfo::on_initialize_voting_to_build_and_earn());
+            Self::payout_shared_rewards(
+                &account,
+                &smart_contract,
+                &staking_info,
+                first_era,
+                last_era,
+            )?;
 
-                    (
-                        Some(Event::<T>::NewSubperiod {
-                            subperiod: protocol_state.subperiod(),
-                            number: protocol_state.period_number(),
-                        }),
-                        era_reward,
+            Ok(())
+        }
+
+        /// Used to set or update the metadata of a dApp.
+        ///
+        /// Caller has to be dApp owner.
+        /// A deposit, proportional to the metadata size, is held from the caller.
+        /// In case metadata already exists, the previous deposit is released before the new one is held.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_dapp_metadata())]
+        pub fn set_dapp_metadata(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            name: BoundedVec<u8, T::MaxMetadataFieldLength>,
+            website: BoundedVec<u8, T::MaxMetadataFieldLength>,
+            ipfs_cid: BoundedVec<u8, T::MaxIpfsCidLength>,
+            category: DAppCategory,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+
+            let mut metadata = DAppMetadataFor::<T> {
+                name,
+                website,
+                ipfs_cid,
+                category,
+                depositor: dev_account.clone(),
+                deposit: Balance::zero(),
+            };
+            metadata.deposit = T::MetadataDepositBase::get().saturating_add(
+                T::MetadataDepositPerByte::get()
+                    .saturating_mul(metadata.encoded_fields_len().into()),
+            );
+
+            Self::remove_dapp_metadata(&smart_contract);
+            T::Currency::hold(
+                &HoldReason::DAppMetadata.into(),
+                &dev_account,
+                metadata.deposit,
+            )?;
+
+            let deposit = metadata.deposit;
+            DAppMetadataOf::<T>::insert(&smart_contract, metadata);
+
+            Self::deposit_event(Event::<T>::DAppMetadataSet {
+                smart_contract,
+                deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Used to clear the metadata of a dApp, releasing the held deposit.
+        ///
+        /// Caller has to be dApp owner.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::clear_dapp_metadata())]
+        pub fn clear_dapp_metadata(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+            ensure!(
+                DAppMetadataOf::<T>::contains_key(&smart_contract),
+                Error::<T>::NoDAppMetadata
+            );
+
+            Self::remove_dapp_metadata(&smart_contract);
+
+            Self::deposit_event(Event::<T>::DAppMetadataCleared { smart_contract });
+
+            Ok(())
+        }
+
+        /// Used to add a contract alias to the dApp.
+        ///
+        /// Alias has to be approved by the same origin which registers smart contracts, since the alias owner
+        /// otherwise has no say in which dApp the stake on it is counted towards.
+        /// Staking on the alias is equivalent to staking on the registered smart contract, and counts towards its stake & tier.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::add_contract_alias())]
+        pub fn add_contract_alias(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            alias: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            T::ContractRegisterOrigin::ensure_origin(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            ensure!(
+                !IntegratedDApps::<T>::contains_key(&alias)
+                    && !ContractAliases::<T>::contains_key(&alias),
+                Error::<T>::ContractAlreadyExists
+            );
+
+            DAppContractAliases::<T>::try_mutate(&dapp_info.id, |aliases| {
+                aliases
+                    .try_push(alias.clone())
+                    .map_err(|_| Error::<T>::TooManyContractAliases)
+            })?;
+            ContractAliases::<T>::insert(&alias, &smart_contract);
+
+            Self::deposit_event(Event::<T>::ContractAliasAdded {
+                smart_contract,
+                alias,
+            });
+
+            Ok(())
+        }
+
+        /// Used to remove a contract alias from the dApp.
+        ///
+        /// Caller has to be dApp owner.
+        /// Existing stakes aren't affected, since they are always tracked on the registered smart contract.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::remove_contract_alias())]
+        pub fn remove_contract_alias(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            alias: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+
+            DAppContractAliases::<T>::try_mutate(&dapp_info.id, |aliases| {
+                let index = aliases
+                    .iter()
+                    .position(|entry| *entry == alias)
+                    .ok_or(Error::<T>::ContractAliasNotFound)?;
+                aliases.remove(index);
+                Ok::<_, Error<T>>(())
+            })?;
+            ContractAliases::<T>::remove(&alias);
+
+            Self::deposit_event(Event::<T>::ContractAliasRemoved {
+                smart_contract,
+                alias,
+            });
+
+            Ok(())
+        }
+
+        /// Sets the share of claimed staker & bonus rewards paid out as a claim bounty to the account
+        /// claiming them on behalf of the caller, via `claim_staker_rewards_for` or `claim_bonus_reward_for`.
+        ///
+        /// The share mustn't exceed `MaxClaimBountyRate`. Setting it to zero opts out of paying the bounty,
+        /// which is also the default.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::set_claim_bounty())]
+        pub fn set_claim_bounty(origin: OriginFor<T>, rate: Perbill) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            ensure!(
+                rate <= T::MaxClaimBountyRate::get(),
+                Error::<T>::ClaimBountyTooLarge
+            );
+            ensure!(
+                !Ledger::<T>::get(&account).is_empty(),
+                Error::<T>::NoLockedFunds
+            );
+
+            StakerPreferencesOf::<T>::mutate(&account, |preferences| {
+                preferences.claim_bounty = rate;
+            });
+
+            Self::deposit_event(Event::<T>::ClaimBountySet { account, rate });
+
+            Ok(())
+        }
+
+        /// A call used to fix accounts with inconsistent state, where frozen balance is actually higher than what's available.
+        ///
+        /// The approach is as simple as possible:
+        /// 1. Caller provides an account to fix.
+        /// 2. If account is eligible for the fix, all unlocking chunks are modified to be claimable immediately.
+        /// 3. The `claim_unlocked` call is executed using the provided account as the origin.
+        /// 4. All states are updated accordingly, and the account is no longer in an inconsistent state.
+        ///
+        /// The benchmarked weight of the `claim_unlocked` call is used as a base, and additional overestimated weight is added.
+        /// Call doesn't touch any storage items that aren't already touched by the `claim_unlocked` call, hence the simplified approach.
+        #[pallet::call_index(100)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(4, 1))]
+        pub fn fix_account(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            ensure_signed(origin)?;
+
+            let mut ledger = Ledger::<T>::get(&account);
+            let locked_amount_ledger = ledger.total_locked_amount();
+            let total_balance = T::Currency::total_balance(&account);
+
+            if locked_amount_ledger > total_balance {
+                // 1. Modify all unlocking chunks so they can be unlocked immediately.
+                let current_block: BlockNumber =
+                    frame_system::Pallet::<T>::block_number().saturated_into();
+                ledger
+                    .unlocking
+                    .iter_mut()
+                    .for_each(|chunk| chunk.unlock_block = current_block);
+                Ledger::<T>::insert(&account, ledger);
+
+                // 2. Execute the unlock call, clearing all of the unlocking chunks.
+                Self::internal_claim_unlocked(account)?;
+
+                // 3. In case of success, ensure no fee is paid.
+                Ok(Pays::No.into())
+            } else {
+                // The above logic is designed for a specific scenario and cannot be used otherwise.
+                Err(Error::<T>::AccountNotInconsistent.into())
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if the account is a staker, `false` otherwise.
+        pub fn is_staker(account: &T::AccountId) -> bool {
+            Ledger::<T>::contains_key(account)
+        }
+
+        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
+        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
+            if ActiveProtocolState::<T>::get().maintenance {
+                Err(Error::<T>::Disabled)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Update the account ledger, and dApp staking balance freeze.
+        ///
+        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        ///
+        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
+        /// runtime definition must ensure it supports necessary freezes.
+        pub(crate) fn update_ledger(
+            account: &T::AccountId,
+            ledger: AccountLedgerFor<T>,
+        ) -> Result<(), DispatchError> {
+            if ledger.is_empty() {
+                Ledger::<T>::remove(&account);
+                StakerPreferencesOf::<T>::remove(&account);
+                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
+            } else {
+                T::Currency::set_freeze(
+                    &FreezeReason::DAppStaking.into(),
+                    account,
+                    ledger.total_locked_amount(),
+                )?;
+                Ledger::<T>::insert(account, ledger);
+            }
+
+            Ok(())
+        }
+
+        /// Returns the number of blocks per voting period.
+        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
+            T::CycleConfiguration::blocks_per_era()
+                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
+        }
+
+        /// Calculates the `EraRewardSpan` index for the specified era.
+        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
+            era.saturating_sub(era % T::EraRewardSpanLength::get())
+        }
+
+        /// Return the oldest period for which rewards can be claimed.
+        /// All rewards before that period are considered to be expired.
+        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
+            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
+        }
+
+        /// Unlocking period expressed in the number of blocks.
+        pub fn unlocking_period() -> BlockNumber {
+            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
+        }
+
+        /// Returns the dApp tier assignment for the current era, based on the current stake amounts.
+        pub fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
+                protocol_state.era,
+                protocol_state.period_number(),
+                Balance::zero(),
+            );
+
+            dapp_tiers.dapps.into_inner()
+        }
+
+        /// Returns the total staker reward amount that would be paid out to the account by the next
+        /// `claim_staker_rewards` call. In case there is nothing to claim, zero is returned.
+        ///
+        /// Storage isn't modified by this call.
+        pub fn get_claimable_staker_rewards(account: &T::AccountId) -> Balance {
+            let mut ledger = Ledger::<T>::get(account);
+
+            Self::calculate_staker_rewards(&mut ledger)
+                .map(|(rewards, _)| {
+                    rewards.iter().fold(Balance::zero(), |sum, (_, reward)| {
+                        sum.saturating_add(*reward)
+                    })
+                })
+                .unwrap_or_default()
+        }
+
+        /// Returns all the claimable staker rewards of the account, per era.
+        /// Unlike [`Self::get_claimable_staker_rewards`], this covers all the `claim_staker_rewards` calls required to claim everything.
+        ///
+        /// Storage isn't modified by this call.
+        pub fn get_claimable_staker_rewards_per_era(
+            account: &T::AccountId,
+        ) -> Vec<(EraNumber, Balance)> {
+            let mut ledger = Ledger::<T>::get(account);
+
+            // Each successful calculation consumes the claimed eras from the ledger, so this is guaranteed to terminate.
+            let mut rewards = Vec::new();
+            while let Ok((era_rewards, _)) = Self::calculate_staker_rewards(&mut ledger) {
+                rewards.extend(era_rewards);
+            }
+
+            rewards
+        }
+
+        /// Returns the bonus reward the account can claim for each smart contract it's eligible for.
+        ///
+        /// Storage isn't modified by this call.
+        pub fn get_claimable_bonus_rewards(
+            account: &T::AccountId,
+        ) -> Vec<(T::SmartContract, Balance)> {
+            StakerInfo::<T>::iter_prefix(account)
+                .filter_map(|(smart_contract, staker_info)| {
+                    Self::calculate_bonus_reward(&staker_info)
+                        .ok()
+                        .map(|bonus_reward| (smart_contract, bonus_reward))
+                })
+                .collect()
+        }
+
+        /// Returns the bonus reward the account can claim for the specified smart contract, or one of its aliases.
+        /// In case the account isn't eligible for the bonus reward, zero is returned.
+        ///
+        /// Storage isn't modified by this call.
+        pub fn get_claimable_bonus_reward(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> Balance {
+            StakerInfo::<T>::get(
+                account,
+                Self::resolve_smart_contract(smart_contract.clone()),
+            )
+            .and_then(|staker_info| Self::calculate_bonus_reward(&staker_info).ok())
+            .unwrap_or_default()
+        }
+
+        /// Returns the unclaimed, non-expired rewards of the specified dApp, per era.
+        /// Smart contract can also be one of the dApp's aliases.
+        ///
+        /// Storage isn't modified by this call.
+        pub fn get_claimable_dapp_rewards(
+            smart_contract: &T::SmartContract,
+        ) -> Vec<(EraNumber, Balance)> {
+            let smart_contract = Self::resolve_smart_contract(smart_contract.clone());
+            let dapp_id = match IntegratedDApps::<T>::get(&smart_contract) {
+                Some(dapp_info) => dapp_info.id,
+                None => return Vec::new(),
+            };
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let mut rewards: Vec<_> = DAppTiers::<T>::iter()
+                .filter(|(era, _)| *era < protocol_state.era)
+                .filter_map(|(era, mut dapp_tiers)| {
+                    Self::calculate_dapp_reward(
+                        dapp_id,
+                        &mut dapp_tiers,
+                        protocol_state.period_number(),
                     )
+                    .ok()
+                    .map(|(amount, _)| {
+                        (
+                            era,
+                            amount.saturating_sub(Self::shared_dapp_reward(&smart_contract, era)),
+                        )
+                    })
+                })
+                .collect();
+            rewards.sort_by_key(|(era, _)| *era);
+
+            rewards
+        }
+
+        /// Returns the number of unique stakers of the specified dApp, in the ongoing period.
+        /// Smart contract can also be one of the dApp's aliases.
+        ///
+        /// In case dApp isn't registered, zero is returned.
+        pub fn get_contract_staker_count(smart_contract: &T::SmartContract) -> u32 {
+            match IntegratedDApps::<T>::get(Self::resolve_smart_contract(smart_contract.clone())) {
+                Some(dapp_info) => ContractStakers::<T>::get(dapp_info.id)
+                    .count(ActiveProtocolState::<T>::get().period_number()),
+                None => 0,
+            }
+        }
+
+        /// Returns the registered smart contract to which the specified alias belongs.
+        ///
+        /// In case the specified smart contract isn't an alias, it's returned as is.
+        pub fn resolve_smart_contract(smart_contract: T::SmartContract) -> T::SmartContract {
+            ContractAliases::<T>::get(&smart_contract).unwrap_or(smart_contract)
+        }
+
+        /// Returns the metadata of the specified dApp, if it exists.
+        /// Smart contract can also be one of the dApp's aliases.
+        pub fn get_dapp_metadata(smart_contract: &T::SmartContract) -> Option<DAppMetadataFor<T>> {
+            DAppMetadataOf::<T>::get(Self::resolve_smart_contract(smart_contract.clone()))
+        }
+
+        /// Removes the dApp metadata, if it exists, and releases the deposit held for it.
+        pub(crate) fn remove_dapp_metadata(smart_contract: &T::SmartContract) {
+            if let Some(metadata) = DAppMetadataOf::<T>::take(smart_contract) {
+                let _ = T::Currency::release(
+                    &HoldReason::DAppMetadata.into(),
+                    &metadata.depositor,
+                    metadata.deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
+
+        /// Returns the archived period summaries of the specified dApp, from the oldest to the latest one.
+        ///
+        /// dApp is identified by its Id, so the archive remains accessible even after the dApp has been unregistered.
+        pub fn get_dapp_period_archive(
+            dapp_id: DAppId,
+        ) -> Vec<DAppPeriodSummary<T::NumberOfTiers>> {
+            DAppPeriodArchive::<T>::get(dapp_id).into_inner()
+        }
+
+        /// Simulates the dApp tier assignment & rewards for the next era, as if the specified stake deltas were applied.
+        ///
+        /// Positive delta increases the stake on the smart contract, while negative delta decreases it.
+        /// Deltas for smart contracts which aren't registered are ignored.
+        ///
+        /// Positive delta is treated as the stake of a new staker, so it also increases the number of unique stakers
+        /// of the dApp by one. This matters for tiers which require a minimum number of stakers.
+        ///
+        /// The current tier configuration and number of unique stakers are used, while the dApp reward pool
+        /// is derived from the total stake for the next era, including the deltas.
+        ///
+        /// Storage isn't modified by this call.
+        pub fn simulate_dapp_tier_assignment(
+            stake_deltas: Vec<(T::SmartContract, i128)>,
+        ) -> DAppTierRewardsFor<T> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let next_era = protocol_state.era.saturating_add(1);
+            let period = protocol_state.period_number();
+
+            // Stake amounts valid for the next era, for all staked dApps.
+            let mut dapp_stakes: BTreeMap<DAppId, StakeAmount> = ContractStake::<T>::iter()
+                .filter_map(|(dapp_id, contract_stake)| {
+                    contract_stake
+                        .get(next_era, period)
+                        .map(|stake_amount| (dapp_id, stake_amount))
+                })
+                .collect();
+
+            let mut total_staked = CurrentEraInfo::<T>::get().total_staked_amount_next_era();
+            let mut new_stakers = sp_std::collections::btree_set::BTreeSet::new();
+            for (smart_contract, delta) in stake_deltas {
+                let dapp_id = match IntegratedDApps::<T>::get(&smart_contract) {
+                    Some(dapp_info) => dapp_info.id,
+                    None => continue,
+                };
+                let stake_amount = dapp_stakes.entry(dapp_id).or_insert(StakeAmount {
+                    era: next_era,
+                    period,
+                    ..Default::default()
+                });
+
+                let amount = delta.unsigned_abs();
+                if delta.is_negative() {
+                    let amount = amount.min(stake_amount.total());
+                    stake_amount.subtract(amount);
+                    total_staked.saturating_reduce(amount);
+                } else if !amount.is_zero() {
+                    stake_amount.add(amount, protocol_state.subperiod());
+                    total_staked.saturating_accrue(amount);
+                    new_stakers.insert(dapp_id);
+                }
+            }
+
+            let dapp_stakes = dapp_stakes
+                .into_iter()
+                .filter(|(_, stake_amount)| !stake_amount.total().is_zero())
+                .map(|(dapp_id, stake_amount)| {
+                    let number_of_stakers = ContractStakers::<T>::get(&dapp_id)
+                        .count(period)
+                        .saturating_add(new_stakers.contains(&dapp_id).into());
+                    (dapp_id, stake_amount, number_of_stakers)
+                })
+                .collect();
+            let (_, dapp_reward_pool) =
+                T::StakingRewardHandler::staker_and_dapp_reward_pools(total_staked);
+
+            Self::calculate_dapp_tier_assignment_and_rewards(dapp_stakes, period, dapp_reward_pool)
+        }
+
+        /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
+        ///
+        /// ### Algorithm
+        ///
+        /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
+        ///    Number of unique stakers is read for each dApp with a non-zero stake.
+        ///
+        /// 2. Assign dApps into tiers using the configured `TierAssignment` algorithm.
+        ///    Assignment which doesn't respect the tier configuration is defensively ignored.
+        ///
+        /// 3. Calculate rewards for each tier.
+        ///    This is done by dividing the total reward pool into tier reward pools,
+        ///    after which the tier reward pool is divided by the number of available slots in the tier.
+        ///
+        /// 4. Calculate reward per rank for each tier, using the remaining reward of the tier's empty slots.
+        ///
+        /// The returned object contains information about each dApp that made it into a tier.
+        /// Alongside tier assignment info, number of read DB contract stake entries is returned.
+        pub(crate) fn get_dapp_tier_assignment_and_rewards(
+            era: EraNumber,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> (DAppTierRewardsFor<T>, DAppId) {
+            let mut dapp_stakes = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
+
+            // 1.
+            // Iterate over all staked dApps.
+            // This is bounded by max amount of dApps we allow to be registered.
+            let mut counter = 0;
+            for (dapp_id, stake_amount) in ContractStake::<T>::iter() {
+                counter.saturating_inc();
+
+                // Skip dApps which don't have ANY amount staked
+                if let Some(stake_amount) = stake_amount.get(era, period) {
+                    if !stake_amount.total().is_zero() {
+                        let number_of_stakers = ContractStakers::<T>::get(&dapp_id).count(period);
+                        dapp_stakes.push((dapp_id, stake_amount, number_of_stakers));
+                    }
+                }
+            }
+
+            (
+                Self::calculate_dapp_tier_assignment_and_rewards(
+                    dapp_stakes,
+                    period,
+                    dapp_reward_pool,
+                ),
+                counter,
+            )
+        }
+
+        /// Assign the provided dApp stakes into appropriate tiers, and calculate reward for each tier.
+        ///
+        /// Steps 2 - 4 of the `get_dapp_tier_assignment_and_rewards` algorithm.
+        /// Apart from the tier configuration, no storage is read.
+        fn calculate_dapp_tier_assignment_and_rewards(
+            dapp_stakes: Vec<(DAppId, StakeAmount, u32)>,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> DAppTierRewardsFor<T> {
+            let tier_config = TierConfig::<T>::get();
+
+            // 2.
+            // Assign dApps into tiers, ensuring tier capacities are respected.
+            let mut dapp_tiers = T::TierAssignment::assign_tiers(dapp_stakes, &tier_config);
+
+            use sp_std::vec;
+            let mut tier_counts = vec![0_u16; tier_config.slots_per_tier.len()];
+            let mut ranks_sums = vec![0_u32; tier_config.slots_per_tier.len()];
+            dapp_tiers.retain(|_, ranked_tier| {
+                let tier_id = ranked_tier.tier() as usize;
+                match (
+                    tier_config.slots_per_tier.get(tier_id),
+                    tier_counts.get_mut(tier_id),
+                    ranks_sums.get_mut(tier_id),
+                ) {
+                    (Some(tier_capacity), Some(tier_count), Some(ranks_sum))
+                        if *tier_count < *tier_capacity =>
+                    {
+                        tier_count.saturating_inc();
+                        *ranks_sum = ranks_sum.saturating_add(ranked_tier.rank().into());
+                        true
+                    }
+                    _ => false,
+                }
+            });
+
+            // 3. Calculate rewards.
+            let tier_rewards = tier_config
+                .reward_portion
+                .iter()
+                .zip(tier_config.slots_per_tier.iter())
+                .map(|(percent, slots)| {
+                    if slots.is_zero() {
+                        Zero::zero()
+                    } else {
+                        *percent * dapp_reward_pool / <u16 as Into<Balance>>::into(*slots)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            // 4.
+            // Calculate reward per rank for each tier.
+            let rank_rewards = tier_config
+                .slots_per_tier
+                .iter()
+                .zip(tier_counts.iter().zip(ranks_sums.iter()))
+                .enumerate()
+                .map(|(tier_id, (tier_capacity, (tier_count, ranks_sum)))| {
+                    if ranks_sum.is_zero() {
+                        Balance::zero()
+                    } else {
+                        // calculate reward per rank
+                        let tier_reward = tier_rewards.get(tier_id).copied().unwrap_or_default();
+                        let empty_slots = tier_capacity.saturating_sub(*tier_count);
+                        let remaining_reward = tier_reward.saturating_mul(empty_slots.into());
+                        // make sure required reward doesn't exceed remaining reward
+                        let reward_per_rank =
+                            tier_reward.saturating_div(RankedTier::MAX_RANK.into());
+                        let expected_reward_for_ranks =
+                            reward_per_rank.saturating_mul((*ranks_sum).into());
+                        let reward_for_ranks = expected_reward_for_ranks.min(remaining_reward);
+                        // re-calculate reward per rank based on available reward
+                        reward_for_ranks.saturating_div((*ranks_sum).into())
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            // 5.
+            // Prepare and return tier & rewards info.
+            // In case rewards creation fails, we just write the default value. This should never happen though.
+            DAppTierRewards::<T::MaxNumberOfContracts, T::NumberOfTiers>::new(
+                dapp_tiers,
+                tier_rewards,
+                period,
+                rank_rewards,
+            )
+            .unwrap_or_default()
+        }
+
+        /// Used to handle era & period transitions.
+        pub(crate) fn era_and_period_handler(
+            now: BlockNumber,
+            tier_assignment: TierAssignmentMode,
+        ) -> Weight {
+            let mut protocol_state = ActiveProtocolState::<T>::get();
+
+            // `ActiveProtocolState` is whitelisted, so we need to account for its read.
+            let mut consumed_weight = T::DbWeight::get().reads(1);
+
+            // We should not modify pallet storage while in maintenance mode.
+            // This is a safety measure, since maintenance mode is expected to be
+            // enabled in case some misbehavior or corrupted storage is detected.
+            if protocol_state.maintenance {
+                return consumed_weight;
+            }
+
+            // Inform observers about the upcoming new era, if it's the case.
+            if protocol_state.next_era_start == now.saturating_add(1) {
+                consumed_weight
+                    .saturating_accrue(Self::notify_block_before_new_era(&protocol_state));
+            }
+
+            // Nothing to do if it's not new era
+            if !protocol_state.is_new_era(now) {
+                return consumed_weight;
+            }
+
+            // At this point it's clear that an era change will happen
+            let mut era_info = CurrentEraInfo::<T>::get();
+
+            let current_era = protocol_state.era;
+            let next_era = current_era.saturating_add(1);
+            let (maybe_period_event, era_reward) = match protocol_state.subperiod() {
+                // Voting subperiod only lasts for one 'prolonged' era
+                Subperiod::Voting => {
+                    // For the sake of consistency, we put zero reward into storage. There are no rewards for the voting subperiod.
+                    let era_reward = EraReward {
+                        staker_reward_pool: Balance::zero(),
+                        staked: era_info.total_staked_amount(),
+                        dapp_reward_pool: Balance::zero(),
+                    };
+
+                    let next_subperiod_start_era = next_era
+                        .saturating_add(T::CycleConfiguration::eras_per_build_and_earn_subperiod());
+                    let build_and_earn_start_block =
+                        now.saturating_add(T::CycleConfiguration::blocks_per_era());
+                    protocol_state.advance_to_next_subperiod(
+                        next_subperiod_start_era,
+                        build_and_earn_start_block,
+                    );
+
+                    era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+
+                    consumed_weight
+                        .saturating_accrue(T::WeightInfo::on_initialize_voting_to_build_and_earn());
+
+                    (
+                        Some(Event::<T>::NewSubperiod {
+                            subperiod: protocol_state.subperiod(),
+                            number: protocol_state.period_number(),
+                        }),
+                        era_reward,
+                    )
+                }
+                Subperiod::BuildAndEarn => {
+                    let staked = era_info.total_staked_amount();
+                    let (staker_reward_pool, dapp_reward_pool) =
+                        T::StakingRewardHandler::staker_and_dapp_reward_pools(staked);
+                    let era_reward = EraReward {
+                        staker_reward_pool,
+                        staked,
+                        dapp_reward_pool,
+                    };
+
+                    // Distribute dapps into tiers, write it into storage
+                    //
+                    // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
+                    // This must never be used in production code, obviously.
+                    let (dapp_tier_rewards, counter) = match tier_assignment {
+                        TierAssignmentMode::Real => Self::get_dapp_tier_assignment_and_rewards(
+                            current_era,
+                            protocol_state.period_number(),
+                            dapp_reward_pool,
+                        ),
+                        #[cfg(feature = "runtime-benchmarks")]
+                        TierAssignmentMode::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
+                    };
+                    Self::update_dapp_period_archive(current_era, &dapp_tier_rewards);
+                    Self::update_dapp_shared_rewards(current_era, &dapp_tier_rewards);
+                    DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
+
+                    consumed_weight
+                        .saturating_accrue(T::WeightInfo::dapp_tier_assignment(counter.into()));
+
+                    // Switch to `Voting` period if conditions are met.
+                    if protocol_state.period_info.is_next_period(next_era) {
+                        // Store info about period end
+                        let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
+                        PeriodEnd::<T>::insert(
+                            &protocol_state.period_number(),
+                            PeriodEndInfo {
+                                bonus_reward_pool,
+                                total_vp_stake: era_info.staked_amount(Subperiod::Voting),
+                                final_era: current_era,
+                            },
+                        );
+
+                        // For the sake of consistency we treat the whole `Voting` period as a single era.
+                        // This means no special handling is required for this period, it only lasts potentially longer than a single standard era.
+                        let next_subperiod_start_era = next_era.saturating_add(1);
+                        let voting_period_length = Self::blocks_per_voting_period();
+                        let next_era_start_block = now.saturating_add(voting_period_length);
+
+                        protocol_state.advance_to_next_subperiod(
+                            next_subperiod_start_era,
+                            next_era_start_block,
+                        );
+
+                        era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+
+                        // Update historical cleanup marker.
+                        // Must be called with the new period number.
+                        Self::update_cleanup_marker(protocol_state.period_number());
+
+                        // Apply the queued tier parameters, so the new period starts with them.
+                        consumed_weight.saturating_accrue(T::DbWeight::get().reads(1));
+                        if let Some(tier_params) = QueuedTierParams::<T>::take() {
+                            StaticTierParams::<T>::put(tier_params);
+                            consumed_weight.saturating_accrue(T::DbWeight::get().writes(2));
+                            Self::deposit_event(Event::<T>::TierParamsApplied {
+                                period: protocol_state.period_number(),
+                            });
+                        }
+
+                        consumed_weight.saturating_accrue(
+                            T::WeightInfo::on_initialize_build_and_earn_to_voting(),
+                        );
+
+                        (
+                            Some(Event::<T>::NewSubperiod {
+                                subperiod: protocol_state.subperiod(),
+                                number: protocol_state.period_number(),
+                            }),
+                            era_reward,
+                        )
+                    } else {
+                        let next_era_start_block =
+                            now.saturating_add(T::CycleConfiguration::blocks_per_era());
+                        protocol_state.next_era_start = next_era_start_block;
+
+                        era_info.migrate_to_next_era(None);
+
+                        consumed_weight.saturating_accrue(
+                            T::WeightInfo::on_initialize_build_and_earn_to_build_and_earn(),
+                        );
+
+                        (None, era_reward)
+                    }
+                }
+            };
+
+            // Update storage items
+            protocol_state.era = next_era;
+            ActiveProtocolState::<T>::put(protocol_state);
+
+            CurrentEraInfo::<T>::put(era_info);
+
+            let era_span_index = Self::era_reward_span_index(current_era);
+            let mut span = EraRewards::<T>::get(&era_span_index).unwrap_or(EraRewardSpan::new());
+            if let Err(_) = span.push(current_era, era_reward) {
+                // This must never happen but we log the error just in case.
+                log::error!(
+                    target: LOG_TARGET,
+                    "Failed to push era {} into the era reward span.",
+                    current_era
+                );
+            }
+            EraRewards::<T>::insert(&era_span_index, span);
+
+            // Re-calculate tier configuration for the upcoming new era
+            let tier_params = StaticTierParams::<T>::get();
+            let average_price = T::NativePriceProvider::average_price();
+            let total_issuance = T::Currency::total_issuance();
+
+            let new_tier_config =
+                TierConfig::<T>::get().calculate_new(&tier_params, average_price, total_issuance);
+
+            // Validate new tier configuration
+            if new_tier_config.is_valid() {
+                TierConfig::<T>::put(new_tier_config);
+            } else {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "New tier configuration is invalid for era {}, preserving old one.",
+                    next_era
+                );
+            }
+
+            Self::deposit_event(Event::<T>::NewEra { era: next_era });
+            if let Some(period_event) = maybe_period_event {
+                Self::deposit_event(period_event);
+            }
+
+            consumed_weight
+        }
+
+        /// Records the tier assignment of the specified era into the period archive of each dApp in tiers.
+        ///
+        /// Weight is accounted for as part of the `dapp_tier_assignment` weight.
+        pub(crate) fn update_dapp_period_archive(
+            era: EraNumber,
+            dapp_tier_rewards: &DAppTierRewardsFor<T>,
+        ) {
+            let period = dapp_tier_rewards.period;
+
+            for (dapp_id, ranked_tier) in dapp_tier_rewards.dapps.iter() {
+                let reward = dapp_tier_rewards.reward_for(*ranked_tier);
+                let stake = ContractStake::<T>::get(dapp_id)
+                    .get(era, period)
+                    .map_or(Balance::zero(), |stake| stake.total());
+
+                DAppPeriodArchive::<T>::mutate(dapp_id, |archive| match archive.last_mut() {
+                    Some(summary) if summary.period == period => {
+                        summary.record_era(ranked_tier.tier(), reward, stake);
+                    }
+                    _ => {
+                        let mut summary = DAppPeriodSummary::new(period);
+                        summary.record_era(ranked_tier.tier(), reward, stake);
+
+                        // Make room for the new summary by removing the oldest one.
+                        if archive.is_full() && !archive.is_empty() {
+                            archive.remove(0);
+                        }
+                        let _ = archive.try_push(summary);
+                    }
+                });
+            }
+        }
+
+        /// Records the dApp rewards of the specified era which are shared with the stakers,
+        /// for each dApp that opted into reward sharing.
+        ///
+        /// Weight is accounted for as part of the `dapp_tier_assignment` weight.
+        pub(crate) fn update_dapp_shared_rewards(
+            era: EraNumber,
+            dapp_tier_rewards: &DAppTierRewardsFor<T>,
+        ) {
+            let period = dapp_tier_rewards.period;
+            let span_index = Self::era_reward_span_index(era);
+
+            for (smart_contract, share) in DAppRewardShares::<T>::iter() {
+                let dapp_id = match IntegratedDApps::<T>::get(&smart_contract) {
+                    Some(dapp_info) => dapp_info.id,
+                    None => continue,
+                };
+
+                let staked = ContractStake::<T>::get(&dapp_id)
+                    .get(era, period)
+                    .map_or(Balance::zero(), |stake| stake.total());
+                // Nothing to share if there are no stakers.
+                let staker_reward_pool = if staked.is_zero() {
+                    Balance::zero()
+                } else {
+                    dapp_tier_rewards
+                        .dapps
+                        .get(&dapp_id)
+                        .map_or(Balance::zero(), |ranked_tier| {
+                            share * dapp_tier_rewards.reward_for(*ranked_tier)
+                        })
+                };
+
+                let mut span = DAppSharedRewards::<T>::get(&span_index, &smart_contract)
+                    .unwrap_or(EraRewardSpan::new());
+                // Fill in the eras in which no rewards were shared, e.g. the voting subperiod era.
+                while !span.is_empty() && span.last_era().saturating_add(1) < era {
+                    if span
+                        .push(span.last_era().saturating_add(1), EraReward::default())
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                if let Err(_) = span.push(
+                    era,
+                    EraReward {
+                        staker_reward_pool,
+                        staked,
+                        dapp_reward_pool: Balance::zero(),
+                    },
+                ) {
+                    // This must never happen but we log the error just in case.
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Failed to push era {} into the shared dApp reward span.",
+                        era
+                    );
+                }
+                DAppSharedRewards::<T>::insert(&span_index, &smart_contract, span);
+
+                if !staker_reward_pool.is_zero() {
+                    DAppLastSharedEra::<T>::insert(&smart_contract, era);
+                }
+            }
+        }
+
+        /// Used to notify observers about the upcoming new era in the next block.
+        fn notify_block_before_new_era(protocol_state: &ProtocolState) -> Weight {
+            let next_era = protocol_state.era.saturating_add(1);
+            T::Observers::block_before_new_era(next_era)
+        }
+
+        /// Updates the cleanup marker with the new oldest valid era if possible.
+        ///
+        /// It's possible that the call will be a no-op since we haven't advanced enough periods yet.
+        fn update_cleanup_marker(new_period_number: PeriodNumber) {
+            // 1. Find out the latest expired period; rewards can no longer be claimed for it or any older period.
+            let latest_expired_period = match new_period_number
+                .checked_sub(T::RewardRetentionInPeriods::get().saturating_add(1))
+            {
+                Some(period) if !period.is_zero() => period,
+                // Haven't advanced enough periods to have any expired entries.
+                _ => return,
+            };
+
+            // 2. Find the oldest valid era for which rewards can still be claimed.
+            //    Technically, this will be `Voting` subperiod era but it doesn't matter.
+            //
+            //    Also, remove the expired `PeriodEnd` entry since it's no longer needed.
+            let oldest_valid_era = match PeriodEnd::<T>::take(latest_expired_period) {
+                Some(period_end_info) => period_end_info.final_era.saturating_add(1),
+                None => {
+                    // Should never happen but nothing we can do if it does.
+                    log::error!(
+                        target: LOG_TARGET,
+                        "No `PeriodEnd` entry for the expired period: {}",
+                        latest_expired_period
+                    );
+                    return;
+                }
+            };
+
+            // 3. Update the cleanup marker with the new oldest valid era.
+            HistoryCleanupMarker::<T>::mutate(|marker| {
+                marker.oldest_valid_era = oldest_valid_era;
+            });
+        }
+
+        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
+        ///
+        /// Returns consumed weight.
+        fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
+            // Need to be able to process one full pass
+            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
+                return Weight::zero();
+            }
+
+            // Get the cleanup marker and ensure we have pending cleanups.
+            let mut cleanup_marker = HistoryCleanupMarker::<T>::get();
+            if !cleanup_marker.has_pending_cleanups() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // 1. Attempt to cleanup one expired `EraRewards` entry, alongside the matching shared dApp rewards.
+            let mut removed_shared_rewards = 0_u32;
+            if cleanup_marker.era_reward_index < cleanup_marker.oldest_valid_era {
+                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
+                    // If oldest valid era comes AFTER this span, it's safe to delete it.
+                    if era_reward.last_era() < cleanup_marker.oldest_valid_era {
+                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
+                        removed_shared_rewards = DAppSharedRewards::<T>::clear_prefix(
+                            cleanup_marker.era_reward_index,
+                            T::MaxNumberOfContracts::get(),
+                            None,
+                        )
+                        .unique;
+                        cleanup_marker
+                            .era_reward_index
+                            .saturating_accrue(T::EraRewardSpanLength::get());
+                    }
+                } else {
+                    // Can happen if the entry is part of history before dApp staking v3
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Era rewards span for era {} is missing, but cleanup marker is set.",
+                        cleanup_marker.era_reward_index
+                    );
+                    cleanup_marker
+                        .era_reward_index
+                        .saturating_accrue(T::EraRewardSpanLength::get());
+                }
+            }
+
+            // 2. Attempt to cleanup one expired `DAppTiers` entry.
+            if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
+                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
+                cleanup_marker.dapp_tiers_index.saturating_inc();
+            }
+
+            // Store the updated cleanup marker
+            HistoryCleanupMarker::<T>::put(cleanup_marker);
+
+            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
+            // we opt for the simpler solution where only 1 entry per block is cleaned up.
+            // It can be changed though.
+
+            // It could end up being less than this weight, but this won't occur often enough to be important.
+            T::WeightInfo::on_idle_cleanup()
+                .saturating_add(T::DbWeight::get().writes(removed_shared_rewards.into()))
+        }
+
+        /// Internal function that executes the `claim_unlocked` logic for the specified account.
+        fn internal_claim_unlocked(account: T::AccountId) -> DispatchResultWithPostInfo {
+            let mut ledger = Ledger::<T>::get(&account);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let amount = ledger.claim_unlocked(current_block.saturated_into());
+            ensure!(amount > Zero::zero(), Error::<T>::NoUnlockedChunksToClaim);
+
+            // In case it's full unlock, account is exiting dApp staking, ensure all storage is cleaned up.
+            let removed_entries = if ledger.is_empty() {
+                let _ = StakerInfo::<T>::clear_prefix(&account, ledger.contract_stake_count, None);
+                ledger.contract_stake_count
+            } else {
+                0
+            };
+
+            Self::update_ledger(&account, ledger)?;
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unlocking_removed(amount);
+            });
+
+            Self::deposit_event(Event::<T>::ClaimedUnlocked { account, amount });
+
+            Ok(Some(T::WeightInfo::claim_unlocked(removed_entries)).into())
+        }
+
+        /// Internal function that executes the `stake` logic for the specified account & smart contract.
+        ///
+        /// Returns the number of era reward spans for which the pending shared dApp rewards were paid out.
+        fn internal_stake(
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) -> Result<u32, DispatchError> {
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+            let smart_contract = Self::resolve_smart_contract(smart_contract);
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            ensure!(
+                !protocol_state
+                    .period_info
+                    .is_next_period(current_era.saturating_add(1)),
+                Error::<T>::PeriodEndsInNextEra
+            );
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // In case old stake rewards are unclaimed & have expired, clean them up.
+            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
+            let _ignore = ledger.maybe_cleanup_expired(threshold_period);
+
+            // 1.
+            // Increase stake amount for the next era & current period in staker's ledger
+            ledger
+                .add_stake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    AccountLedgerError::UnavailableStakeFunds => Error::<T>::UnavailableStakeFunds,
+                    // Defensive check, should never happen
+                    _ => Error::<T>::InternalStakeError,
+                })?;
+
+            // 2.
+            // Update `StakerInfo` storage with the new stake amount on the specified contract.
+            //
+            // There are two distinct scenarios:
+            // 1. Existing entry matches the current period number - just update it.
+            // 2. Entry doesn't exist or it's for an older period - create a new one.
+            //
+            // This is ok since we only use this storage entry to keep track of how much each staker
+            // has staked on each contract in the current period. We only ever need the latest information.
+            // This is because `AccountLedger` is the one keeping information about how much was staked when.
+            let existing_staking_info = StakerInfo::<T>::get(&account, &smart_contract);
+            // Shared dApp rewards must be paid out before the entry is modified.
+            let paid_spans = match &existing_staking_info {
+                Some(staking_info) => {
+                    Self::payout_pending_shared_rewards(&account, &smart_contract, staking_info)?
                 }
-                Subperiod::BuildAndEarn => {
-                    let staked = era_info.total_staked_amount();
-                    let (staker_reward_pool, dapp_reward_pool) =
-                        T::StakingRewardHandler::staker_and_dapp_reward_pools(staked);
-                    let era_reward = EraReward {
-                        staker_reward_pool,
-                        staked,
-                        dapp_reward_pool,
-                    };
+                None => 0,
+            };
+            let (mut new_staking_info, is_new_entry) = match existing_staking_info {
+                // Entry with matching period exists
+                Some(staking_info)
+                    if staking_info.period_number() == protocol_state.period_number() =>
+                {
+                    (staking_info, false)
+                }
+                // Entry exists but period doesn't match. Bonus reward might still be claimable.
+                Some(staking_info)
+                    if staking_info.period_number() >= threshold_period
+                        && staking_info.is_loyal() =>
+                {
+                    return Err(Error::<T>::UnclaimedRewards.into());
+                }
+                // No valid entry exists
+                _ => (
+                    SingularStakingInfo::new(
+                        protocol_state.period_number(),
+                        protocol_state.subperiod(),
+                    ),
+                    true,
+                ),
+            };
+            new_staking_info.stake(amount, current_era, protocol_state.subperiod());
+            ensure!(
+                new_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
+                Error::<T>::InsufficientStakeAmount
+            );
 
-                    // Distribute dapps into tiers, write it into storage
-                    //
-                    // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
-                    // This must never be used in production code, obviously.
-                    let (dapp_tier_rewards, counter) = match tier_assignment {
-                        TierAssignment::Real => Self::get_dapp_tier_assignment_and_rewards(
-                            current_era,
-                            protocol_state.period_number(),
-                            dapp_reward_pool,
-                        ),
-                        #[cfg(feature = "runtime-benchmarks")]
-                        TierAssignment::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
-                    };
-                    DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
+            if is_new_entry {
+                ledger.contract_stake_count.saturating_inc();
+                ensure!(
+                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
+                    Error::<T>::TooManyStakedContracts
+                );
+                ContractStakers::<T>::mutate(&dapp_info.id, |staker_count| {
+                    staker_count.increase(protocol_state.period_number())
+                });
+            }
 
-                    consumed_weight
-                        .saturating_accrue(T::WeightInfo::dapp_tier_assignment(counter.into()));
+            // 3.
+            // Update `ContractStake` storage with the new stake amount on the specified contract.
+            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
+            contract_stake_info.stake(amount, protocol_state.period_info, current_era);
 
-                    // Switch to `Voting` period if conditions are met.
-                    if protocol_state.period_info.is_next_period(next_era) {
-                        // Store info about period end
-                        let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
-                        PeriodEnd::<T>::insert(
-                            &protocol_state.period_number(),
-                            PeriodEndInfo {
-                                bonus_reward_pool,
-                                total_vp_stake: era_info.staked_amount(Subperiod::Voting),
-                                final_era: current_era,
-                            },
-                        );
+            // 4.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_stake_amount(amount, protocol_state.subperiod());
+            });
 
-                        // For the sake of consistency we treat the whole `Voting` period as a single era.
-                        // This means no special handling is required for this period, it only lasts potentially longer than a single standard era.
-                        let next_subperiod_start_era = next_era.saturating_add(1);
-                        let voting_period_length = Self::blocks_per_voting_period();
-                        let next_era_start_block = now.saturating_add(voting_period_length);
+            // 5.
+            // Update remaining storage entries
+            Self::update_ledger(&account, ledger)?;
+            StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
 
-                        protocol_state.advance_to_next_subperiod(
-                            next_subperiod_start_era,
-                            next_era_start_block,
-                        );
+            Self::deposit_event(Event::<T>::Stake {
+                account,
+                smart_contract,
+                amount,
+            });
 
-                        era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+            Ok(paid_spans)
+        }
 
-                        // Update historical cleanup marker.
-                        // Must be called with the new period number.
-                        Self::update_cleanup_marker(protocol_state.period_number());
+        /// Internal function that executes the `unstake` logic for the specified account & smart contract.
+        ///
+        /// Returns the number of era reward spans for which the pending shared dApp rewards were paid out.
+        fn internal_unstake(
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) -> Result<u32, DispatchError> {
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
 
-                        consumed_weight.saturating_accrue(
-                            T::WeightInfo::on_initialize_build_and_earn_to_voting(),
-                        );
+            let smart_contract = Self::resolve_smart_contract(smart_contract);
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
-                        (
-                            Some(Event::<T>::NewSubperiod {
-                                subperiod: protocol_state.subperiod(),
-                                number: protocol_state.period_number(),
-                            }),
-                            era_reward,
-                        )
-                    } else {
-                        let next_era_start_block =
-                            now.saturating_add(T::CycleConfiguration::blocks_per_era());
-                        protocol_state.next_era_start = next_era_start_block;
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
 
-                        era_info.migrate_to_next_era(None);
+            let mut ledger = Ledger::<T>::get(&account);
 
-                        consumed_weight.saturating_accrue(
-                            T::WeightInfo::on_initialize_build_and_earn_to_build_and_earn(),
+            // 1.
+            // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
+            let mut paid_spans = 0;
+            let (new_staking_info, amount, era_and_amount_pairs) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(mut staking_info) => {
+                        ensure!(
+                            staking_info.period_number() == protocol_state.period_number(),
+                            Error::<T>::UnstakeFromPastPeriod
+                        );
+                        paid_spans = Self::payout_pending_shared_rewards(
+                            &account,
+                            &smart_contract,
+                            &staking_info,
+                        )?;
+                        ensure!(
+                            staking_info.total_staked_amount() >= amount,
+                            Error::<T>::UnstakeAmountTooLarge
                         );
 
-                        (None, era_reward)
-                    }
-                }
-            };
+                        // If unstaking would take the total staked amount below the minimum required value,
+                        // unstake everything.
+                        let amount = if staking_info.total_staked_amount().saturating_sub(amount)
+                            < T::MinimumStakeAmount::get()
+                        {
+                            staking_info.total_staked_amount()
+                        } else {
+                            amount
+                        };
 
-            // Update storage items
-            protocol_state.era = next_era;
-            ActiveProtocolState::<T>::put(protocol_state);
+                        let era_and_amount_pairs =
+                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
 
-            CurrentEraInfo::<T>::put(era_info);
+                        (staking_info, amount, era_and_amount_pairs)
+                    }
+                    None => {
+                        return Err(Error::<T>::NoStakingInfo.into());
+                    }
+                };
 
-            let era_span_index = Self::era_reward_span_index(current_era);
-            let mut span = EraRewards::<T>::get(&era_span_index).unwrap_or(EraRewardSpan::new());
-            if let Err(_) = span.push(current_era, era_reward) {
-                // This must never happen but we log the error just in case.
-                log::error!(
-                    target: LOG_TARGET,
-                    "Failed to push era {} into the era reward span.",
-                    current_era
-                );
-            }
-            EraRewards::<T>::insert(&era_span_index, span);
+            // 2.
+            // Reduce stake amount
+            ledger
+                .unstake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    // This is a defensive check, which should never happen since we calculate the correct value above.
+                    AccountLedgerError::UnstakeAmountLargerThanStake => {
+                        Error::<T>::UnstakeAmountTooLarge
+                    }
+                    _ => Error::<T>::InternalUnstakeError,
+                })?;
 
-            // Re-calculate tier configuration for the upcoming new era
-            let tier_params = StaticTierParams::<T>::get();
-            let average_price = T::NativePriceProvider::average_price();
-            let total_issuance = T::Currency::total_issuance();
+            // 3.
+            // Update `ContractStake` storage with the reduced stake amount on the specified contract.
+            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
+            contract_stake_info.unstake(
+                era_and_amount_pairs,
+                protocol_state.period_info,
+                current_era,
+            );
 
-            let new_tier_config =
-                TierConfig::<T>::get().calculate_new(&tier_params, average_price, total_issuance);
+            // 4.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unstake_amount(amount);
+            });
 
-            // Validate new tier configuration
-            if new_tier_config.is_valid() {
-                TierConfig::<T>::put(new_tier_config);
-            } else {
-                log::warn!(
-                    target: LOG_TARGET,
-                    "New tier configuration is invalid for era {}, preserving old one.",
-                    next_era
-                );
-            }
+            // 5.
+            // Update remaining storage entries
+            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
 
-            Self::deposit_event(Event::<T>::NewEra { era: next_era });
-            if let Some(period_event) = maybe_period_event {
-                Self::deposit_event(period_event);
+            if new_staking_info.is_empty() {
+                ledger.contract_stake_count.saturating_dec();
+                StakerInfo::<T>::remove(&account, &smart_contract);
+                SharedRewardClaimMarkers::<T>::remove(&account, &smart_contract);
+                ContractStakers::<T>::mutate(&dapp_info.id, |staker_count| {
+                    staker_count.decrease(protocol_state.period_number())
+                });
+            } else {
+                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
             }
 
-            consumed_weight
-        }
+            Self::update_ledger(&account, ledger)?;
 
-        /// Used to notify observers about the upcoming new era in the next block.
-        fn notify_block_before_new_era(protocol_state: &ProtocolState) -> Weight {
-            let next_era = protocol_state.era.saturating_add(1);
-            T::Observers::block_before_new_era(next_era)
+            Self::deposit_event(Event::<T>::Unstake {
+                account,
+                smart_contract,
+                amount,
+            });
+
+            Ok(())
         }
 
-        /// Updates the cleanup marker with the new oldest valid era if possible.
+        /// Internal function that executes the `claim_staker_rewards_` logic for the specified account.
         ///
-        /// It's possible that the call will be a no-op since we haven't advanced enough periods yet.
-        fn update_cleanup_marker(new_period_number: PeriodNumber) {
-            // 1. Find out the latest expired period; rewards can no longer be claimed for it or any older period.
-            let latest_expired_period = match new_period_number
-                .checked_sub(T::RewardRetentionInPeriods::get().saturating_add(1))
-            {
-                Some(period) if !period.is_zero() => period,
-                // Haven't advanced enough periods to have any expired entries.
-                _ => return,
-            };
+        /// In case account has opted into auto-restaking, claimed rewards are also re-staked.
+        fn internal_claim_staker_rewards_for(
+            claimer: T::AccountId,
+            account: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let mut ledger = Ledger::<T>::get(&account);
+            let auto_restake = StakerPreferencesOf::<T>::get(&account).auto_restake;
+            let stake_entries = ledger.contract_stake_count;
 
-            // 2. Find the oldest valid era for which rewards can still be claimed.
-            //    Technically, this will be `Voting` subperiod era but it doesn't matter.
-            //
-            //    Also, remove the expired `PeriodEnd` entry since it's no longer needed.
-            let oldest_valid_era = match PeriodEnd::<T>::take(latest_expired_period) {
-                Some(period_end_info) => period_end_info.final_era.saturating_add(1),
-                None => {
-                    // Should never happen but nothing we can do if it does.
-                    log::error!(
-                        target: LOG_TARGET,
-                        "No `PeriodEnd` entry for the expired period: {}",
-                        latest_expired_period
-                    );
-                    return;
-                }
-            };
+            let (rewards, period_end) = Self::calculate_staker_rewards(&mut ledger)?;
+            let reward_sum = rewards.iter().fold(Balance::zero(), |sum, (_, reward)| {
+                sum.saturating_add(*reward)
+            });
+            let rewards_len: u32 = rewards.len().unique_saturated_into();
 
-            // 3. Update the cleanup marker with the new oldest valid era.
-            HistoryCleanupMarker::<T>::mutate(|marker| {
-                marker.oldest_valid_era = oldest_valid_era;
+            let (staker_reward, claim_bounty) =
+                Self::payout_claimed_reward(&claimer, &account, reward_sum, RewardKind::Staker)?;
+
+            Self::update_ledger(&account, ledger)?;
+
+            rewards.into_iter().for_each(|(era, reward)| {
+                Self::deposit_event(Event::<T>::Reward {
+                    account: account.clone(),
+                    era,
+                    amount: reward,
+                });
             });
-        }
 
-        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
-        ///
-        /// Returns consumed weight.
-        fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
-            // Need to be able to process one full pass
-            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
-                return Weight::zero();
-            }
+            // Restaking is best-effort - in case it fails, all of its changes are reverted and rewards remain liquid.
+            let restake_weight = if auto_restake {
+                let restaked =
+                    with_storage_layer(|| Self::restake_rewards(&account, staker_reward));
+                if !staker_reward.is_zero() && !matches!(restaked, Ok(count) if count > 0) {
+                    Self::deposit_event(Event::<T>::RestakeSkipped {
+                        account: account.clone(),
+                        amount: staker_reward,
+                    });
+                }
+                T::WeightInfo::restake(stake_entries)
+            } else {
+                Weight::zero()
+            };
 
-            // Get the cleanup marker and ensure we have pending cleanups.
-            let mut cleanup_marker = HistoryCleanupMarker::<T>::get();
-            if !cleanup_marker.has_pending_cleanups() {
-                return T::DbWeight::get().reads(1);
+            if !claim_bounty.is_zero() {
+                Self::deposit_event(Event::<T>::ClaimBountyPaid {
+                    claimer,
+                    account,
+                    amount: claim_bounty,
+                });
             }
 
-            // 1. Attempt to cleanup one expired `EraRewards` entry.
-            if cleanup_marker.era_reward_index < cleanup_marker.oldest_valid_era {
-                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
-                    // If oldest valid era comes AFTER this span, it's safe to delete it.
-                    if era_reward.last_era() < cleanup_marker.oldest_valid_era {
-                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
-                        cleanup_marker
-                            .era_reward_index
-                            .saturating_accrue(T::EraRewardSpanLength::get());
-                    }
+            Ok(Some(
+                if period_end.is_some() {
+                    T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
                 } else {
-                    // Can happen if the entry is part of history before dApp staking v3
-                    log::warn!(
-                        target: LOG_TARGET,
-                        "Era rewards span for era {} is missing, but cleanup marker is set.",
-                        cleanup_marker.era_reward_index
-                    );
-                    cleanup_marker
-                        .era_reward_index
-                        .saturating_accrue(T::EraRewardSpanLength::get());
+                    T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
                 }
-            }
-
-            // 2. Attempt to cleanup one expired `DAppTiers` entry.
-            if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
-                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
-                cleanup_marker.dapp_tiers_index.saturating_inc();
-            }
+                .saturating_add(restake_weight)
+                .saturating_add(if claim_bounty.is_zero() {
+                    Weight::zero()
+                } else {
+                    Self::claim_bounty_weight()
+                }),
+            )
+            .into())
+        }
 
-            // Store the updated cleanup marker
-            HistoryCleanupMarker::<T>::put(cleanup_marker);
+        /// Pays out the claimed reward to the staker account.
+        ///
+        /// In case reward is claimed by a third party, and the staker has opted into paying the claim bounty,
+        /// part of it is paid out to the claimer as a bounty, limited by `MaxClaimBounty`.
+        ///
+        /// Returns the amounts paid out to the staker and to the claimer, respectively.
+        fn payout_claimed_reward(
+            claimer: &T::AccountId,
+            account: &T::AccountId,
+            reward: Balance,
+            kind: RewardKind,
+        ) -> Result<(Balance, Balance), Error<T>> {
+            let claim_bounty = if claimer == account {
+                Balance::zero()
+            } else {
+                // Maximum rate is enforced here as well, in case it was lowered after the staker set their rate.
+                let rate = StakerPreferencesOf::<T>::get(account)
+                    .claim_bounty
+                    .min(T::MaxClaimBountyRate::get());
+                (rate * reward).min(T::MaxClaimBounty::get())
+            };
+            let staker_reward = reward.saturating_sub(claim_bounty);
 
-            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
-            // we opt for the simpler solution where only 1 entry per block is cleaned up.
-            // It can be changed though.
+            T::StakingRewardHandler::payout_reward(account, staker_reward, kind)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            if !claim_bounty.is_zero() {
+                T::StakingRewardHandler::payout_reward(claimer, claim_bounty, kind)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            }
 
-            // It could end up being less than this weight, but this won't occur often enough to be important.
-            T::WeightInfo::on_idle_cleanup()
+            Ok((staker_reward, claim_bounty))
         }
 
-        /// Internal function that executes the `claim_unlocked` logic for the specified account.
-        fn internal_claim_unlocked(account: T::AccountId) -> DispatchResultWithPostInfo {
-            let mut ledger = Ledger::<T>::get(&account);
+        /// Weight of reading the staker's claim bounty and paying it out to the claimer account.
+        pub(crate) fn claim_bounty_weight() -> Weight {
+            T::DbWeight::get().reads_writes(2, 1)
+        }
 
-            let current_block = frame_system::Pallet::<T>::block_number();
-            let amount = ledger.claim_unlocked(current_block.saturated_into());
-            ensure!(amount > Zero::zero(), Error::<T>::NoUnlockedChunksToClaim);
+        /// Locks the specified reward amount, and stakes it on the contracts the account has staked on
+        /// during the ongoing period. Reward is split proportionally to the existing stake on each contract.
+        ///
+        /// Since only the existing stake entries are increased, `MaxNumberOfStakedContracts` and `MinimumStakeAmount`
+        /// limits are respected. In case there's nothing to stake on, nothing is locked.
+        ///
+        /// Returns the number of contracts on which the reward was re-staked.
+        pub(crate) fn restake_rewards(
+            account: &T::AccountId,
+            amount: Balance,
+        ) -> Result<u32, DispatchError> {
+            if amount.is_zero() {
+                return Ok(0);
+            }
 
-            // In case it's full unlock, account is exiting dApp staking, ensure all storage is cleaned up.
-            let removed_entries = if ledger.is_empty() {
-                let _ = StakerInfo::<T>::clear_prefix(&account, ledger.contract_stake_count, None);
-                ledger.contract_stake_count
-            } else {
-                0
-            };
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+            let stakes: Vec<_> = StakerInfo::<T>::iter_prefix(account)
+                .filter(|(smart_contract, staking_info)| {
+                    staking_info.period_number() == current_period
+                        && IntegratedDApps::<T>::contains_key(smart_contract)
+                })
+                .map(|(smart_contract, staking_info)| {
+                    (smart_contract, staking_info.total_staked_amount())
+                })
+                .collect();
+            let total_staked = stakes.iter().fold(Balance::zero(), |sum, (_, staked)| {
+                sum.saturating_add(*staked)
+            });
+            if total_staked.is_zero() {
+                return Ok(0);
+            }
 
-            Self::update_ledger(&account, ledger)?;
+            // 1. Lock the reward, so it can be used for staking.
+            let mut ledger = Ledger::<T>::get(account);
+            ledger.add_lock_amount(amount);
+            Self::update_ledger(account, ledger)?;
             CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.unlocking_removed(amount);
+                era_info.add_locked(amount);
+            });
+            Self::deposit_event(Event::<T>::Locked {
+                account: account.clone(),
+                amount,
             });
 
-            Self::deposit_event(Event::<T>::ClaimedUnlocked { account, amount });
+            // 2. Stake the reward, proportionally to the existing stake.
+            // Any rounding remainder goes to the last contract, so the entire locked reward gets staked.
+            let mut remaining = amount;
+            let mut restaked_contracts = 0_u32;
+            let last_index = stakes.len().saturating_sub(1);
+            for (index, (smart_contract, staked)) in stakes.into_iter().enumerate() {
+                let stake_amount = if index == last_index {
+                    remaining
+                } else {
+                    Perbill::from_rational(staked, total_staked) * amount
+                };
+                if stake_amount.is_zero() {
+                    continue;
+                }
 
-            Ok(Some(T::WeightInfo::claim_unlocked(removed_entries)).into())
+                Self::internal_stake(account.clone(), smart_contract, stake_amount)?;
+                remaining.saturating_reduce(stake_amount);
+                restaked_contracts.saturating_inc();
+            }
+
+            Ok(restaked_contracts)
         }
 
-        /// Internal function that executes the `claim_staker_rewards_` logic for the specified account.
-        fn internal_claim_staker_rewards_for(account: T::AccountId) -> DispatchResultWithPostInfo {
-            let mut ledger = Ledger::<T>::get(&account);
+        /// Calculates the staker rewards which can be claimed in a single claim call, using the provided ledger.
+        /// Claimed eras are consumed from the ledger, so it's up to the caller to decide whether the ledger should be stored or discarded.
+        ///
+        /// Returns a vector of `(era, reward)` pairs, alongside the final era of the staked period, in case the period has ended.
+        fn calculate_staker_rewards(
+            ledger: &mut AccountLedgerFor<T>,
+        ) -> Result<(Vec<(EraNumber, Balance)>, Option<EraNumber>), Error<T>> {
             let staked_period = ledger
                 .staked_period()
                 .ok_or(Error::<T>::NoClaimableRewards)?;
@@ -2150,7 +3586,6 @@ pub mod pallet {
 
             // Calculate rewards
             let mut rewards: Vec<_> = Vec::new();
-            let mut reward_sum = Balance::zero();
             for (era, amount) in rewards_iter {
                 let era_reward = era_rewards
                     .get(era)
@@ -2164,38 +3599,66 @@ pub mod pallet {
                     * era_reward.staker_reward_pool;
 
                 rewards.push((era, staker_reward));
-                reward_sum.saturating_accrue(staker_reward);
             }
-            let rewards_len: u32 = rewards.len().unique_saturated_into();
-
-            T::StakingRewardHandler::payout_reward(&account, reward_sum)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            Self::update_ledger(&account, ledger)?;
-
-            rewards.into_iter().for_each(|(era, reward)| {
-                Self::deposit_event(Event::<T>::Reward {
-                    account: account.clone(),
-                    era,
-                    amount: reward,
-                });
-            });
 
-            Ok(Some(if period_end.is_some() {
-                T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
-            } else {
-                T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
-            })
-            .into())
+            Ok((rewards, period_end))
         }
 
         /// Internal function that executes the `claim_bonus_reward` logic for the specified account & smart contract.
         fn internal_claim_bonus_reward_for(
+            claimer: T::AccountId,
             account: T::AccountId,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
+            let smart_contract = Self::resolve_smart_contract(smart_contract);
             let staker_info = StakerInfo::<T>::get(&account, &smart_contract)
                 .ok_or(Error::<T>::NoClaimableRewards)?;
+            let staked_period = staker_info.period_number();
+            let bonus_reward = Self::calculate_bonus_reward(&staker_info)?;
+
+            // Shared dApp rewards must be paid out before the entry is removed.
+            let paid_spans =
+                Self::payout_pending_shared_rewards(&account, &smart_contract, &staker_info)?;
+
+            let (_, claim_bounty) =
+                Self::payout_claimed_reward(&claimer, &account, bonus_reward, RewardKind::Bonus)?;
+
+            // Cleanup entry since the reward has been claimed
+            StakerInfo::<T>::remove(&account, &smart_contract);
+            SharedRewardClaimMarkers::<T>::remove(&account, &smart_contract);
+            Ledger::<T>::mutate(&account, |ledger| {
+                ledger.contract_stake_count.saturating_dec();
+            });
+
+            Self::deposit_event(Event::<T>::BonusReward {
+                account: account.clone(),
+                smart_contract,
+                period: staked_period,
+                amount: bonus_reward,
+            });
+
+            if !claim_bounty.is_zero() {
+                Self::deposit_event(Event::<T>::ClaimBountyPaid {
+                    claimer,
+                    account,
+                    amount: claim_bounty,
+                });
+            }
+
+            Ok(Some(
+                T::WeightInfo::claim_bonus_reward()
+                    .saturating_add(Self::shared_rewards_payout_weight_for(paid_spans))
+                    .saturating_add(if claim_bounty.is_zero() {
+                        Weight::zero()
+                    } else {
+                        Self::claim_bounty_weight()
+                    }),
+            )
+            .into())
+        }
+
+        /// Calculates the bonus reward for the provided staking info, ensuring it's eligible for it.
+        fn calculate_bonus_reward(staker_info: &SingularStakingInfo) -> Result<Balance, Error<T>> {
             let protocol_state = ActiveProtocolState::<T>::get();
 
             // Ensure:
@@ -2226,27 +3689,172 @@ pub mod pallet {
             );
 
             let eligible_amount = staker_info.staked_amount(Subperiod::Voting);
-            let bonus_reward =
+            Ok(
                 Perbill::from_rational(eligible_amount, period_end_info.total_vp_stake)
-                    * period_end_info.bonus_reward_pool;
+                    * period_end_info.bonus_reward_pool,
+            )
+        }
 
-            T::StakingRewardHandler::payout_reward(&account, bonus_reward)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+        /// Consumes the reward of the specified dApp from the provided tier rewards, ensuring it hasn't expired.
+        ///
+        /// Returns the reward amount, alongside the ranked tier into which the dApp was assigned.
+        fn calculate_dapp_reward(
+            dapp_id: DAppId,
+            dapp_tiers: &mut DAppTierRewardsFor<T>,
+            current_period: PeriodNumber,
+        ) -> Result<(Balance, RankedTier), Error<T>> {
+            ensure!(
+                dapp_tiers.period >= Self::oldest_claimable_period(current_period),
+                Error::<T>::RewardExpired
+            );
 
-            // Cleanup entry since the reward has been claimed
-            StakerInfo::<T>::remove(&account, &smart_contract);
-            Ledger::<T>::mutate(&account, |ledger| {
-                ledger.contract_stake_count.saturating_dec();
-            });
+            dapp_tiers.try_claim(dapp_id).map_err(|error| match error {
+                DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
+                _ => Error::<T>::InternalClaimDAppError,
+            })
+        }
 
-            Self::deposit_event(Event::<T>::BonusReward {
+        /// Returns the range of eras, `(first_era, last_era)`, for which the account can claim its part
+        /// of the dApp rewards shared with the stakers, based on the provided staking info.
+        ///
+        /// `None` is returned if there are no such eras.
+        fn shared_reward_claim_range(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            staking_info: &SingularStakingInfo,
+        ) -> Option<(EraNumber, EraNumber)> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            // Only ended eras can be claimed, and only up to the end of the staked period.
+            let mut last_era = protocol_state.era.saturating_sub(1);
+            let staked_period = staking_info.period_number();
+            if staked_period < protocol_state.period_number() {
+                let period_end_info = PeriodEnd::<T>::get(&staked_period)?;
+                last_era = last_era.min(period_end_info.final_era);
+            }
+
+            // Older eras are either already claimed, no longer tracked by the staking info, or expired.
+            let first_era = SharedRewardClaimMarkers::<T>::get(account, smart_contract)
+                .saturating_add(1)
+                .max(staking_info.first_tracked_era())
+                .max(HistoryCleanupMarker::<T>::get().oldest_valid_era);
+
+            if first_era <= last_era {
+                Some((first_era, last_era))
+            } else {
+                None
+            }
+        }
+
+        /// Returns the part of the dApp reward for the specified era which was shared with the dApp stakers.
+        fn shared_dapp_reward(smart_contract: &T::SmartContract, era: EraNumber) -> Balance {
+            DAppSharedRewards::<T>::get(&Self::era_reward_span_index(era), smart_contract)
+                .and_then(|span| {
+                    span.get(era)
+                        .map(|era_reward| era_reward.staker_reward_pool)
+                })
+                .unwrap_or_default()
+        }
+
+        /// Pays out the account's part of the dApp rewards shared with the stakers of the specified smart contract,
+        /// for the eras in the provided range. The range is cut at the end of the era reward span of `first_era`.
+        fn payout_shared_rewards(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            staking_info: &SingularStakingInfo,
+            first_era: EraNumber,
+            last_era: EraNumber,
+        ) -> Result<(), Error<T>> {
+            // Claim is limited to a single span, to keep it bounded.
+            let span_index = Self::era_reward_span_index(first_era);
+            let last_era = last_era.min(
+                span_index
+                    .saturating_add(T::EraRewardSpanLength::get())
+                    .saturating_sub(1),
+            );
+
+            let amount = DAppSharedRewards::<T>::get(&span_index, smart_contract).map_or(
+                Balance::zero(),
+                |span| {
+                    (first_era..=last_era)
+                        .filter_map(|era| {
+                            span.get(era)
+                                .filter(|era_reward| !era_reward.staked.is_zero())
+                                .map(|era_reward| {
+                                    Perbill::from_rational(
+                                        staking_info.staked_amount_in_era(era),
+                                        era_reward.staked,
+                                    ) * era_reward.staker_reward_pool
+                                })
+                        })
+                        .fold(Balance::zero(), |sum, reward| sum.saturating_add(reward))
+                },
+            );
+
+            if !amount.is_zero() {
+                T::StakingRewardHandler::payout_reward(account, amount, RewardKind::Staker)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            }
+            SharedRewardClaimMarkers::<T>::insert(account, smart_contract, last_era);
+
+            Self::deposit_event(Event::<T>::SharedDAppReward {
                 account: account.clone(),
-                smart_contract,
-                period: staked_period,
-                amount: bonus_reward,
+                smart_contract: smart_contract.clone(),
+                era: last_era,
+                amount,
             });
 
             Ok(())
         }
+
+        /// Pays out all of the account's pending rewards shared by the dApp with the stakers.
+        ///
+        /// Since staking info only tracks the latest stake amounts, this must be done before it's modified.
+        ///
+        /// Returns the number of era reward spans for which the rewards were paid out.
+        fn payout_pending_shared_rewards(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            staking_info: &SingularStakingInfo,
+        ) -> Result<u32, Error<T>> {
+            let last_shared_era = DAppLastSharedEra::<T>::get(smart_contract);
+            if last_shared_era.is_zero() {
+                return Ok(0);
+            }
+
+            // Each payout advances the claim marker by at least one span, so this is bounded by the number
+            // of spans in a period.
+            let mut paid_spans = 0_u32;
+            while let Some((first_era, last_era)) =
+                Self::shared_reward_claim_range(account, smart_contract, staking_info)
+            {
+                if first_era > last_shared_era {
+                    break;
+                }
+                Self::payout_shared_rewards(
+                    account,
+                    smart_contract,
+                    staking_info,
+                    first_era,
+                    last_era.min(last_shared_era),
+                )?;
+                paid_spans.saturating_inc();
+            }
+
+            Ok(paid_spans)
+        }
+
+        /// Worst-case weight of paying out all pending shared dApp rewards of a single staking info entry.
+        pub fn shared_rewards_payout_weight() -> Weight {
+            let max_spans = T::CycleConfiguration::eras_per_period()
+                .saturating_div(T::EraRewardSpanLength::get())
+                .saturating_add(1);
+            Self::shared_rewards_payout_weight_for(max_spans)
+        }
+
+        /// Weight of paying out the pending shared dApp rewards for the specified number of era reward spans.
+        pub fn shared_rewards_payout_weight_for(spans: u32) -> Weight {
+            T::WeightInfo::claim_shared_dapp_rewards().saturating_mul(spans.into())
+        }
     }
 }
@@ -49,6 +49,111 @@ pub mod versioned_migrations {
             Pallet<T>,
             <T as frame_system::Config>::DbWeight,
         >;
+
+    /// Migration V8 to V9 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 8.
+    pub type V8ToV9<T> = frame_support::migrations::VersionedMigration<
+        8,
+        9,
+        v9::VersionMigrateV8ToV9<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Translate static tier parameters to include the minimum number of stakers,
+/// with unique staker counting starting from the next period.
+mod v9 {
+    use super::*;
+
+    /// v8 type for [`crate::TierParameters`]
+    #[derive(Encode, Decode)]
+    pub struct TierParameters<NT: Get<u32>> {
+        pub reward_portion: BoundedVec<Permill, NT>,
+        pub slot_distribution: BoundedVec<Permill, NT>,
+        pub tier_thresholds: BoundedVec<TierThreshold, NT>,
+    }
+
+    pub struct VersionMigrateV8ToV9<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV8ToV9<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // 1. Existing tier parameters have no minimum number of stakers requirement.
+            let result = StaticTierParams::<T>::translate::<TierParameters<T::NumberOfTiers>, _>(
+                |maybe_old_params| {
+                    maybe_old_params.map(|old_params| crate::TierParameters {
+                        reward_portion: old_params.reward_portion,
+                        slot_distribution: old_params.slot_distribution,
+                        tier_thresholds: old_params.tier_thresholds,
+                        min_stakers: BoundedVec::default(),
+                    })
+                },
+            );
+
+            if result.is_err() {
+                log::error!("Failed to translate StaticTierParams from previous V8 type to current V9 type. Check TierParametersV8 decoding.");
+                // Enable maintenance mode.
+                ActiveProtocolState::<T>::mutate(|state| {
+                    state.maintenance = true;
+                });
+                log::warn!("Maintenance mode enabled.");
+                return T::DbWeight::get().reads_writes(1, 0);
+            }
+
+            // 2. Unique staker counts aren't initialized for the ongoing period, since that would require
+            //    iterating over all staker info entries. Counting starts lazily, and is accurate from the next period on.
+            //    This is safe since minimum number of stakers can only be introduced via queued tier params,
+            //    which are applied at the start of the next period.
+
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let old_params = frame_support::storage::unhashed::get::<
+                TierParameters<T::NumberOfTiers>,
+            >(&StaticTierParams::<T>::hashed_key())
+            .ok_or_else(|| {
+                TryRuntimeError::Other(
+                    "dapp-staking-v3::migration::v9: No old params found for StaticTierParams",
+                )
+            })?;
+            Ok(old_params.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let old_params = TierParameters::<T::NumberOfTiers>::decode(&mut &data[..])
+                .map_err(|_| {
+                    TryRuntimeError::Other(
+                        "dapp-staking-v3::migration::v9: Failed to decode old v8 version of tier params",
+                    )
+                })?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 9,
+                "dapp-staking-v3::migration::v9: Wrong storage version."
+            );
+
+            let actual_params = StaticTierParams::<T>::get();
+            ensure!(
+                actual_params.is_valid(),
+                "dapp-staking-v3::migration::v9: New tier params are invalid."
+            );
+            ensure!(
+                actual_params.reward_portion == old_params.reward_portion
+                    && actual_params.slot_distribution == old_params.slot_distribution
+                    && actual_params.tier_thresholds == old_params.tier_thresholds,
+                "dapp-staking-v3::migration::v9: Tier params have changed."
+            );
+            ensure!(
+                actual_params.min_stakers.is_empty(),
+                "dapp-staking-v3::migration::v9: Minimum number of stakers must be empty."
+            );
+
+            Ok(())
+        }
+    }
 }
 
 // TierThreshold as percentage of the total issuance
@@ -83,6 +188,7 @@ mod v8 {
                                 slot_distribution: old_params.slot_distribution,
                                 reward_portion: old_params.reward_portion,
                                 tier_thresholds,
+                                min_stakers: BoundedVec::default(),
                             }),
                             Err(err) => {
                                 log::error!(
@@ -32,7 +32,7 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Permill,
+    BuildStorage, Perbill, Permill,
 };
 use sp_std::cell::RefCell;
 
@@ -122,6 +122,26 @@ thread_local! {
     pub(crate) static DOES_PAYOUT_SUCCEED: RefCell<bool> = RefCell::new(false);
     pub(crate) static BLOCK_BEFORE_NEW_ERA: RefCell<EraNumber> = RefCell::new(0);
     pub(crate) static NATIVE_PRICE: RefCell<FixedU128> = RefCell::new(BaseNativeCurrencyPrice::get());
+    pub(crate) static TIER_ASSIGNMENT_OVERRIDE: RefCell<Option<BTreeMap<DAppId, RankedTier>>> = RefCell::new(None);
+}
+
+pub(crate) const MAX_CLAIM_BOUNTY: Balance = 50_000_000_000;
+
+pub struct DummyTierAssignment;
+impl TierAssignment<Test> for DummyTierAssignment {
+    fn assign_tiers(
+        dapp_stakes: Vec<(DAppId, StakeAmount, u32)>,
+        tier_config: &TiersConfigurationFor<Test>,
+    ) -> BTreeMap<DAppId, RankedTier> {
+        TIER_ASSIGNMENT_OVERRIDE
+            .with(|v| v.borrow().clone())
+            .unwrap_or_else(|| {
+                <StandardTierAssignment as TierAssignment<Test>>::assign_tiers(
+                    dapp_stakes,
+                    tier_config,
+                )
+            })
+    }
 }
 
 pub struct DummyStakingRewardHandler;
@@ -137,7 +157,7 @@ impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
         Balance::from(3_000_000_u128)
     }
 
-    fn payout_reward(beneficiary: &AccountId, reward: Balance) -> Result<(), ()> {
+    fn payout_reward(beneficiary: &AccountId, reward: Balance, _: RewardKind) -> Result<(), ()> {
         if DOES_PAYOUT_SUCCEED.with(|v| v.borrow().clone()) {
             let _ = Balances::mint_into(beneficiary, reward);
             Ok(())
@@ -203,6 +223,7 @@ impl AccountCheck<AccountId> for DummyAccountCheck {
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const MaxClaimBountyRate: Perbill = Perbill::from_percent(10);
 }
 ord_parameter_types! {
     pub const ContractRegisterAccount: AccountId = 1337;
@@ -213,6 +234,7 @@ ord_parameter_types! {
 impl pallet_dapp_staking::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = MockSmartContract;
     type ContractRegisterOrigin =
@@ -240,6 +262,15 @@ impl pallet_dapp_staking::Config for Test {
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierAssignment = DummyTierAssignment;
+    type MaxClaimBountyRate = MaxClaimBountyRate;
+    type MaxClaimBounty = ConstU128<MAX_CLAIM_BOUNTY>;
+    type MaxArchivedPeriods = ConstU32<3>;
+    type MetadataDepositBase = ConstU128<10>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type MaxMetadataFieldLength = ConstU32<32>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type MaxContractAliases = ConstU32<4>;
     type WeightInfo = weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -335,6 +366,7 @@ impl ExtBuilder {
                     },
                 ])
                 .unwrap(),
+                min_stakers: BoundedVec::default(),
             };
 
             let total_issuance = <Test as Config>::Currency::total_issuance();
@@ -19,9 +19,9 @@
 use crate::test::mock::*;
 use crate::types::*;
 use crate::{
-    pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppTiers,
-    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, NextDAppId,
-    PeriodEnd, PeriodEndInfo, StakerInfo,
+    pallet::Config, ActiveProtocolState, ContractStake, ContractStakers, CurrentEraInfo, DAppId,
+    DAppSharedRewards, DAppTiers, EraRewards, Event, FreezeReason, HistoryCleanupMarker,
+    IntegratedDApps, Ledger, NextDAppId, PeriodEnd, PeriodEndInfo, StakerInfo,
 };
 
 use frame_support::{
@@ -57,6 +57,7 @@ pub(crate) struct MemorySnapshot {
         SingularStakingInfo,
     >,
     contract_stake: HashMap<DAppId, ContractStakeAmount>,
+    contract_stakers: HashMap<DAppId, StakerCount>,
     era_rewards: HashMap<EraNumber, EraRewardSpan<<Test as Config>::EraRewardSpanLength>>,
     period_end: HashMap<PeriodNumber, PeriodEndInfo>,
     dapp_tiers: HashMap<EraNumber, DAppTierRewardsFor<Test>>,
@@ -76,6 +77,7 @@ impl MemorySnapshot {
                 .map(|(k1, k2, v)| ((k1, k2), v))
                 .collect(),
             contract_stake: ContractStake::<Test>::iter().collect(),
+            contract_stakers: ContractStakers::<Test>::iter().collect(),
             era_rewards: EraRewards::<Test>::iter().collect(),
             period_end: PeriodEnd::<Test>::iter().collect(),
             dapp_tiers: DAppTiers::<Test>::iter().collect(),
@@ -202,6 +204,9 @@ pub(crate) fn assert_unregister(smart_contract: &MockSmartContract) {
     assert!(!ContractStake::<Test>::contains_key(
         &pre_snapshot.integrated_dapps[&smart_contract].id
     ));
+    assert!(!ContractStakers::<Test>::contains_key(
+        &pre_snapshot.integrated_dapps[&smart_contract].id
+    ));
 }
 
 /// Lock funds into dApp staking and assert success.
@@ -454,6 +459,11 @@ pub(crate) fn assert_stake(
         .contract_stake
         .get(&pre_snapshot.integrated_dapps[&smart_contract].id)
         .map_or(ContractStakeAmount::default(), |series| series.clone());
+    let pre_contract_stakers = pre_snapshot
+        .contract_stakers
+        .get(&pre_snapshot.integrated_dapps[&smart_contract].id)
+        .copied()
+        .unwrap_or_default();
     let pre_era_info = pre_snapshot.current_era_info;
 
     let stake_era = pre_snapshot.active_protocol_state.era + 1;
@@ -535,9 +545,17 @@ pub(crate) fn assert_stake(
     // 2. verify staker info
     // =====================
     // =====================
+    let post_contract_stakers = post_snapshot.contract_stakers
+        [&pre_snapshot.integrated_dapps[&smart_contract].id]
+        .count(stake_period);
     match pre_staker_info {
         // We're just updating an existing entry
         Some(pre_staker_info) if pre_staker_info.period_number() == stake_period => {
+            assert_eq!(
+                post_contract_stakers,
+                pre_contract_stakers.count(stake_period),
+                "Number of unique stakers mustn't change."
+            );
             assert_eq!(
                 post_staker_info.total_staked_amount(),
                 pre_staker_info.total_staked_amount() + amount,
@@ -557,6 +575,11 @@ pub(crate) fn assert_stake(
         }
         // A new entry is created.
         _ => {
+            assert_eq!(
+                post_contract_stakers,
+                pre_contract_stakers.count(stake_period) + 1,
+                "Number of unique stakers must increase by one."
+            );
             assert_eq!(
                 post_staker_info.total_staked_amount(),
                 amount,
@@ -630,6 +653,11 @@ pub(crate) fn assert_unstake(
         .contract_stake
         .get(&pre_snapshot.integrated_dapps[&smart_contract].id)
         .expect("Entry must exist since 'unstake' is being called.");
+    let pre_contract_stakers = pre_snapshot
+        .contract_stakers
+        .get(&pre_snapshot.integrated_dapps[&smart_contract].id)
+        .copied()
+        .unwrap_or_default();
     let pre_era_info = pre_snapshot.current_era_info;
 
     let unstake_era = pre_snapshot.active_protocol_state.era;
@@ -686,13 +714,30 @@ pub(crate) fn assert_unstake(
     // =====================
     // =====================
 
+    let post_contract_stakers = post_snapshot
+        .contract_stakers
+        .get(&pre_snapshot.integrated_dapps[&smart_contract].id)
+        .copied()
+        .unwrap_or_default()
+        .count(unstake_period);
+
     // Verify that expected unstake amounts are applied.
     if is_full_unstake {
         assert!(
             !StakerInfo::<Test>::contains_key(&account, smart_contract),
             "Entry must be deleted since it was a full unstake."
         );
+        assert_eq!(
+            post_contract_stakers,
+            pre_contract_stakers.count(unstake_period) - 1,
+            "Number of unique stakers must decrease by one since it was a full unstake."
+        );
     } else {
+        assert_eq!(
+            post_contract_stakers,
+            pre_contract_stakers.count(unstake_period),
+            "Number of unique stakers mustn't change."
+        );
         let post_staker_info = post_snapshot
             .staker_info
             .get(&(account, *smart_contract))
@@ -1035,6 +1080,15 @@ pub(crate) fn assert_claim_dapp_reward(
         let mut info = pre_reward_info.clone();
         info.try_claim(dapp_info.id).unwrap()
     };
+    // Part of the reward might have been shared with the stakers.
+    let shared_reward =
+        DAppSharedRewards::<Test>::get(&DappStaking::era_reward_span_index(era), smart_contract)
+            .and_then(|span| {
+                span.get(era)
+                    .map(|era_reward| era_reward.staker_reward_pool)
+            })
+            .unwrap_or_default();
+    let expected_reward = expected_reward - shared_reward;
 
     // Claim dApp reward & verify event
     assert_ok!(DappStaking::claim_dapp_reward(
@@ -1498,6 +1552,12 @@ pub(crate) fn assert_on_idle_cleanup() {
         assert!(!EraRewards::<Test>::contains_key(
             pre_cleanup_marker.era_reward_index
         ));
+        assert!(
+            DAppSharedRewards::<Test>::iter_prefix(pre_cleanup_marker.era_reward_index)
+                .next()
+                .is_none(),
+            "Shared dApp rewards must be cleaned up alongside the era rewards."
+        );
     }
 
     if is_era_reward_index_increase {
@@ -18,18 +18,20 @@
 
 use crate::test::{mock::*, testing_utils::*};
 use crate::{
-    pallet::Config, ActiveProtocolState, ContractStake, DAppId, DAppTierRewardsFor, DAppTiers,
-    EraRewards, Error, Event, ForcingType, GenesisConfig, IntegratedDApps, Ledger, NextDAppId,
-    Perbill, PeriodNumber, Permill, Safeguard, StakerInfo, StaticTierParams, Subperiod, TierConfig,
-    TierThreshold,
+    pallet::Config, ActiveProtocolState, ContractAliases, ContractStake, CurrentEraInfo,
+    DAppCategory, DAppContractAliases, DAppId, DAppMetadataOf, DAppPeriodArchive,
+    DAppPeriodSummary, DAppRewardShares, DAppSharedRewards, DAppTierRewardsFor, DAppTiers,
+    EraReward, EraRewards, Error, Event, ForcingType, GenesisConfig, HoldReason, IntegratedDApps,
+    Ledger, NextDAppId, Perbill, PeriodNumber, Permill, QueuedTierParams, Safeguard, StakerInfo,
+    StakerPreferencesOf, StaticTierParams, Subperiod, TierConfig, TierThreshold, WeightInfo,
 };
 
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
     error::BadOrigin,
     traits::{
-        fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnInitialize,
-        ReservableCurrency,
+        fungible::{InspectHold as FunInspectHold, Unbalanced as FunUnbalanced},
+        Currency, Get, OnFinalize, OnInitialize, ReservableCurrency,
     },
     BoundedVec,
 };
@@ -182,6 +184,67 @@ fn maintenance_mode_call_filtering_works() {
             DappStaking::withdraw_unbonded(RuntimeOrigin::signed(1),),
             Error::<Test>::Disabled
         );
+        assert_noop!(
+            DappStaking::queue_tier_params(RuntimeOrigin::root(), StaticTierParams::<Test>::get()),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::stake_many(RuntimeOrigin::signed(1), BoundedVec::new()),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::unstake_many(RuntimeOrigin::signed(1), BoundedVec::new()),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_dapp_reward_share(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                Perbill::from_percent(10)
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::claim_shared_dapp_rewards(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId)
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                BoundedVec::truncate_from(b"name".to_vec()),
+                BoundedVec::truncate_from(b"website".to_vec()),
+                BoundedVec::truncate_from(b"cid".to_vec()),
+                DAppCategory::Defi
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId)
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::add_contract_alias(
+                RuntimeOrigin::root(),
+                MockSmartContract::wasm(1 as AccountId),
+                MockSmartContract::wasm(2 as AccountId)
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::remove_contract_alias(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                MockSmartContract::wasm(2 as AccountId)
+            ),
+            Error::<Test>::Disabled
+        );
     })
 }
 
@@ -2608,6 +2671,46 @@ fn get_dapp_tier_assignment_and_rewards_basic_example_works() {
     })
 }
 
+#[test]
+fn get_dapp_tier_assignment_and_rewards_ignores_invalid_assignment() {
+    ExtBuilder::build().execute_with(|| {
+        // This test will rely on the configuration inside the mock file.
+        // If that changes, this test might have to be updated as well.
+        let tier_config = TierConfig::<Test>::get();
+        assert_eq!(tier_config.slots_per_tier[0], 2, "Sanity check.");
+
+        // Custom tier assignment which overfills the 1st tier, and uses a non-existing tier
+        TIER_ASSIGNMENT_OVERRIDE.with(|v| {
+            *v.borrow_mut() = Some(BTreeMap::from([
+                (0, RankedTier::new_saturated(0, 1)),
+                (1, RankedTier::new_saturated(0, 2)),
+                (2, RankedTier::new_saturated(0, 3)),
+                (3, RankedTier::new_saturated(1, 0)),
+                (4, RankedTier::new_saturated(7, 0)),
+            ]))
+        });
+
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let (tier_assignment, _) = DappStaking::get_dapp_tier_assignment_and_rewards(
+            protocol_state.era,
+            protocol_state.period_number(),
+            1_000_000,
+        );
+
+        // Only the entries respecting the tier configuration are kept
+        assert_eq!(
+            tier_assignment.dapps.into_inner(),
+            BTreeMap::from([
+                (0, RankedTier::new_saturated(0, 1)),
+                (1, RankedTier::new_saturated(0, 2)),
+                (3, RankedTier::new_saturated(1, 0)),
+            ])
+        );
+        // 1st tier has no empty slots, so there's no reward available for ranks
+        assert!(tier_assignment.rank_rewards[0].is_zero());
+    })
+}
+
 #[test]
 fn get_dapp_tier_assignment_and_rewards_zero_slots_per_tier_works() {
     ExtBuilder::build().execute_with(|| {
@@ -3537,3 +3640,1929 @@ fn claim_bonus_reward_for_works() {
         );
     })
 }
+
+#[test]
+fn claim_staker_rewards_for_pays_capped_claim_bounty() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let staker_account = 2;
+        assert_lock(staker_account, 300);
+        assert_stake(staker_account, &smart_contract, 93);
+        assert_ok!(DappStaking::set_claim_bounty(
+            RuntimeOrigin::signed(staker_account),
+            Perbill::from_percent(10)
+        ));
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        let claimer_account = 3;
+        let (init_staker_balance, init_claimer_balance) = (
+            Balances::free_balance(&staker_account),
+            Balances::free_balance(&claimer_account),
+        );
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(claimer_account),
+            staker_account
+        ));
+
+        // for this simple test, entire staker reward pool goes to the staker
+        let reward = <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0;
+        assert!(
+            Perbill::from_percent(10) * reward > MAX_CLAIM_BOUNTY,
+            "Sanity check."
+        );
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimBountyPaid {
+            claimer: claimer_account,
+            account: staker_account,
+            amount: MAX_CLAIM_BOUNTY,
+        }));
+
+        assert_eq!(
+            Balances::free_balance(&staker_account),
+            init_staker_balance + reward - MAX_CLAIM_BOUNTY
+        );
+        assert_eq!(
+            Balances::free_balance(&claimer_account),
+            init_claimer_balance + MAX_CLAIM_BOUNTY
+        );
+    })
+}
+
+#[test]
+fn claim_bonus_reward_for_pays_claim_bounty() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let staker_account = 2;
+        assert_lock(staker_account, 300);
+        assert_stake(staker_account, &smart_contract, 93);
+        assert_ok!(DappStaking::set_claim_bounty(
+            RuntimeOrigin::signed(staker_account),
+            Perbill::from_percent(1)
+        ));
+
+        // Advance to the next period, and claim the bonus
+        advance_to_next_period();
+        let claimer_account = 3;
+        let (init_staker_balance, init_claimer_balance) = (
+            Balances::free_balance(&staker_account),
+            Balances::free_balance(&claimer_account),
+        );
+
+        assert_ok!(DappStaking::claim_bonus_reward_for(
+            RuntimeOrigin::signed(claimer_account),
+            staker_account,
+            smart_contract.clone()
+        ));
+
+        // for this simple test, entire bonus reward pool goes to the staker
+        let reward = <Test as Config>::StakingRewardHandler::bonus_reward_pool();
+        let claim_bounty = Perbill::from_percent(1) * reward;
+        assert!(claim_bounty < MAX_CLAIM_BOUNTY, "Sanity check.");
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimBountyPaid {
+            claimer: claimer_account,
+            account: staker_account,
+            amount: claim_bounty,
+        }));
+
+        assert_eq!(
+            Balances::free_balance(&staker_account),
+            init_staker_balance + reward - claim_bounty
+        );
+        assert_eq!(
+            Balances::free_balance(&claimer_account),
+            init_claimer_balance + claim_bounty
+        );
+    })
+}
+
+#[test]
+fn claim_bounty_not_paid_for_own_claim() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let staker_account = 2;
+        assert_lock(staker_account, 300);
+        assert_stake(staker_account, &smart_contract, 93);
+        assert_ok!(DappStaking::set_claim_bounty(
+            RuntimeOrigin::signed(staker_account),
+            Perbill::from_percent(1)
+        ));
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        // Claiming own rewards via the delegated call pays out the entire reward to the staker
+        let init_staker_balance = Balances::free_balance(&staker_account);
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(staker_account),
+            staker_account
+        ));
+
+        let reward = <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0;
+        assert_eq!(
+            Balances::free_balance(&staker_account),
+            init_staker_balance + reward
+        );
+        assert!(!System::events().iter().any(|e| matches!(
+            e.event,
+            RuntimeEvent::DappStaking(Event::ClaimBountyPaid { .. })
+        )));
+    })
+}
+
+#[test]
+fn claim_bounty_not_paid_without_opt_in() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let staker_account = 2;
+        assert_lock(staker_account, 300);
+        assert_stake(staker_account, &smart_contract, 93);
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        // Third party claims the rewards, but staker hasn't opted into paying the bounty
+        let claimer_account = 3;
+        let (init_staker_balance, init_claimer_balance) = (
+            Balances::free_balance(&staker_account),
+            Balances::free_balance(&claimer_account),
+        );
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(claimer_account),
+            staker_account
+        ));
+
+        let reward = <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0;
+        assert_eq!(
+            Balances::free_balance(&staker_account),
+            init_staker_balance + reward
+        );
+        assert_eq!(
+            Balances::free_balance(&claimer_account),
+            init_claimer_balance
+        );
+        assert!(!System::events().iter().any(|e| matches!(
+            e.event,
+            RuntimeEvent::DappStaking(Event::ClaimBountyPaid { .. })
+        )));
+    })
+}
+
+#[test]
+fn set_claim_bounty_works() {
+    ExtBuilder::build().execute_with(|| {
+        let account = 2;
+        assert_lock(account, 300);
+        assert!(
+            StakerPreferencesOf::<Test>::get(&account)
+                .claim_bounty
+                .is_zero(),
+            "Sanity check."
+        );
+
+        // Opt into paying the maximum claim bounty
+        let rate = <Test as Config>::MaxClaimBountyRate::get();
+        assert_ok!(DappStaking::set_claim_bounty(
+            RuntimeOrigin::signed(account),
+            rate
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ClaimBountySet {
+            account,
+            rate,
+        }));
+        assert_eq!(
+            StakerPreferencesOf::<Test>::get(&account).claim_bounty,
+            rate
+        );
+
+        // Opt out again
+        assert_ok!(DappStaking::set_claim_bounty(
+            RuntimeOrigin::signed(account),
+            Perbill::zero()
+        ));
+        assert!(StakerPreferencesOf::<Test>::get(&account)
+            .claim_bounty
+            .is_zero());
+    })
+}
+
+#[test]
+fn set_claim_bounty_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let rate = <Test as Config>::MaxClaimBountyRate::get();
+
+        // No locked funds
+        assert_noop!(
+            DappStaking::set_claim_bounty(RuntimeOrigin::signed(2), rate),
+            Error::<Test>::NoLockedFunds,
+        );
+
+        // Rate above the maximum
+        assert_lock(2, 300);
+        assert_noop!(
+            DappStaking::set_claim_bounty(
+                RuntimeOrigin::signed(2),
+                rate.saturating_add(Perbill::from_parts(1))
+            ),
+            Error::<Test>::ClaimBountyTooLarge,
+        );
+    })
+}
+
+#[test]
+fn get_claimable_staker_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Nothing to claim yet
+        assert!(DappStaking::get_claimable_staker_rewards(&account).is_zero());
+
+        // Advance a few eras, and ensure the queried amount matches the claimed amount.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let claimable_amount = DappStaking::get_claimable_staker_rewards(&account);
+        assert!(claimable_amount > 0);
+
+        let init_balance = Balances::free_balance(&account);
+        assert_claim_staker_rewards(account);
+        assert_eq!(
+            Balances::free_balance(&account),
+            init_balance + claimable_amount
+        );
+
+        // Everything has been claimed
+        assert!(DappStaking::get_claimable_staker_rewards(&account).is_zero());
+    })
+}
+
+#[test]
+fn get_claimable_staker_rewards_per_era_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Advance to the next period, so multiple claim calls are required to claim everything.
+        advance_to_next_period();
+        let claimable_rewards = DappStaking::get_claimable_staker_rewards_per_era(&account);
+        assert!(!claimable_rewards.is_empty());
+        let claimable_amount: Balance = claimable_rewards.iter().map(|(_, reward)| reward).sum();
+
+        let init_balance = Balances::free_balance(&account);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_eq!(
+            Balances::free_balance(&account),
+            init_balance + claimable_amount
+        );
+
+        // Everything has been claimed
+        assert!(DappStaking::get_claimable_staker_rewards_per_era(&account).is_empty());
+    })
+}
+
+#[test]
+fn get_claimable_bonus_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Bonus reward isn't claimable during the ongoing period
+        assert!(DappStaking::get_claimable_bonus_rewards(&account).is_empty());
+        assert!(DappStaking::get_claimable_bonus_reward(&account, &smart_contract).is_zero());
+
+        advance_to_next_period();
+        let claimable_rewards = DappStaking::get_claimable_bonus_rewards(&account);
+        assert_eq!(claimable_rewards.len(), 1);
+        let (claimable_contract, claimable_amount) = claimable_rewards[0];
+        assert_eq!(claimable_contract, smart_contract);
+        assert_eq!(
+            DappStaking::get_claimable_bonus_reward(&account, &smart_contract),
+            claimable_amount
+        );
+
+        let init_balance = Balances::free_balance(&account);
+        assert_claim_bonus_reward(account, &smart_contract);
+        assert_eq!(
+            Balances::free_balance(&account),
+            init_balance + claimable_amount
+        );
+
+        // Everything has been claimed
+        assert!(DappStaking::get_claimable_bonus_rewards(&account).is_empty());
+        assert!(DappStaking::get_claimable_bonus_reward(&account, &smart_contract).is_zero());
+    })
+}
+
+#[test]
+fn get_claimable_dapp_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance a few eras so there are multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let claimable_rewards = DappStaking::get_claimable_dapp_rewards(&smart_contract);
+        assert!(!claimable_rewards.is_empty());
+        assert!(
+            claimable_rewards.windows(2).all(|w| w[0].0 < w[1].0),
+            "Rewards must be sorted by era."
+        );
+
+        for (era, amount) in claimable_rewards.iter() {
+            let init_balance = Balances::free_balance(&dev_account);
+            assert_claim_dapp_reward(account, &smart_contract, *era);
+            assert_eq!(Balances::free_balance(&dev_account), init_balance + amount);
+        }
+
+        // Everything has been claimed
+        assert!(DappStaking::get_claimable_dapp_rewards(&smart_contract).is_empty());
+
+        // Register another smart contract, which shares part of its reward with the stakers
+        let sharing_dev_account = 3;
+        let sharing_contract = MockSmartContract::wasm(3 as AccountId);
+        assert_register(sharing_dev_account, &sharing_contract);
+        let share = Perbill::from_percent(40);
+        assert_ok!(DappStaking::set_dapp_reward_share(
+            RuntimeOrigin::signed(sharing_dev_account),
+            sharing_contract.clone(),
+            share
+        ));
+        assert_lock(account, amount);
+        assert_stake(account, &sharing_contract, amount);
+
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let claimable_rewards = DappStaking::get_claimable_dapp_rewards(&sharing_contract);
+        assert!(!claimable_rewards.is_empty());
+
+        // Only the part which isn't shared with the stakers is claimable
+        let dapp_id = IntegratedDApps::<Test>::get(&sharing_contract).unwrap().id;
+        for (era, amount) in claimable_rewards.iter() {
+            let dapp_tiers = DAppTiers::<Test>::get(era).unwrap();
+            let dapp_reward = dapp_tiers.reward_for(*dapp_tiers.dapps.get(&dapp_id).unwrap());
+            assert!(!(share * dapp_reward).is_zero(), "Sanity check.");
+            assert_eq!(*amount, dapp_reward - share * dapp_reward);
+
+            let init_balance = Balances::free_balance(&sharing_dev_account);
+            assert_claim_dapp_reward(account, &sharing_contract, *era);
+            assert_eq!(
+                Balances::free_balance(&sharing_dev_account),
+                init_balance + amount
+            );
+        }
+    })
+}
+
+#[test]
+fn set_auto_restake_works() {
+    ExtBuilder::build().execute_with(|| {
+        let account = 2;
+        assert_lock(account, 300);
+        assert!(
+            !StakerPreferencesOf::<Test>::get(&account).auto_restake,
+            "Sanity check."
+        );
+
+        // Enable auto-restake
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoRestakeSet {
+            account,
+            enabled: true,
+        }));
+        assert!(StakerPreferencesOf::<Test>::get(&account).auto_restake);
+
+        // Disable it again
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoRestakeSet {
+            account,
+            enabled: false,
+        }));
+        assert!(!StakerPreferencesOf::<Test>::get(&account).auto_restake);
+    })
+}
+
+#[test]
+fn staker_preferences_are_removed_with_ledger() {
+    ExtBuilder::build().execute_with(|| {
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        // Unlock & claim everything, removing the ledger
+        assert_unlock(account, lock_amount);
+        run_for_blocks(DappStaking::unlocking_period());
+        assert_claim_unlocked(account);
+
+        assert!(!Ledger::<Test>::contains_key(&account));
+        assert!(!StakerPreferencesOf::<Test>::contains_key(&account));
+    })
+}
+
+#[test]
+fn set_auto_restake_without_locked_funds_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            DappStaking::set_auto_restake(RuntimeOrigin::signed(2), true),
+            Error::<Test>::NoLockedFunds,
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_auto_restake_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts, lock&stake some amount on both of them
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        let account = 2;
+        let lock_amount = 1000;
+        assert_lock(account, lock_amount);
+        let (stake_amount_1, stake_amount_2) = (100, 300);
+        assert_stake(account, &smart_contract_1, stake_amount_1);
+        assert_stake(account, &smart_contract_2, stake_amount_2);
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        // Advance a few eras, and claim the rewards
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let reward = DappStaking::get_claimable_staker_rewards(&account);
+        assert!(reward > 0);
+        assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+            account
+        )));
+
+        // Rewards must have been locked, and staked proportionally to the existing stake
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.active_locked_amount(), lock_amount + reward);
+
+        let restaked_1 =
+            Perbill::from_rational(stake_amount_1, stake_amount_1 + stake_amount_2) * reward;
+        let restaked_2 = reward - restaked_1;
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_1)
+                .unwrap()
+                .total_staked_amount(),
+            stake_amount_1 + restaked_1
+        );
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .total_staked_amount(),
+            stake_amount_2 + restaked_2
+        );
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::Locked {
+            account,
+            amount: reward,
+        }));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Stake {
+            account,
+            smart_contract: smart_contract_2,
+            amount: restaked_2,
+        }));
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_auto_restake_and_no_ongoing_stake_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        assert_stake(account, &smart_contract, 100);
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        // Advance to the next period - there's no ongoing stake to restake the rewards on.
+        advance_to_next_period();
+        let init_balance = Balances::free_balance(&account);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+                account
+            )));
+        }
+
+        // Rewards remain liquid
+        assert!(Balances::free_balance(&account) > init_balance);
+        assert_eq!(
+            Ledger::<Test>::get(&account).active_locked_amount(),
+            lock_amount
+        );
+        assert!(System::events().iter().any(|e| matches!(
+            e.event,
+            RuntimeEvent::DappStaking(Event::RestakeSkipped { account: acc, .. }) if acc == account
+        )));
+    })
+}
+
+#[test]
+fn move_stake_basic_example_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let account = 2;
+        assert_lock(account, 400);
+
+        // Stake some amount during voting subperiod, and some during build&earn subperiod
+        assert_stake(account, &source_contract, 100);
+        advance_to_next_subperiod();
+        assert_stake(account, &source_contract, 50);
+
+        let pre_ledger = Ledger::<Test>::get(&account);
+        let pre_era_info = CurrentEraInfo::<Test>::get();
+
+        // Move some stake, which should be taken from the build&earn stake first
+        let move_amount = 70;
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            source_contract,
+            destination_contract,
+            move_amount,
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+            account,
+            source_contract,
+            destination_contract,
+            amount: move_amount,
+        }));
+
+        // Voting & build&earn split is preserved
+        let source_info = StakerInfo::<Test>::get(&account, &source_contract).unwrap();
+        assert_eq!(source_info.staked_amount(Subperiod::Voting), 80);
+        assert_eq!(source_info.staked_amount(Subperiod::BuildAndEarn), 0);
+        let destination_info = StakerInfo::<Test>::get(&account, &destination_contract).unwrap();
+        assert_eq!(destination_info.staked_amount(Subperiod::Voting), 20);
+        assert_eq!(destination_info.staked_amount(Subperiod::BuildAndEarn), 50);
+        assert_eq!(
+            destination_info.era(),
+            ActiveProtocolState::<Test>::get().era + 1
+        );
+
+        // Total stake remains the same, only split between two contracts now
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(
+            ledger.staked_amount(period),
+            pre_ledger.staked_amount(period)
+        );
+        assert_eq!(ledger.contract_stake_count, 2);
+        assert_eq!(
+            CurrentEraInfo::<Test>::get().total_staked_amount_next_era(),
+            pre_era_info.total_staked_amount_next_era()
+        );
+
+        let source_id = IntegratedDApps::<Test>::get(&source_contract).unwrap().id;
+        let destination_id = IntegratedDApps::<Test>::get(&destination_contract)
+            .unwrap()
+            .id;
+        assert_eq!(
+            ContractStake::<Test>::get(&source_id).total_staked_amount(period),
+            80
+        );
+        assert_eq!(
+            ContractStake::<Test>::get(&destination_id).staked_amount(period, Subperiod::Voting),
+            20
+        );
+        assert_eq!(
+            ContractStake::<Test>::get(&destination_id)
+                .staked_amount(period, Subperiod::BuildAndEarn),
+            50
+        );
+    })
+}
+
+#[test]
+fn move_stake_preserves_loyalty() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let account = 2;
+        assert_lock(account, 400);
+
+        // Stake during voting subperiod, to become a loyal staker
+        assert_stake(account, &source_contract, 100);
+        advance_to_next_subperiod();
+        assert!(StakerInfo::<Test>::get(&account, &source_contract)
+            .unwrap()
+            .is_loyal());
+
+        // Move part of the stake, loyalty is kept on both sides
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            source_contract,
+            destination_contract,
+            40,
+        ));
+        assert!(StakerInfo::<Test>::get(&account, &source_contract)
+            .unwrap()
+            .is_loyal());
+        assert!(StakerInfo::<Test>::get(&account, &destination_contract)
+            .unwrap()
+            .is_loyal());
+
+        // Move everything that's left, source entry is removed
+        advance_to_next_era();
+        assert_claim_staker_rewards(account);
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            source_contract,
+            destination_contract,
+            60,
+        ));
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account,
+            &source_contract
+        ));
+        let destination_info = StakerInfo::<Test>::get(&account, &destination_contract).unwrap();
+        assert!(destination_info.is_loyal());
+        assert_eq!(destination_info.total_staked_amount(), 100);
+        assert_eq!(Ledger::<Test>::get(&account).contract_stake_count, 1);
+    })
+}
+
+#[test]
+fn move_stake_from_non_loyal_entry_removes_destination_loyalty() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let account = 2;
+        assert_lock(account, 400);
+
+        // Stake on both contracts during voting subperiod, to become a loyal staker
+        assert_stake(account, &source_contract, 100);
+        assert_stake(account, &destination_contract, 100);
+        advance_to_next_subperiod();
+
+        // Unstaking voting stake during build&earn subperiod forfeits the loyalty of the source entry
+        assert_unstake(account, &source_contract, 10);
+        assert!(!StakerInfo::<Test>::get(&account, &source_contract)
+            .unwrap()
+            .is_loyal());
+        assert!(StakerInfo::<Test>::get(&account, &destination_contract)
+            .unwrap()
+            .is_loyal());
+
+        // Moving the non-loyal voting stake makes the destination entry lose its loyalty too
+        advance_to_next_era();
+        assert_claim_staker_rewards(account);
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            source_contract,
+            destination_contract,
+            40,
+        ));
+        assert!(!StakerInfo::<Test>::get(&account, &source_contract)
+            .unwrap()
+            .is_loyal());
+        let destination_info = StakerInfo::<Test>::get(&account, &destination_contract).unwrap();
+        assert!(!destination_info.is_loyal());
+        assert_eq!(destination_info.staked_amount(Subperiod::Voting), 140);
+    })
+}
+
+#[test]
+fn move_stake_with_leftover_amount_below_minimum_moves_everything() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+
+        let min_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+        assert_stake(account, &source_contract, min_stake_amount + 10);
+
+        // Move some amount, bringing the source stake below the minimum
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            source_contract,
+            destination_contract,
+            11,
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+            account,
+            source_contract,
+            destination_contract,
+            amount: min_stake_amount + 10,
+        }));
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account,
+            &source_contract
+        ));
+        assert_eq!(Ledger::<Test>::get(&account).contract_stake_count, 1);
+    })
+}
+
+#[test]
+fn move_stake_fails_due_to_invalid_arguments() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &source_contract, 100);
+
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                source_contract,
+                destination_contract,
+                0
+            ),
+            Error::<Test>::ZeroAmount,
+        );
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                source_contract,
+                source_contract,
+                10
+            ),
+            Error::<Test>::SameSourceAndDestination,
+        );
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                source_contract,
+                MockSmartContract::wasm(3 as AccountId),
+                10
+            ),
+            Error::<Test>::ContractNotFound,
+        );
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                destination_contract,
+                source_contract,
+                10
+            ),
+            Error::<Test>::NoStakingInfo,
+        );
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                source_contract,
+                destination_contract,
+                101
+            ),
+            Error::<Test>::UnstakeAmountTooLarge,
+        );
+    })
+}
+
+#[test]
+fn contract_staker_count_is_tracked() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let (account_1, account_2) = (2, 3);
+        assert_lock(account_1, 300);
+        assert_lock(account_2, 300);
+
+        // Each staker is counted only once
+        assert_stake(account_1, &source_contract, 100);
+        assert_stake(account_2, &source_contract, 100);
+        assert_stake(account_1, &source_contract, 100);
+        assert_eq!(DappStaking::get_contract_staker_count(&source_contract), 2);
+        assert!(DappStaking::get_contract_staker_count(&destination_contract).is_zero());
+
+        // Full unstake reduces the count
+        assert_unstake(account_2, &source_contract, 100);
+        assert_eq!(DappStaking::get_contract_staker_count(&source_contract), 1);
+
+        // Moving the entire stake moves the staker over to the destination contract
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account_1),
+            source_contract,
+            destination_contract,
+            200,
+        ));
+        assert!(DappStaking::get_contract_staker_count(&source_contract).is_zero());
+        assert_eq!(
+            DappStaking::get_contract_staker_count(&destination_contract),
+            1
+        );
+
+        // Count doesn't carry over into the next period
+        advance_to_next_period();
+        assert!(DappStaking::get_contract_staker_count(&destination_contract).is_zero());
+
+        // Unknown contract has no stakers
+        assert!(
+            DappStaking::get_contract_staker_count(&MockSmartContract::wasm(3 as AccountId))
+                .is_zero()
+        );
+    })
+}
+
+#[test]
+fn get_dapp_tier_assignment_and_rewards_respects_min_stakers() {
+    ExtBuilder::build().execute_with(|| {
+        // This test will rely on the configuration inside the mock file.
+        // If that changes, this test might have to be updated as well.
+        let threshold = TierConfig::<Test>::get().tier_thresholds[0];
+
+        // 1st tier requires at least 2 unique stakers
+        StaticTierParams::<Test>::mutate(|params| {
+            params.min_stakers = BoundedVec::try_from(vec![2, 1, 0, 0]).unwrap();
+        });
+
+        let single_staker_contract = MockSmartContract::wasm(1 as AccountId);
+        let multi_staker_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &single_staker_contract);
+        assert_register(1, &multi_staker_contract);
+
+        fn lock_and_stake(account: AccountId, smart_contract: &MockSmartContract, amount: Balance) {
+            Balances::make_free_balance_be(&account, amount);
+            assert_lock(account, amount);
+            assert_stake(account, smart_contract, amount);
+        }
+
+        // dApp with the highest stake, but only a single staker
+        lock_and_stake(1, &single_staker_contract, threshold * 3);
+        // dApp with two stakers, satisfying the 1st tier threshold together
+        lock_and_stake(2, &multi_staker_contract, threshold);
+        lock_and_stake(3, &multi_staker_contract, threshold);
+
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let (tier_assignment, _) = DappStaking::get_dapp_tier_assignment_and_rewards(
+            protocol_state.era + 1,
+            protocol_state.period_number(),
+            1_000_000,
+        );
+
+        // dApp with too few stakers for the 1st tier falls into the 2nd tier
+        assert_eq!(tier_assignment.dapps.len(), 2);
+        assert_eq!(tier_assignment.dapps[&0].tier(), 1);
+        assert_eq!(tier_assignment.dapps[&1].tier(), 0);
+    })
+}
+
+#[test]
+fn queue_tier_params_works() {
+    ExtBuilder::build().execute_with(|| {
+        let init_params = StaticTierParams::<Test>::get();
+        let mut new_params = init_params.clone();
+        new_params.reward_portion = BoundedVec::try_from(vec![
+            Permill::from_percent(25),
+            Permill::from_percent(25),
+            Permill::from_percent(25),
+            Permill::from_percent(25),
+        ])
+        .unwrap();
+        assert_ne!(init_params, new_params, "Sanity check.");
+
+        // Queue the new params, using the manager account
+        assert_ok!(DappStaking::queue_tier_params(
+            RuntimeOrigin::signed(ManagerAccount::get()),
+            new_params.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::TierParamsQueued {
+            params: new_params.clone(),
+        }));
+        assert_eq!(QueuedTierParams::<Test>::get(), Some(new_params.clone()));
+
+        // Params aren't applied on era change, only on the period change
+        advance_to_next_era();
+        advance_to_next_era();
+        assert_eq!(StaticTierParams::<Test>::get(), init_params);
+        assert_eq!(QueuedTierParams::<Test>::get(), Some(new_params.clone()));
+
+        advance_to_next_period();
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        assert_eq!(StaticTierParams::<Test>::get(), new_params);
+        assert!(QueuedTierParams::<Test>::get().is_none());
+        assert!(dapp_staking_events().contains(&Event::TierParamsApplied {
+            period: current_period,
+        }));
+
+        // Nothing is applied on the next period change, since nothing is queued
+        advance_to_next_period();
+        assert_eq!(StaticTierParams::<Test>::get(), new_params);
+    })
+}
+
+#[test]
+fn queue_tier_params_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let params = StaticTierParams::<Test>::get();
+
+        // Only manager origin can queue new params
+        assert_noop!(
+            DappStaking::queue_tier_params(RuntimeOrigin::signed(1), params.clone()),
+            BadOrigin
+        );
+
+        // Invalid params are rejected
+        let mut invalid_params = params.clone();
+        invalid_params.reward_portion = BoundedVec::try_from(vec![
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+        ])
+        .unwrap();
+        assert_noop!(
+            DappStaking::queue_tier_params(RuntimeOrigin::root(), invalid_params),
+            Error::<Test>::InvalidTierParams
+        );
+
+        let mut invalid_params = params;
+        invalid_params.slot_distribution = BoundedVec::default();
+        assert_noop!(
+            DappStaking::queue_tier_params(RuntimeOrigin::root(), invalid_params),
+            Error::<Test>::InvalidTierParams
+        );
+    })
+}
+
+#[test]
+fn dapp_period_archive_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock & stake enough to enter the tiers
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        let amount = TierConfig::<Test>::get().tier_thresholds[0];
+        Balances::make_free_balance_be(&2, amount);
+        assert_lock(2, amount);
+        assert_stake(2, &smart_contract, amount);
+
+        // Nothing is archived during the voting subperiod
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        advance_to_next_era();
+        assert!(DappStaking::get_dapp_period_archive(dapp_id).is_empty());
+
+        // Each build&earn era is recorded into the summary of the ongoing period
+        advance_to_next_period();
+        let archive = DappStaking::get_dapp_period_archive(dapp_id);
+        assert_eq!(archive.len(), 1);
+
+        let mut expected_summary =
+            DAppPeriodSummary::<<Test as Config>::NumberOfTiers>::new(period);
+        for (_, dapp_tiers) in DAppTiers::<Test>::iter() {
+            if let Some(ranked_tier) = dapp_tiers.dapps.get(&dapp_id) {
+                expected_summary.record_era(
+                    ranked_tier.tier(),
+                    dapp_tiers.reward_for(*ranked_tier),
+                    amount,
+                );
+            }
+        }
+        assert!(!expected_summary.reward.is_zero(), "Sanity check.");
+        assert_eq!(archive[0], expected_summary);
+        assert_eq!(archive[0].peak_stake, amount);
+
+        // Archive isn't affected by claiming or expiry of the dApp rewards
+        advance_to_period(period + <Test as Config>::RewardRetentionInPeriods::get() + 1);
+        for _ in 0..10 {
+            run_for_blocks(1);
+        }
+        assert_eq!(
+            DappStaking::get_dapp_period_archive(dapp_id),
+            vec![expected_summary.clone()]
+        );
+
+        // Archive remains accessible after the dApp has been unregistered
+        assert_unregister(&smart_contract);
+        assert_eq!(
+            DappStaking::get_dapp_period_archive(dapp_id),
+            vec![expected_summary]
+        );
+
+        // Unknown dApp has no archive
+        assert!(DappStaking::get_dapp_period_archive(dapp_id + 1).is_empty());
+    })
+}
+
+#[test]
+fn dapp_period_archive_is_bounded() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // Fill up the archive with the summaries of some past periods
+        let max_archived_periods = <Test as Config>::MaxArchivedPeriods::get();
+        advance_to_period(max_archived_periods + 1);
+        let past_summaries: Vec<_> = (1..=max_archived_periods)
+            .map(DAppPeriodSummary::new)
+            .collect();
+        DAppPeriodArchive::<Test>::insert(
+            dapp_id,
+            BoundedVec::try_from(past_summaries.clone()).unwrap(),
+        );
+
+        let amount = TierConfig::<Test>::get().tier_thresholds[0];
+        Balances::make_free_balance_be(&2, amount);
+        assert_lock(2, amount);
+        assert_stake(2, &smart_contract, amount);
+
+        // First build&earn era of the ongoing period replaces the oldest summary
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        advance_to_next_era();
+        advance_to_next_era();
+
+        let archive = DappStaking::get_dapp_period_archive(dapp_id);
+        assert_eq!(archive.len(), max_archived_periods as usize);
+        assert_eq!(archive[..archive.len() - 1], past_summaries[1..]);
+        assert_eq!(archive.last().unwrap().period, period);
+        assert_eq!(archive.last().unwrap().eras_per_tier.iter().sum::<u32>(), 1);
+    })
+}
+
+#[test]
+fn simulate_dapp_tier_assignment_works() {
+    ExtBuilder::build().execute_with(|| {
+        // This test will rely on the configuration inside the mock file.
+        // If that changes, this test might have to be updated as well.
+        let threshold = TierConfig::<Test>::get().tier_thresholds[0];
+
+        let contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &contract_1);
+        assert_register(1, &contract_2);
+        let dapp_id_1 = IntegratedDApps::<Test>::get(&contract_1).unwrap().id;
+        let dapp_id_2 = IntegratedDApps::<Test>::get(&contract_2).unwrap().id;
+
+        fn lock_and_stake(account: AccountId, smart_contract: &MockSmartContract, amount: Balance) {
+            Balances::make_free_balance_be(&account, amount);
+            assert_lock(account, amount);
+            assert_stake(account, smart_contract, amount);
+        }
+
+        // 1st dApp satisfies the 1st tier threshold, 2nd dApp doesn't
+        lock_and_stake(1, &contract_1, threshold);
+        lock_and_stake(2, &contract_2, threshold / 2);
+
+        // Without any deltas, simulation is based on the stake for the next era
+        let simulation = DappStaking::simulate_dapp_tier_assignment(vec![]);
+        assert_eq!(
+            simulation.period,
+            ActiveProtocolState::<Test>::get().period_number()
+        );
+        assert_eq!(simulation.dapps[&dapp_id_1].tier(), 0);
+        assert!(simulation
+            .dapps
+            .get(&dapp_id_2)
+            .map_or(true, |ranked_tier| ranked_tier.tier() > 0));
+
+        // Move the stake from the 1st dApp to the 2nd one, ignoring the unregistered contract
+        let stake_deltas = vec![
+            (contract_1.clone(), -(threshold as i128)),
+            (contract_2.clone(), (threshold / 2) as i128),
+            (MockSmartContract::wasm(3 as AccountId), threshold as i128),
+        ];
+        assert_storage_noop!(DappStaking::simulate_dapp_tier_assignment(
+            stake_deltas.clone()
+        ));
+        let what_if_simulation = DappStaking::simulate_dapp_tier_assignment(stake_deltas);
+        assert!(!what_if_simulation.dapps.contains_key(&dapp_id_1));
+        assert_eq!(what_if_simulation.dapps[&dapp_id_2].tier(), 0);
+        assert_eq!(what_if_simulation.dapps.len(), 1);
+
+        // Simulation without deltas matches the regular tier assignment for the next era
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let (_, dapp_reward_pool) =
+            <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(
+                CurrentEraInfo::<Test>::get().total_staked_amount_next_era(),
+            );
+        let (tier_assignment, _) = DappStaking::get_dapp_tier_assignment_and_rewards(
+            protocol_state.era + 1,
+            protocol_state.period_number(),
+            dapp_reward_pool,
+        );
+        assert_eq!(tier_assignment, simulation);
+    })
+}
+
+#[test]
+fn simulate_dapp_tier_assignment_counts_new_staker() {
+    ExtBuilder::build().execute_with(|| {
+        // This test will rely on the configuration inside the mock file.
+        // If that changes, this test might have to be updated as well.
+        let threshold = TierConfig::<Test>::get().tier_thresholds[0];
+
+        // 1st tier requires at least 2 unique stakers
+        StaticTierParams::<Test>::mutate(|params| {
+            params.min_stakers = BoundedVec::try_from(vec![2, 1, 0, 0]).unwrap();
+        });
+
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        Balances::make_free_balance_be(&1, threshold);
+        assert_lock(1, threshold);
+        assert_stake(1, &smart_contract, threshold);
+
+        // Single staker isn't enough for the 1st tier, regardless of the stake
+        let simulation = DappStaking::simulate_dapp_tier_assignment(vec![]);
+        assert_eq!(simulation.dapps[&dapp_id].tier(), 1);
+
+        // Negative delta doesn't add a staker
+        let simulation =
+            DappStaking::simulate_dapp_tier_assignment(vec![(smart_contract.clone(), -1)]);
+        assert_eq!(simulation.dapps[&dapp_id].tier(), 1);
+
+        // Positive delta is treated as the stake of a new staker, which satisfies the minimum
+        let simulation = DappStaking::simulate_dapp_tier_assignment(vec![(smart_contract, 1)]);
+        assert_eq!(simulation.dapps[&dapp_id].tier(), 0);
+    })
+}
+
+#[test]
+fn stake_many_and_unstake_many_work() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts & lock some amount
+        let smart_contracts: Vec<_> = (1..=3)
+            .map(|idx| MockSmartContract::wasm(idx as AccountId))
+            .collect();
+        for smart_contract in &smart_contracts {
+            assert_register(1, smart_contract);
+        }
+
+        let account = 2;
+        assert_lock(account, 600);
+
+        // Stake on all contracts in a single call
+        let stakes: BoundedVec<_, _> = smart_contracts
+            .iter()
+            .cloned()
+            .zip([100, 150, 200])
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        assert_ok!(DappStaking::stake_many(
+            RuntimeOrigin::signed(account),
+            stakes.clone()
+        ));
+
+        let events = dapp_staking_events();
+        let stake_events = &events[events.len() - stakes.len()..];
+        for ((smart_contract, amount), event) in stakes.iter().zip(stake_events) {
+            assert_eq!(
+                event,
+                &Event::Stake {
+                    account,
+                    smart_contract: smart_contract.clone(),
+                    amount: *amount,
+                }
+            );
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, smart_contract)
+                    .unwrap()
+                    .total_staked_amount(),
+                *amount
+            );
+        }
+
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        assert_eq!(
+            Ledger::<Test>::get(&account).staked_amount(current_period),
+            450
+        );
+
+        // Unstake from all contracts in a single call
+        let unstakes: BoundedVec<_, _> = smart_contracts
+            .iter()
+            .cloned()
+            .zip([30, 50, 70])
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        assert_ok!(DappStaking::unstake_many(
+            RuntimeOrigin::signed(account),
+            unstakes.clone()
+        ));
+
+        let events = dapp_staking_events();
+        let unstake_events = &events[events.len() - unstakes.len()..];
+        for (((smart_contract, amount), event), staked) in
+            unstakes.iter().zip(unstake_events).zip([100, 150, 200])
+        {
+            assert_eq!(
+                event,
+                &Event::Unstake {
+                    account,
+                    smart_contract: smart_contract.clone(),
+                    amount: *amount,
+                }
+            );
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, smart_contract)
+                    .unwrap()
+                    .total_staked_amount(),
+                staked - amount
+            );
+        }
+        assert_eq!(
+            Ledger::<Test>::get(&account).staked_amount(current_period),
+            300
+        );
+    })
+}
+
+#[test]
+fn stake_many_and_unstake_many_are_all_or_nothing() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+
+        let account = 2;
+        assert_lock(account, 300);
+
+        // Empty batches are rejected
+        assert_noop!(
+            DappStaking::stake_many(RuntimeOrigin::signed(account), BoundedVec::new()),
+            Error::<Test>::ZeroAmount
+        );
+        assert_noop!(
+            DappStaking::unstake_many(RuntimeOrigin::signed(account), BoundedVec::new()),
+            Error::<Test>::ZeroAmount
+        );
+
+        // Second contract isn't registered, so nothing is staked
+        let stakes: BoundedVec<_, _> = vec![
+            (smart_contract_1.clone(), 100),
+            (smart_contract_2.clone(), 100),
+        ]
+        .try_into()
+        .unwrap();
+        assert_noop!(
+            DappStaking::stake_many(RuntimeOrigin::signed(account), stakes),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Second entry exceeds the locked amount, so nothing is staked
+        let stakes: BoundedVec<_, _> = vec![
+            (smart_contract_1.clone(), 100),
+            (smart_contract_1.clone(), 201),
+        ]
+        .try_into()
+        .unwrap();
+        assert_noop!(
+            DappStaking::stake_many(RuntimeOrigin::signed(account), stakes),
+            Error::<Test>::UnavailableStakeFunds
+        );
+
+        // Second entry exceeds the staked amount, so nothing is unstaked
+        assert_stake(account, &smart_contract_1, 100);
+        let unstakes: BoundedVec<_, _> = vec![
+            (smart_contract_1.clone(), 50),
+            (smart_contract_1.clone(), 51),
+        ]
+        .try_into()
+        .unwrap();
+        assert_noop!(
+            DappStaking::unstake_many(RuntimeOrigin::signed(account), unstakes),
+            Error::<Test>::UnstakeAmountTooLarge
+        );
+    })
+}
+
+#[test]
+fn set_dapp_reward_share_works() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let share = Perbill::from_percent(30);
+
+        // Contract must be registered
+        assert_noop!(
+            DappStaking::set_dapp_reward_share(
+                RuntimeOrigin::signed(owner),
+                smart_contract.clone(),
+                share
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Only owner can set the share
+        assert_register(owner, &smart_contract);
+        assert_noop!(
+            DappStaking::set_dapp_reward_share(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract.clone(),
+                share
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        assert_ok!(DappStaking::set_dapp_reward_share(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            share
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardShareUpdated {
+            smart_contract: smart_contract.clone(),
+            share,
+        }));
+        assert_eq!(DAppRewardShares::<Test>::get(&smart_contract), Some(share));
+
+        // Zero share disables reward sharing
+        assert_ok!(DappStaking::set_dapp_reward_share(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            Perbill::zero()
+        ));
+        assert!(!DAppRewardShares::<Test>::contains_key(&smart_contract));
+
+        // Share is removed once dApp is unregistered
+        assert_ok!(DappStaking::set_dapp_reward_share(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            share
+        ));
+        assert_unregister(&smart_contract);
+        assert!(!DAppRewardShares::<Test>::contains_key(&smart_contract));
+    })
+}
+
+#[test]
+fn shared_dapp_rewards_work() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract & enable reward sharing
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(owner, &smart_contract);
+        let share = Perbill::from_percent(40);
+        assert_ok!(DappStaking::set_dapp_reward_share(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            share
+        ));
+
+        // Two stakers, with different stake amounts
+        let (staker_1, stake_1) = (2, 300);
+        let (staker_2, stake_2) = (3, 100);
+        assert_lock(staker_1, stake_1);
+        assert_stake(staker_1, &smart_contract, stake_1);
+        assert_lock(staker_2, stake_2);
+        assert_stake(staker_2, &smart_contract, stake_2);
+
+        // Nothing to claim before any era has ended
+        assert_noop!(
+            DappStaking::claim_shared_dapp_rewards(
+                RuntimeOrigin::signed(staker_1),
+                smart_contract.clone()
+            ),
+            Error::<Test>::NoClaimableRewards
+        );
+
+        // Advance so the dApp earns some reward
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let reward_era = ActiveProtocolState::<Test>::get().era - 1;
+
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        let dapp_tiers = DAppTiers::<Test>::get(&reward_era).unwrap();
+        let dapp_reward = dapp_tiers.reward_for(*dapp_tiers.dapps.get(&dapp_id).unwrap());
+        let shared_reward = share * dapp_reward;
+        assert!(!shared_reward.is_zero(), "Sanity check.");
+        assert_eq!(
+            DAppSharedRewards::<Test>::get(
+                &DappStaking::era_reward_span_index(reward_era),
+                &smart_contract
+            )
+            .unwrap()
+            .get(reward_era),
+            Some(&EraReward {
+                staker_reward_pool: shared_reward,
+                staked: stake_1 + stake_2,
+                dapp_reward_pool: 0,
+            })
+        );
+
+        // dApp beneficiary only receives the part that isn't shared
+        assert_claim_dapp_reward(owner, &smart_contract, reward_era);
+
+        // First staker claims its part, pro rata to its stake
+        let expected_reward_1 = Perbill::from_rational(stake_1, stake_1 + stake_2) * shared_reward;
+        let pre_balance = <Test as Config>::Currency::free_balance(&staker_1);
+        assert_ok!(DappStaking::claim_shared_dapp_rewards(
+            RuntimeOrigin::signed(staker_1),
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::SharedDAppReward {
+            account: staker_1,
+            smart_contract: smart_contract.clone(),
+            era: reward_era,
+            amount: expected_reward_1,
+        }));
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker_1),
+            pre_balance + expected_reward_1
+        );
+
+        // Same reward can't be claimed twice
+        assert_noop!(
+            DappStaking::claim_shared_dapp_rewards(
+                RuntimeOrigin::signed(staker_1),
+                smart_contract.clone()
+            ),
+            Error::<Test>::NoClaimableRewards
+        );
+
+        // Second staker's part is paid out automatically when the stake is modified
+        let expected_reward_2 = Perbill::from_rational(stake_2, stake_1 + stake_2) * shared_reward;
+        assert_claim_staker_rewards(staker_2);
+        let pre_balance = <Test as Config>::Currency::free_balance(&staker_2);
+        assert_unstake(staker_2, &smart_contract, 10);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::SharedDAppReward {
+            account: staker_2,
+            smart_contract: smart_contract.clone(),
+            era: reward_era,
+            amount: expected_reward_2,
+        }));
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(&staker_2),
+            pre_balance + expected_reward_2
+        );
+        assert_noop!(
+            DappStaking::claim_shared_dapp_rewards(
+                RuntimeOrigin::signed(staker_2),
+                smart_contract.clone()
+            ),
+            Error::<Test>::NoClaimableRewards
+        );
+    })
+}
+
+#[test]
+fn shared_dapp_rewards_payout_weight_is_based_on_paid_out_spans() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract & enable reward sharing
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(owner, &smart_contract);
+        assert_ok!(DappStaking::set_dapp_reward_share(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            Perbill::from_percent(40)
+        ));
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // Advance so the dApp earns some reward, all within a single era reward span
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_claim_staker_rewards(account);
+
+        // Pending shared rewards of a single span are paid out, and only their weight is charged
+        let post_info =
+            DappStaking::stake(RuntimeOrigin::signed(account), smart_contract.clone(), 50).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(
+                <Test as Config>::WeightInfo::stake()
+                    + <Test as Config>::WeightInfo::claim_shared_dapp_rewards()
+            )
+        );
+
+        // Nothing is pending anymore, so no payout weight is charged
+        let post_info =
+            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract.clone(), 50)
+                .unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<Test as Config>::WeightInfo::unstake())
+        );
+    })
+}
+
+#[test]
+fn set_dapp_metadata_works() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let hold_reason: RuntimeHoldReason = HoldReason::DAppMetadata.into();
+
+        // Contract must be registered
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract.clone(),
+                BoundedVec::truncate_from(b"Astar dApp".to_vec()),
+                BoundedVec::truncate_from(b"https://astar.network".to_vec()),
+                BoundedVec::truncate_from(b"cid".to_vec()),
+                DAppCategory::Defi
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Only owner can set the metadata
+        assert_register(owner, &smart_contract);
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract.clone(),
+                BoundedVec::truncate_from(b"Astar dApp".to_vec()),
+                BoundedVec::truncate_from(b"https://astar.network".to_vec()),
+                BoundedVec::truncate_from(b"cid".to_vec()),
+                DAppCategory::Defi
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        // Set the metadata & check the deposit
+        let name = b"Astar dApp".to_vec();
+        let website = b"https://astar.network".to_vec();
+        let ipfs_cid = b"cid".to_vec();
+        let deposit = <Test as Config>::MetadataDepositBase::get()
+            + <Test as Config>::MetadataDepositPerByte::get()
+                * (name.len() + website.len() + ipfs_cid.len()) as Balance;
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            BoundedVec::truncate_from(name.clone()),
+            BoundedVec::truncate_from(website.clone()),
+            BoundedVec::truncate_from(ipfs_cid.clone()),
+            DAppCategory::Gaming
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMetadataSet {
+            smart_contract: smart_contract.clone(),
+            deposit,
+        }));
+
+        let metadata = DAppMetadataOf::<Test>::get(&smart_contract).expect("Entry must exist.");
+        assert_eq!(metadata.name.into_inner(), name);
+        assert_eq!(metadata.website.into_inner(), website);
+        assert_eq!(metadata.ipfs_cid.into_inner(), ipfs_cid);
+        assert_eq!(metadata.category, DAppCategory::Gaming);
+        assert_eq!(metadata.depositor, owner);
+        assert_eq!(metadata.deposit, deposit);
+        assert_eq!(Balances::balance_on_hold(&hold_reason, &owner), deposit);
+
+        // Update the metadata, old deposit is released & the new one is held
+        let name = b"dApp".to_vec();
+        let deposit = <Test as Config>::MetadataDepositBase::get()
+            + <Test as Config>::MetadataDepositPerByte::get() * name.len() as Balance;
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            BoundedVec::truncate_from(name),
+            BoundedVec::new(),
+            BoundedVec::new(),
+            DAppCategory::Other
+        ));
+        assert_eq!(
+            DappStaking::get_dapp_metadata(&smart_contract).map(|metadata| metadata.deposit),
+            Some(deposit)
+        );
+        assert_eq!(Balances::balance_on_hold(&hold_reason, &owner), deposit);
+
+        // Deposit is released & metadata removed once dApp is unregistered
+        assert_unregister(&smart_contract);
+        assert!(!DAppMetadataOf::<Test>::contains_key(&smart_contract));
+        assert!(Balances::balance_on_hold(&hold_reason, &owner).is_zero());
+    })
+}
+
+#[test]
+fn clear_dapp_metadata_works() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let hold_reason: RuntimeHoldReason = HoldReason::DAppMetadata.into();
+        assert_register(owner, &smart_contract);
+
+        // Metadata must exist
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::signed(owner), smart_contract.clone()),
+            Error::<Test>::NoDAppMetadata
+        );
+
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            BoundedVec::truncate_from(b"Astar dApp".to_vec()),
+            BoundedVec::truncate_from(b"https://astar.network".to_vec()),
+            BoundedVec::truncate_from(b"cid".to_vec()),
+            DAppCategory::Infrastructure
+        ));
+        let free_balance = Balances::free_balance(&owner);
+
+        // Only owner can clear the metadata
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract.clone()
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        let deposit = Balances::balance_on_hold(&hold_reason, &owner);
+        assert_ok!(DappStaking::clear_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMetadataCleared {
+            smart_contract: smart_contract.clone(),
+        }));
+
+        assert!(DappStaking::get_dapp_metadata(&smart_contract).is_none());
+        assert!(Balances::balance_on_hold(&hold_reason, &owner).is_zero());
+        assert_eq!(Balances::free_balance(&owner), free_balance + deposit);
+    })
+}
+
+#[test]
+fn add_and_remove_contract_alias_works() {
+    ExtBuilder::build().execute_with(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let alias = MockSmartContract::wasm(2 as AccountId);
+
+        // Contract must be registered
+        assert_noop!(
+            DappStaking::add_contract_alias(
+                RuntimeOrigin::root(),
+                smart_contract.clone(),
+                alias.clone()
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Alias must be approved by the contract register origin, not even the owner can add it
+        assert_register(owner, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        assert_noop!(
+            DappStaking::add_contract_alias(
+                RuntimeOrigin::signed(owner),
+                smart_contract.clone(),
+                alias.clone()
+            ),
+            BadOrigin
+        );
+
+        // Registered smart contract cannot be used as an alias
+        let other_contract = MockSmartContract::wasm(3 as AccountId);
+        assert_register(owner, &other_contract);
+        assert_noop!(
+            DappStaking::add_contract_alias(
+                RuntimeOrigin::root(),
+                smart_contract.clone(),
+                other_contract.clone()
+            ),
+            Error::<Test>::ContractAlreadyExists
+        );
+
+        // Add an alias
+        assert_ok!(DappStaking::add_contract_alias(
+            RuntimeOrigin::root(),
+            smart_contract.clone(),
+            alias.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ContractAliasAdded {
+            smart_contract: smart_contract.clone(),
+            alias: alias.clone(),
+        }));
+        assert_eq!(
+            ContractAliases::<Test>::get(&alias),
+            Some(smart_contract.clone())
+        );
+        assert_eq!(
+            DAppContractAliases::<Test>::get(&dapp_id).into_inner(),
+            vec![alias.clone()]
+        );
+        assert_eq!(
+            DappStaking::resolve_smart_contract(alias.clone()),
+            smart_contract
+        );
+
+        // Alias cannot be reused, nor registered as a new smart contract
+        assert_noop!(
+            DappStaking::add_contract_alias(
+                RuntimeOrigin::root(),
+                other_contract.clone(),
+                alias.clone()
+            ),
+            Error::<Test>::ContractAlreadyExists
+        );
+        assert_noop!(
+            DappStaking::register(RuntimeOrigin::root(), owner, alias.clone()),
+            Error::<Test>::ContractAlreadyExists
+        );
+
+        // Number of aliases is bounded
+        let max_aliases = <Test as Config>::MaxContractAliases::get();
+        for idx in 1..max_aliases {
+            assert_ok!(DappStaking::add_contract_alias(
+                RuntimeOrigin::root(),
+                smart_contract.clone(),
+                MockSmartContract::wasm((100 + idx) as AccountId)
+            ));
+        }
+        assert_noop!(
+            DappStaking::add_contract_alias(
+                RuntimeOrigin::root(),
+                smart_contract.clone(),
+                MockSmartContract::wasm(200 as AccountId)
+            ),
+            Error::<Test>::TooManyContractAliases
+        );
+
+        // Only owner can remove an alias, and it must belong to the dApp
+        assert_noop!(
+            DappStaking::remove_contract_alias(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract.clone(),
+                alias.clone()
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::remove_contract_alias(
+                RuntimeOrigin::signed(owner),
+                other_contract,
+                alias.clone()
+            ),
+            Error::<Test>::ContractAliasNotFound
+        );
+
+        // Remove the alias
+        assert_ok!(DappStaking::remove_contract_alias(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            alias.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::ContractAliasRemoved {
+            smart_contract: smart_contract.clone(),
+            alias: alias.clone(),
+        }));
+        assert!(!ContractAliases::<Test>::contains_key(&alias));
+        assert_eq!(
+            DAppContractAliases::<Test>::get(&dapp_id).len() as u32,
+            max_aliases - 1
+        );
+        assert_eq!(DappStaking::resolve_smart_contract(alias.clone()), alias);
+
+        // Remaining aliases are removed once dApp is unregistered
+        assert_unregister(&smart_contract);
+        assert!(DAppContractAliases::<Test>::get(&dapp_id).is_empty());
+        assert!(!ContractAliases::<Test>::contains_key(
+            &MockSmartContract::wasm(101 as AccountId)
+        ));
+    })
+}
+
+#[test]
+fn stake_and_unstake_via_contract_alias_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract with an alias
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        let alias = MockSmartContract::wasm(2 as AccountId);
+        assert_register(owner, &smart_contract);
+        assert_ok!(DappStaking::add_contract_alias(
+            RuntimeOrigin::root(),
+            smart_contract.clone(),
+            alias.clone()
+        ));
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // Stake on both the smart contract & its alias
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        assert_ok!(DappStaking::stake(
+            RuntimeOrigin::signed(account),
+            alias.clone(),
+            50
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Stake {
+            account,
+            smart_contract: smart_contract.clone(),
+            amount: 50,
+        }));
+
+        // Stake is tracked on the registered smart contract only
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        assert!(!StakerInfo::<Test>::contains_key(&account, &alias));
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract)
+                .unwrap()
+                .total_staked_amount(),
+            150
+        );
+        assert_eq!(
+            ContractStake::<Test>::get(&dapp_id).total_staked_amount(period),
+            150
+        );
+
+        // Unstake via the alias
+        assert_ok!(DappStaking::unstake(
+            RuntimeOrigin::signed(account),
+            alias.clone(),
+            30
+        ));
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract)
+                .unwrap()
+                .total_staked_amount(),
+            120
+        );
+
+        // Moving stake between the smart contract & its alias isn't possible
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                alias,
+                smart_contract.clone(),
+                10
+            ),
+            Error::<Test>::SameSourceAndDestination
+        );
+    })
+}
@@ -2077,6 +2077,18 @@ fn singular_staking_info_basics_are_ok() {
         staking_info.previous_staked.era, era_2,
         "Must be equal to the previous staked era."
     );
+
+    // Stake amounts per era are tracked starting from the previous staked era
+    assert_eq!(staking_info.first_tracked_era(), era_2);
+    assert!(staking_info.staked_amount_in_era(era_2 - 1).is_zero());
+    assert_eq!(
+        staking_info.staked_amount_in_era(era_2),
+        vote_stake_amount_1
+    );
+    assert_eq!(
+        staking_info.staked_amount_in_era(era_2 + 1),
+        vote_stake_amount_1 + bep_stake_amount_1
+    );
 }
 
 #[test]
@@ -2729,6 +2741,38 @@ fn contract_stake_amount_advanced_unstake_is_ok() {
     assert!(contract_stake.staked.build_and_earn.is_zero());
 }
 
+#[test]
+fn staker_count_works() {
+    let mut staker_count = StakerCount::default();
+    let period = 3;
+    assert!(staker_count.count(period).is_zero());
+
+    // Increase & decrease in the same period
+    staker_count.increase(period);
+    staker_count.increase(period);
+    assert_eq!(staker_count.count(period), 2);
+    staker_count.decrease(period);
+    assert_eq!(staker_count.count(period), 1);
+
+    // Count from a past period is treated as zero
+    let next_period = period + 1;
+    assert!(staker_count.count(next_period).is_zero());
+
+    // Decrease for a different period has no effect
+    staker_count.decrease(next_period);
+    assert_eq!(staker_count.count(period), 1);
+
+    // Increase for the next period resets the count
+    staker_count.increase(next_period);
+    assert_eq!(staker_count.count(next_period), 1);
+    assert!(staker_count.count(period).is_zero());
+
+    // Decrease doesn't underflow
+    staker_count.decrease(next_period);
+    staker_count.decrease(next_period);
+    assert!(staker_count.count(next_period).is_zero());
+}
+
 #[test]
 fn era_reward_span_push_and_get_works() {
     get_u32_type!(SpanLength, 8);
@@ -2839,6 +2883,7 @@ fn tier_params_check_is_ok() {
             },
         ])
         .unwrap(),
+        min_stakers: BoundedVec::default(),
     };
     assert!(params.is_valid());
 
@@ -2885,6 +2930,18 @@ fn tier_params_check_is_ok() {
     }])
     .unwrap();
     assert!(!new_params.is_valid());
+
+    // 5th scenario - minimum number of stakers specified for each tier, and that is ok
+    let mut new_params = params.clone();
+    new_params.min_stakers = BoundedVec::try_from(vec![10, 5, 0]).unwrap();
+    assert!(new_params.is_valid());
+    assert_eq!(new_params.min_stakers(0), 10);
+    assert_eq!(new_params.min_stakers(2), 0);
+
+    // 6th scenario - minimum number of stakers has incorrect vector length
+    let mut new_params = params.clone();
+    new_params.min_stakers = BoundedVec::try_from(vec![10, 5]).unwrap();
+    assert!(!new_params.is_valid());
 }
 
 #[test]
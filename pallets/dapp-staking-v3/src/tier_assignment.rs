@@ -0,0 +1,140 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # dApp Staking Tier Assignment
+//!
+//! Contains the `TierAssignment` trait, used to plug in the algorithm which assigns dApps into tiers,
+//! and `StandardTierAssignment`, the default algorithm.
+
+use frame_support::pallet_prelude::*;
+use sp_runtime::{traits::Zero, Saturating};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+use astar_primitives::{
+    dapp_staking::{DAppId, RankedTier},
+    Balance,
+};
+
+use crate::{pallet::Config, StakeAmount, StaticTierParams, TiersConfigurationFor};
+
+/// Algorithm used to assign dApps into tiers, based on their stake.
+pub trait TierAssignment<T: Config> {
+    /// Assign dApps into tiers, using the provided tier configuration.
+    ///
+    /// `dapp_stakes` contains the stake of each dApp with a non-zero stake in the era for which tiers are assigned,
+    /// alongside its number of unique stakers, in no particular order.
+    ///
+    /// The returned assignment must respect the tier configuration. dApps assigned to a non-existing tier,
+    /// or over the capacity of a tier, will be ignored.
+    fn assign_tiers(
+        dapp_stakes: Vec<(DAppId, StakeAmount, u32)>,
+        tier_config: &TiersConfigurationFor<T>,
+    ) -> BTreeMap<DAppId, RankedTier>;
+}
+
+/// Default tier assignment algorithm.
+///
+/// 1. Sort the dApps by their total stake, in descending order - the top staked dApp comes first.
+///
+/// 2. Iterate over tier thresholds & capacities, starting from the top tier, and assign dApps to them.
+///
+///    ```text
+///    for each tier:
+///        for each unassigned dApp:
+///            if tier has capacity && dApp satisfies the tier threshold:
+///                if dApp satisfies the tier minimum number of stakers:
+///                    add dapp to the tier
+///            else:
+///               exit loop since no more dApps will satisfy the threshold since they are sorted by stake
+///    ```
+///
+///    dApps which don't satisfy the minimum number of stakers for a tier remain eligible for the lower tiers.
+///
+/// In case ranking is enabled, rank of each dApp is determined by where its stake falls
+/// between the tier threshold and the threshold of the tier above it.
+pub struct StandardTierAssignment;
+impl<T: Config> TierAssignment<T> for StandardTierAssignment {
+    fn assign_tiers(
+        dapp_stakes: Vec<(DAppId, StakeAmount, u32)>,
+        tier_config: &TiersConfigurationFor<T>,
+    ) -> BTreeMap<DAppId, RankedTier> {
+        // 1.
+        // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
+        let mut dapp_stakes: Vec<_> = dapp_stakes
+            .into_iter()
+            .map(|(dapp_id, stake_amount, number_of_stakers)| {
+                (dapp_id, stake_amount.total(), number_of_stakers)
+            })
+            .collect();
+        dapp_stakes.sort_unstable_by(|(_, amount_1, _), (_, amount_2, _)| amount_2.cmp(amount_1));
+
+        let tier_params = StaticTierParams::<T>::get();
+
+        // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
+        // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
+        //
+        // In the current implementation, the dApp with the lower dApp Id has the advantage.
+        // There is no guarantee this will persist in the future, so it's best for dApps to do their
+        // best to avoid getting themselves into such situations.
+
+        // 2.
+        // Iterate over configured tier and potential dApps.
+        // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
+        // and tier capacity hasn't been filled yet.
+        let mut dapp_tiers = BTreeMap::new();
+        let mut upper_bound = Balance::zero();
+
+        for (tier_id, (tier_capacity, lower_bound)) in tier_config
+            .slots_per_tier
+            .iter()
+            .zip(tier_config.tier_thresholds.iter())
+            .enumerate()
+        {
+            let min_stakers = tier_params.min_stakers(tier_id);
+            let mut tier_slots = 0_u16;
+
+            // Iterate over unassigned dApps until one of two conditions has been met:
+            // 1. Tier has no more capacity
+            // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
+            for (dapp_id, staked_amount, number_of_stakers) in dapp_stakes
+                .iter()
+                .filter(|(dapp_id, _, _)| !dapp_tiers.contains_key(dapp_id))
+                .take_while(|(_, amount, _)| amount.ge(lower_bound))
+            {
+                if tier_slots >= *tier_capacity {
+                    break;
+                }
+                if *number_of_stakers < min_stakers {
+                    continue;
+                }
+
+                let rank = if T::RankingEnabled::get() {
+                    RankedTier::find_rank(*lower_bound, upper_bound, *staked_amount)
+                } else {
+                    0
+                };
+                dapp_tiers.insert(*dapp_id, RankedTier::new_saturated(tier_id as u8, rank));
+                tier_slots.saturating_inc();
+            }
+
+            upper_bound = *lower_bound; // current threshold becomes upper bound for next tier
+        }
+
+        dapp_tiers
+    }
+}
@@ -41,6 +41,7 @@
 //! * `DAppId` - a compact unique numeric Id of a dApp.
 //! * `DAppInfo` - contains general information about a dApp, like owner and reward beneficiary, Id and state.
 //! * `ContractStakeAmount` - contains information about how much is staked on a particular contract.
+//! * `StakerCount` - contains the number of unique stakers on a particular contract.
 //!
 //! ## Staker Information
 //!
@@ -48,6 +49,7 @@
 //! * `StakeAmount` - contains information about the staked amount in a particular era, and period.
 //! * `AccountLedger` - keeps track of total locked & staked balance, unlocking chunks and number of stake entries.
 //! * `SingularStakingInfo` - contains information about a particular staker's stake on a specific smart contract. Used to track loyalty.
+//! * `StakerPreferences` - contains the reward claiming preferences of a staker, like auto-restaking & claim bounty.
 //!
 //! ## Era Information
 //!
@@ -62,6 +64,9 @@
 //! * `TiersConfiguration` - contains dynamic information about tiers, derived from `TierParameters` and onchain data.
 //! * `DAppTier` - a compact struct describing a dApp's tier.
 //! * `DAppTierRewards` - composite of `DAppTier` objects, describing the entire reward distribution for a particular era.
+//! * `DAppPeriodSummary` - summary of tiers held, rewards earned and peak stake of a dApp during a particular period.
+//! * `DAppCategory` - category of a dApp, as declared by its owner.
+//! * `DAppMetadata` - deposit-backed descriptive metadata of a dApp (name, website, IPFS CID & category).
 //!
 
 use frame_support::{pallet_prelude::*, BoundedBTreeMap, BoundedVec};
@@ -75,7 +80,9 @@ use sp_runtime::{
 pub use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec};
 
 use astar_primitives::{
-    dapp_staking::{DAppId, EraNumber, PeriodNumber, RankedTier, TierSlots as TierSlotsFunc},
+    dapp_staking::{
+        DAppId, EraNumber, PeriodNumber, RankedTier, TierId, TierSlots as TierSlotsFunc,
+    },
     Balance, BlockNumber,
 };
 
@@ -94,6 +101,20 @@ pub type EraRewardSpanFor<T> = EraRewardSpan<<T as Config>::EraRewardSpanLength>
 // Convenience type for `DAppInfo` usage.
 pub type DAppInfoFor<T> = DAppInfo<<T as frame_system::Config>::AccountId>;
 
+// Convenience type for `DAppMetadata` usage.
+pub type DAppMetadataFor<T> = DAppMetadata<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::MaxMetadataFieldLength,
+    <T as Config>::MaxIpfsCidLength,
+>;
+
+// Convenience type for `TiersConfiguration` usage.
+pub type TiersConfigurationFor<T> = TiersConfiguration<
+    <T as Config>::NumberOfTiers,
+    <T as Config>::TierSlots,
+    <T as Config>::BaseNativeCurrencyPrice,
+>;
+
 /// Simple enum representing errors possible when using sparse bounded vector.
 #[derive(Debug, PartialEq, Eq)]
 pub enum AccountLedgerError {
@@ -114,7 +135,19 @@ pub enum AccountLedgerError {
 }
 
 /// Distinct subperiods in dApp staking protocol.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub enum Subperiod {
     /// Subperiod during which the focus is on voting. No rewards are earned during this subperiod.
     Voting,
@@ -133,7 +166,19 @@ impl Subperiod {
 }
 
 /// Info about the ongoing period.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub struct PeriodInfo {
     /// Period number.
     #[codec(compact)]
@@ -177,7 +222,19 @@ pub enum ForcingType {
 }
 
 /// General information & state of the dApp staking protocol.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub struct ProtocolState {
     /// Ongoing era number.
     #[codec(compact)]
@@ -270,8 +327,100 @@ impl<AccountId> DAppInfo<AccountId> {
     }
 }
 
+/// Category of a dApp, as declared by its owner.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Default,
+    Serialize,
+    Deserialize,
+)]
+pub enum DAppCategory {
+    /// Decentralized finance.
+    Defi,
+    /// NFTs & digital collectibles.
+    Nft,
+    /// Games & game related infrastructure.
+    Gaming,
+    /// Tooling, oracles, bridges & other infrastructure.
+    Infrastructure,
+    /// Social networks & messaging.
+    Social,
+    /// Any other category.
+    #[default]
+    Other,
+}
+
+/// Descriptive metadata about a dApp, set by its owner & backed by a deposit.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(FieldLen, CidLen))]
+pub struct DAppMetadata<
+    AccountId: Debug + PartialEq + Eq + Clone,
+    FieldLen: Get<u32>,
+    CidLen: Get<u32>,
+> {
+    /// Name of the dApp.
+    pub name: BoundedVec<u8, FieldLen>,
+    /// Website of the dApp.
+    pub website: BoundedVec<u8, FieldLen>,
+    /// IPFS CID of additional dApp information, e.g. logo & description.
+    pub ipfs_cid: BoundedVec<u8, CidLen>,
+    /// Category of the dApp.
+    pub category: DAppCategory,
+    /// Account which has reserved the deposit.
+    pub depositor: AccountId,
+    /// Deposit reserved for storing the metadata.
+    #[codec(compact)]
+    pub deposit: Balance,
+}
+
+impl<AccountId, FieldLen, CidLen> DAppMetadata<AccountId, FieldLen, CidLen>
+where
+    AccountId: Debug + PartialEq + Eq + Clone,
+    FieldLen: Get<u32>,
+    CidLen: Get<u32>,
+{
+    /// Total number of bytes used by the variable length metadata fields.
+    pub fn encoded_fields_len(&self) -> u32 {
+        self.name
+            .len()
+            .saturating_add(self.website.len())
+            .saturating_add(self.ipfs_cid.len())
+            .unique_saturated_into()
+    }
+}
+
 /// How much was unlocked in some block.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Default, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Default,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub struct UnlockingChunk {
     /// Amount undergoing the unlocking period.
     #[codec(compact)]
@@ -815,7 +964,20 @@ impl Iterator for EraStakePairIter {
 }
 
 /// Describes stake amount in an particular era/period.
-#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Default,
+    Serialize,
+    Deserialize,
+)]
 pub struct StakeAmount {
     /// Amount of staked funds accounting for the voting subperiod.
     #[codec(compact)]
@@ -923,6 +1085,13 @@ impl EraInfo {
         self.next_stake_amount.subtract(amount);
     }
 
+    /// Subtract the specified `amount` from the current era stake amount only.
+    ///
+    /// Used when stake is moved between contracts, since the next era stake amount doesn't change.
+    pub fn unstake_current_era_amount(&mut self, amount: Balance) {
+        self.current_stake_amount.subtract(amount);
+    }
+
     /// Total staked amount in this era.
     pub fn total_staked_amount(&self) -> Balance {
         self.current_stake_amount.total()
@@ -969,7 +1138,20 @@ impl EraInfo {
 /// Information about how much a particular staker staked on a particular smart contract.
 ///
 /// Keeps track of amount staked in the 'voting subperiod', as well as 'build&earn subperiod'.
-#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Default,
+    Serialize,
+    Deserialize,
+)]
 pub struct SingularStakingInfo {
     /// Amount staked before, if anything.
     pub(crate) previous_staked: StakeAmount,
@@ -1013,6 +1195,21 @@ impl SingularStakingInfo {
 
     /// Stake the specified amount on the contract, for the specified subperiod.
     pub fn stake(&mut self, amount: Balance, current_era: EraNumber, subperiod: Subperiod) {
+        match subperiod {
+            Subperiod::Voting => self.stake_split(amount, Balance::zero(), current_era),
+            Subperiod::BuildAndEarn => self.stake_split(Balance::zero(), amount, current_era),
+        }
+    }
+
+    /// Stake the specified `voting` and `build_and_earn` amounts on the contract.
+    ///
+    /// Used when stake is moved over from another contract, so its subperiod split can be preserved.
+    pub fn stake_split(
+        &mut self,
+        voting: Balance,
+        build_and_earn: Balance,
+        current_era: EraNumber,
+    ) {
         // Keep the previous stake amount for future reference
         self.previous_staked = self.staked;
         self.previous_staked.era = current_era;
@@ -1021,7 +1218,8 @@ impl SingularStakingInfo {
         }
 
         // Stake is only valid from the next era so we keep it consistent here
-        self.staked.add(amount, subperiod);
+        self.staked.add(voting, Subperiod::Voting);
+        self.staked.add(build_and_earn, Subperiod::BuildAndEarn);
         self.staked.era = current_era.saturating_add(1);
     }
 
@@ -1148,6 +1346,30 @@ impl SingularStakingInfo {
         self.staked.era
     }
 
+    /// First era for which the entry still holds the staked amount.
+    ///
+    /// Stake amounts for any older eras are no longer tracked by the entry.
+    pub fn first_tracked_era(&self) -> EraNumber {
+        if self.previous_staked.is_empty() {
+            self.staked.era
+        } else {
+            self.previous_staked.era
+        }
+    }
+
+    /// Total amount staked on the contract in the specified era.
+    ///
+    /// Only eras starting from `first_tracked_era` are covered, zero is returned for any older era.
+    pub fn staked_amount_in_era(&self, era: EraNumber) -> Balance {
+        if era >= self.staked.era {
+            self.staked.total()
+        } else if !self.previous_staked.is_empty() && era >= self.previous_staked.era {
+            self.previous_staked.total()
+        } else {
+            Balance::zero()
+        }
+    }
+
     /// `true` if no stake exists, `false` otherwise.
     pub fn is_empty(&self) -> bool {
         self.staked.is_empty()
@@ -1162,7 +1384,19 @@ impl SingularStakingInfo {
 /// **NOTE:** The 'future' entry term is only valid in the era when `stake` is called. It's possible contract stake isn't changed in consecutive eras,
 /// so we might end up in a situation where era is `N + 10` but `staked` entry refers to era `N` and `staked_future` entry refers to era `N+1`.
 /// This is still valid since these values are expected to be updated lazily.
-#[derive(Encode, Decode, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, Clone, TypeInfo, Default)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebug,
+    PartialEq,
+    Eq,
+    Clone,
+    TypeInfo,
+    Default,
+    Serialize,
+    Deserialize,
+)]
 pub struct ContractStakeAmount {
     /// Staked amount in the 'current' era.
     pub staked: StakeAmount,
@@ -1335,6 +1569,64 @@ impl ContractStakeAmount {
     }
 }
 
+/// Number of unique stakers on a contract, in a particular period.
+///
+/// Count from a past period is treated as zero, so it doesn't need to be reset when a new period starts.
+#[derive(
+    Encode, Decode, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, Clone, Copy, TypeInfo, Default,
+)]
+pub struct StakerCount {
+    /// Period to which the count applies.
+    #[codec(compact)]
+    pub period: PeriodNumber,
+    /// Number of unique stakers.
+    #[codec(compact)]
+    pub count: u32,
+}
+
+impl StakerCount {
+    /// Number of unique stakers in the specified period.
+    pub fn count(&self, period: PeriodNumber) -> u32 {
+        if self.period == period {
+            self.count
+        } else {
+            0
+        }
+    }
+
+    /// Increase the number of unique stakers in the specified period.
+    /// In case count refers to a past period, it's reset first.
+    pub fn increase(&mut self, period: PeriodNumber) {
+        if self.period != period {
+            self.period = period;
+            self.count = 0;
+        }
+        self.count.saturating_inc();
+    }
+
+    /// Decrease the number of unique stakers in the specified period.
+    /// In case count refers to a past period, nothing is changed.
+    pub fn decrease(&mut self, period: PeriodNumber) {
+        if self.period == period {
+            self.count.saturating_dec();
+        }
+    }
+}
+
+/// Reward claiming preferences of a staker.
+///
+/// Kept separately from the `AccountLedger`, so new preferences can be introduced without translating all the ledgers.
+#[derive(
+    Encode, Decode, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, Clone, Copy, TypeInfo, Default,
+)]
+pub struct StakerPreferences {
+    /// Whether claimed staker rewards should automatically be locked & re-staked.
+    pub auto_restake: bool,
+    /// Share of the claimed reward paid out as a bounty to the account claiming it on behalf of the staker.
+    /// Zero means staker hasn't opted into paying the claim bounty.
+    pub claim_bounty: Perbill,
+}
+
 /// Information required for staker reward payout for a particular era.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
 pub struct EraReward {
@@ -1517,6 +1809,10 @@ pub struct TierParameters<NT: Get<u32>> {
     /// Requirements for entry into each tier.
     /// First entry refers to the first tier, and so on.
     pub tier_thresholds: BoundedVec<TierThreshold, NT>,
+    /// Minimum number of unique stakers a dApp must have in order to enter each tier.
+    /// First entry refers to the first tier, and so on.
+    /// Optional - in case it's empty, there is no such requirement.
+    pub min_stakers: BoundedVec<u32, NT>,
 }
 
 impl<NT: Get<u32>> TierParameters<NT> {
@@ -1553,6 +1849,12 @@ impl<NT: Get<u32>> TierParameters<NT> {
         number_of_tiers == self.reward_portion.len()
             && number_of_tiers == self.slot_distribution.len()
             && number_of_tiers == self.tier_thresholds.len()
+            && (self.min_stakers.is_empty() || number_of_tiers == self.min_stakers.len())
+    }
+
+    /// Minimum number of unique stakers required to enter the specified tier.
+    pub fn min_stakers(&self, tier_id: usize) -> u32 {
+        self.min_stakers.get(tier_id).copied().unwrap_or_default()
     }
 }
 
@@ -1562,6 +1864,7 @@ impl<NT: Get<u32>> Default for TierParameters<NT> {
             reward_portion: BoundedVec::default(),
             slot_distribution: BoundedVec::default(),
             tier_thresholds: BoundedVec::default(),
+            min_stakers: BoundedVec::default(),
         }
     }
 }
@@ -1788,8 +2091,13 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
             .remove(&dapp_id)
             .ok_or(DAppTierError::NoDAppInTiers)?;
 
+        Ok((self.reward_for(ranked_tier), ranked_tier))
+    }
+
+    /// Reward amount for a dApp in the specified tier & rank.
+    pub fn reward_for(&self, ranked_tier: RankedTier) -> Balance {
         let (tier_id, rank) = ranked_tier.deconstruct();
-        let mut amount = self
+        let amount = self
             .rewards
             .get(tier_id as usize)
             .map_or(Balance::zero(), |x| *x);
@@ -1800,9 +2108,7 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
             .map_or(Balance::zero(), |x| *x);
 
         let additional_reward = reward_per_rank.saturating_mul(rank.into());
-        amount = amount.saturating_add(additional_reward);
-
-        Ok((amount, ranked_tier))
+        amount.saturating_add(additional_reward)
     }
 }
 
@@ -1814,6 +2120,56 @@ pub enum DAppTierError {
     InternalError,
 }
 
+/// Summary of dApp performance during a single period.
+///
+/// Only eras in which the dApp was assigned to a tier are taken into account.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(NT))]
+pub struct DAppPeriodSummary<NT: Get<u32>> {
+    /// Period to which the summary refers.
+    #[codec(compact)]
+    pub period: PeriodNumber,
+    /// Number of eras the dApp spent in each tier. First entry refers to the first tier, and so on.
+    pub eras_per_tier: BoundedVec<EraNumber, NT>,
+    /// Total dApp reward earned during the period, regardless of whether it has been claimed or not.
+    #[codec(compact)]
+    pub reward: Balance,
+    /// Highest total amount staked on the dApp in any of the eras.
+    #[codec(compact)]
+    pub peak_stake: Balance,
+}
+
+impl<NT: Get<u32>> DAppPeriodSummary<NT> {
+    /// Create new empty summary for the specified period.
+    pub fn new(period: PeriodNumber) -> Self {
+        use sp_std::vec;
+        Self {
+            period,
+            eras_per_tier: BoundedVec::truncate_from(vec![0; NT::get() as usize]),
+            reward: Balance::zero(),
+            peak_stake: Balance::zero(),
+        }
+    }
+
+    /// Record an era in which the dApp was assigned to the specified tier.
+    pub fn record_era(&mut self, tier_id: TierId, reward: Balance, stake: Balance) {
+        if let Some(eras) = self.eras_per_tier.get_mut(tier_id as usize) {
+            eras.saturating_inc();
+        }
+        self.reward.saturating_accrue(reward);
+        self.peak_stake = self.peak_stake.max(stake);
+    }
+}
+
 /// Describes which entries are next in line for cleanup.
 #[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
 pub struct CleanupMarker {
@@ -73,6 +73,19 @@ pub trait WeightInfo {
 	fn on_initialize_build_and_earn_to_build_and_earn() -> Weight;
 	fn dapp_tier_assignment(x: u32, ) -> Weight;
 	fn on_idle_cleanup() -> Weight;
+	fn set_auto_restake() -> Weight;
+	fn restake(x: u32, ) -> Weight;
+	fn set_claim_bounty() -> Weight;
+	fn move_stake() -> Weight;
+	fn queue_tier_params() -> Weight;
+	fn stake_many(x: u32, ) -> Weight;
+	fn unstake_many(x: u32, ) -> Weight;
+	fn set_dapp_reward_share() -> Weight;
+	fn claim_shared_dapp_rewards() -> Weight;
+	fn set_dapp_metadata() -> Weight;
+	fn clear_dapp_metadata() -> Weight;
+	fn add_contract_alias() -> Weight;
+	fn remove_contract_alias() -> Weight;
 }
 
 /// Weights for pallet_dapp_staking_v3 using the Substrate node and recommended hardware.
@@ -128,14 +141,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DappStaking CounterForIntegratedDApps (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:0 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:0 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn unregister() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `74`
-		//  Estimated: `3086`
-		// Minimum execution time: 18_458_000 picoseconds.
 		Weight::from_parts(18_864_000, 3086)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
@@ -241,14 +252,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn stake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `251`
-		//  Estimated: `4764`
-		// Minimum execution time: 44_905_000 picoseconds.
 		Weight::from_parts(45_261_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
 	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
@@ -264,14 +273,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn unstake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `432`
-		//  Estimated: `4764`
-		// Minimum execution time: 48_594_000 picoseconds.
 		Weight::from_parts(49_441_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
@@ -449,18 +456,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
 	/// Storage: DappStaking TierConfig (r:1 w:0)
 	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:100 w:0)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppPeriodArchive (r:100 w:100)
+	/// Proof: DappStaking DAppPeriodArchive (max_values: None, max_size: Some(907), added: 3382, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppRewardShares (r:101 w:0)
+	/// Proof: DappStaking DAppRewardShares (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:100 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppSharedRewards (r:100 w:100)
+	/// Proof: DappStaking DAppSharedRewards (max_values: None, max_size: Some(833), added: 3308, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppLastSharedEra (r:0 w:100)
+	/// Proof: DappStaking DAppLastSharedEra (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// The range of component `x` is `[0, 100]`.
 	fn dapp_tier_assignment(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `152 + x * (32 ±0)`
-		//  Estimated: `3061 + x * (2071 ±0)`
-		// Minimum execution time: 7_549_000 picoseconds.
 		Weight::from_parts(12_476_634, 3061)
-			// Standard Error: 3_594
 			.saturating_add(Weight::from_parts(2_387_577, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 15421).saturating_mul(x.into()))
 	}
 	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:1)
 	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
@@ -477,6 +492,195 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: DappStaking Ledger (r:1 w:0)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerPreferencesOf (r:1 w:1)
+	/// Proof: DappStaking StakerPreferencesOf (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn set_auto_restake() -> Weight {
+		Weight::from_parts(12_778_000, 3775)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking StakerInfo (r:x w:x)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:x w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:x w:x)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn restake(x: u32, ) -> Weight {
+		Weight::from_parts(38_835_000, 4764)
+			.saturating_add(Weight::from_parts(45_261_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking Ledger (r:1 w:0)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerPreferencesOf (r:1 w:1)
+	/// Proof: DappStaking StakerPreferencesOf (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn set_claim_bounty() -> Weight {
+		Weight::from_parts(12_778_000, 3775)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:2 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:2 w:2)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:2 w:2)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:2 w:2)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn move_stake() -> Weight {
+		Weight::from_parts(62_105_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: DappStaking QueuedTierParams (r:0 w:1)
+	/// Proof: DappStaking QueuedTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
+	fn queue_tier_params() -> Weight {
+		Weight::from_parts(9_370_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:x w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:x w:x)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:x w:x)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:x w:x)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn stake_many(x: u32, ) -> Weight {
+		Weight::from_parts(33_906_521, 3774)
+			.saturating_add(Weight::from_parts(13_384_775, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:x w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:x w:x)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:x w:x)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:x w:x)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn unstake_many(x: u32, ) -> Weight {
+		Weight::from_parts(36_201_870, 3774)
+			.saturating_add(Weight::from_parts(14_902_313, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppRewardShares (r:0 w:1)
+	/// Proof: DappStaking DAppRewardShares (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn set_dapp_reward_share() -> Weight {
+		Weight::from_parts(13_201_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking StakerInfo (r:1 w:0)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking SharedRewardClaimMarkers (r:1 w:1)
+	/// Proof: DappStaking SharedRewardClaimMarkers (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:0)
+	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: DappStaking PeriodEnd (r:1 w:0)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppSharedRewards (r:1 w:0)
+	/// Proof: DappStaking DAppSharedRewards (max_values: None, max_size: Some(833), added: 3308, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_shared_dapp_rewards() -> Weight {
+		Weight::from_parts(43_588_000, 4298)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(103), added: 2578, mode: MaxEncodedLen)
+	fn set_dapp_metadata() -> Weight {
+		Weight::from_parts(39_402_000, 3890)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(103), added: 2578, mode: MaxEncodedLen)
+	fn clear_dapp_metadata() -> Weight {
+		Weight::from_parts(36_905_000, 3890)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:2 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractAliases (r:1 w:1)
+	/// Proof: DappStaking ContractAliases (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppContractAliases (r:1 w:1)
+	/// Proof: DappStaking DAppContractAliases (max_values: None, max_size: Some(275), added: 2750, mode: MaxEncodedLen)
+	fn add_contract_alias() -> Weight {
+		Weight::from_parts(24_787_000, 6182)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppContractAliases (r:1 w:1)
+	/// Proof: DappStaking DAppContractAliases (max_values: None, max_size: Some(275), added: 2750, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractAliases (r:0 w:1)
+	/// Proof: DappStaking ContractAliases (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	fn remove_contract_alias() -> Weight {
+		Weight::from_parts(21_961_000, 3740)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -531,14 +735,12 @@ impl WeightInfo for () {
 	/// Proof: DappStaking CounterForIntegratedDApps (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:0 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:0 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn unregister() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `74`
-		//  Estimated: `3086`
-		// Minimum execution time: 18_458_000 picoseconds.
 		Weight::from_parts(18_864_000, 3086)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
@@ -644,14 +846,12 @@ impl WeightInfo for () {
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn stake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `251`
-		//  Estimated: `4764`
-		// Minimum execution time: 44_905_000 picoseconds.
 		Weight::from_parts(45_261_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
 	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
@@ -667,14 +867,12 @@ impl WeightInfo for () {
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:1 w:1)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
 	fn unstake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `432`
-		//  Estimated: `4764`
-		// Minimum execution time: 48_594_000 picoseconds.
 		Weight::from_parts(49_441_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
@@ -852,18 +1050,26 @@ impl WeightInfo for () {
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
 	/// Storage: DappStaking TierConfig (r:1 w:0)
 	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:100 w:0)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppPeriodArchive (r:100 w:100)
+	/// Proof: DappStaking DAppPeriodArchive (max_values: None, max_size: Some(907), added: 3382, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppRewardShares (r:101 w:0)
+	/// Proof: DappStaking DAppRewardShares (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:100 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppSharedRewards (r:100 w:100)
+	/// Proof: DappStaking DAppSharedRewards (max_values: None, max_size: Some(833), added: 3308, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppLastSharedEra (r:0 w:100)
+	/// Proof: DappStaking DAppLastSharedEra (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// The range of component `x` is `[0, 100]`.
 	fn dapp_tier_assignment(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `152 + x * (32 ±0)`
-		//  Estimated: `3061 + x * (2071 ±0)`
-		// Minimum execution time: 7_549_000 picoseconds.
 		Weight::from_parts(12_476_634, 3061)
-			// Standard Error: 3_594
 			.saturating_add(Weight::from_parts(2_387_577, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 15421).saturating_mul(x.into()))
 	}
 	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:1)
 	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
@@ -880,4 +1086,193 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: DappStaking Ledger (r:1 w:0)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerPreferencesOf (r:1 w:1)
+	/// Proof: DappStaking StakerPreferencesOf (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn set_auto_restake() -> Weight {
+		Weight::from_parts(12_778_000, 3775)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking StakerInfo (r:x w:x)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:x w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:x w:x)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn restake(x: u32, ) -> Weight {
+		Weight::from_parts(38_835_000, 4764)
+			.saturating_add(Weight::from_parts(45_261_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking Ledger (r:1 w:0)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerPreferencesOf (r:1 w:1)
+	/// Proof: DappStaking StakerPreferencesOf (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn set_claim_bounty() -> Weight {
+		Weight::from_parts(12_778_000, 3775)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:2 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:2 w:2)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:2 w:2)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:2 w:2)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn move_stake() -> Weight {
+		Weight::from_parts(62_105_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: DappStaking QueuedTierParams (r:0 w:1)
+	/// Proof: DappStaking QueuedTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
+	fn queue_tier_params() -> Weight {
+		Weight::from_parts(9_370_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:x w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:x w:x)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:x w:x)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:x w:x)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn stake_many(x: u32, ) -> Weight {
+		Weight::from_parts(33_906_521, 3774)
+			.saturating_add(Weight::from_parts(13_384_775, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:x w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:x w:x)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:x w:x)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStakers (r:x w:x)
+	/// Proof: DappStaking ContractStakers (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	fn unstake_many(x: u32, ) -> Weight {
+		Weight::from_parts(36_201_870, 3774)
+			.saturating_add(Weight::from_parts(14_902_313, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppRewardShares (r:0 w:1)
+	/// Proof: DappStaking DAppRewardShares (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn set_dapp_reward_share() -> Weight {
+		Weight::from_parts(13_201_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking StakerInfo (r:1 w:0)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking SharedRewardClaimMarkers (r:1 w:1)
+	/// Proof: DappStaking SharedRewardClaimMarkers (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:0)
+	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: DappStaking PeriodEnd (r:1 w:0)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppSharedRewards (r:1 w:0)
+	/// Proof: DappStaking DAppSharedRewards (max_values: None, max_size: Some(833), added: 3308, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_shared_dapp_rewards() -> Weight {
+		Weight::from_parts(43_588_000, 4298)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(103), added: 2578, mode: MaxEncodedLen)
+	fn set_dapp_metadata() -> Weight {
+		Weight::from_parts(39_402_000, 3890)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppMetadataOf (r:1 w:1)
+	/// Proof: DappStaking DAppMetadataOf (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(103), added: 2578, mode: MaxEncodedLen)
+	fn clear_dapp_metadata() -> Weight {
+		Weight::from_parts(36_905_000, 3890)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:2 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractAliases (r:1 w:1)
+	/// Proof: DappStaking ContractAliases (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppContractAliases (r:1 w:1)
+	/// Proof: DappStaking DAppContractAliases (max_values: None, max_size: Some(275), added: 2750, mode: MaxEncodedLen)
+	fn add_contract_alias() -> Weight {
+		Weight::from_parts(24_787_000, 6182)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking DAppContractAliases (r:1 w:1)
+	/// Proof: DappStaking DAppContractAliases (max_values: None, max_size: Some(275), added: 2750, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractAliases (r:0 w:1)
+	/// Proof: DappStaking ContractAliases (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	fn remove_contract_alias() -> Weight {
+		Weight::from_parts(21_961_000, 3740)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
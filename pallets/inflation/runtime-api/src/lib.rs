@@ -0,0 +1,48 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::Balance;
+use sp_runtime::Perquintill;
+
+pub use pallet_inflation::{CycleIssuance, InflationConfiguration, InflationParameters};
+
+sp_api::decl_runtime_apis! {
+
+    /// Inflation Api.
+    ///
+    /// Used to provide information about the current & the upcoming inflation.
+    pub trait InflationApi {
+
+        /// Get the active inflation configuration.
+        fn inflation_configuration() -> InflationConfiguration;
+
+        /// Get the inflation parameters, used for the next inflation recalculation.
+        fn inflation_parameters() -> InflationParameters;
+
+        /// Get the projected inflation configuration for the next cycle, based on the current total issuance.
+        fn projected_inflation_configuration() -> InflationConfiguration;
+
+        /// Get the effective yearly staker APR for the specified total value staked, excluding the bonus rewards.
+        fn staker_apr(total_value_staked: Balance) -> Perquintill;
+
+        /// Get the issuance minted & burned so far during the ongoing cycle.
+        fn cycle_issuance() -> CycleIssuance;
+    }
+}
@@ -139,6 +139,63 @@ mod benchmarks {
         assert!(T::Currency::total_issuance() > init_issuance);
     }
 
+    #[benchmark]
+    fn schedule_inflation_params() -> Result<(), BenchmarkError> {
+        initial_config::<T>();
+
+        let origin =
+            T::ScheduleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Worst case is when all but one schedule slots are occupied, and the new entry is inserted at the front.
+        let recalculation_era = ActiveInflationConfig::<T>::get().recalculation_era;
+        let schedule: Vec<_> = (1..T::MaxScheduledParams::get())
+            .map(|idx| (recalculation_era + idx, InflationParameters::default()))
+            .collect();
+        ScheduledInflationParams::<T>::put(BoundedVec::try_from(schedule).unwrap());
+
+        let params = InflationParameters::default();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, recalculation_era, params);
+
+        assert_last_event::<T>(
+            Event::<T>::InflationParametersScheduled {
+                era: recalculation_era,
+                params,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_inflation_params() -> Result<(), BenchmarkError> {
+        initial_config::<T>();
+
+        let origin =
+            T::ScheduleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Worst case is when all schedule slots are occupied, and the first entry is removed.
+        let recalculation_era = ActiveInflationConfig::<T>::get().recalculation_era;
+        let schedule: Vec<_> = (0..T::MaxScheduledParams::get())
+            .map(|idx| (recalculation_era + idx, InflationParameters::default()))
+            .collect();
+        ScheduledInflationParams::<T>::put(BoundedVec::try_from(schedule).unwrap());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, recalculation_era);
+
+        assert_last_event::<T>(
+            Event::<T>::ScheduledInflationParametersCancelled {
+                era: recalculation_era,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
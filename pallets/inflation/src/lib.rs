@@ -67,6 +67,12 @@
 //!
 //! Some rewards are calculated to be paid out per block, while some are per era or per period.
 //!
+//! ### Scheduled Parameters
+//!
+//! Governance can schedule new inflation parameters to take effect at a future recalculation era.
+//! When recalculation happens, the latest parameters scheduled at or before that era are applied first,
+//! and then used to calculate the new inflation configuration. Applied entries are removed from the schedule.
+//!
 //! ## Rewards
 //!
 //! ### Collator & Treasury Rewards
@@ -87,6 +93,16 @@
 //! dApp rewards are paid out per dApp, _on-demand_. The reward is decided by the dApp staking protocol, or the tier system to be more precise.
 //! This pallet only provides the total reward pool for all dApps per era.
 //!
+//! ## Issuance Tracking
+//!
+//! All the rewards minted during a cycle are tracked, per purpose, together with the burned issuance (e.g. transaction fees).
+//! Burned amounts are reported via the `OnIssuanceBurned` trait, or by using `BurnAndRecord` (or `BurnImbalanceAndRecord`) to burn
+//! the credit directly. Runtime should use these wherever it would otherwise simply drop the credit, e.g. for dust removal or slashes.
+//! At the end of each cycle, the summary is deposited as an event and tracking starts anew.
+//!
+//! Optionally, the inflation can target the net issuance increase, in which case the amount burned during the ending cycle
+//! is added to the maximum emission of the next one.
+//!
 //! # Interface
 //!
 //! ## StakingRewardHandler
@@ -100,7 +116,8 @@ pub use pallet::*;
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, Observer as DappStakingObserver, StakingRewardHandler,
+        CycleConfiguration, EraNumber, Observer as DappStakingObserver, OnIssuanceBurned,
+        RewardKind, StakingRewardHandler,
     },
     Balance,
 };
@@ -109,13 +126,14 @@ use frame_support::{
     traits::{
         fungible::{Balanced, Credit, Inspect},
         tokens::Precision,
+        Imbalance, OnUnbalanced,
     },
     DefaultNoBound,
 };
 use frame_system::{ensure_root, pallet_prelude::*};
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-    traits::{CheckedAdd, Zero},
+    traits::{CheckedAdd, Saturating, Zero},
     Perquintill,
 };
 use sp_std::marker::PhantomData;
@@ -143,8 +161,7 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     // Negative imbalance type of this pallet.
-    pub(crate) type CreditOf<T> =
-        Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+    pub type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -159,6 +176,20 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// Privileged origin allowed to schedule, replace & cancel future inflation parameters.
+        type ScheduleOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum number of inflation parameter entries which can be scheduled at once.
+        #[pallet::constant]
+        type MaxScheduledParams: Get<u32>;
+
+        /// If `true`, the maximum inflation rate targets the net inflation instead of the gross one.
+        ///
+        /// In that case, the amount burned during the ending cycle is added to the maximum emission of the next cycle,
+        /// but never more than the maximum emission itself.
+        #[pallet::constant]
+        type TargetNetInflation: Get<bool>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -174,12 +205,32 @@ pub mod pallet {
         ForcedInflationRecalculation { config: InflationConfiguration },
         /// New inflation configuration has been set.
         NewInflationConfiguration { config: InflationConfiguration },
+        /// Inflation parameters have been scheduled to be applied at the first recalculation at or after the specified era.
+        InflationParametersScheduled {
+            era: EraNumber,
+            params: InflationParameters,
+        },
+        /// Scheduled inflation parameters have been cancelled.
+        ScheduledInflationParametersCancelled { era: EraNumber },
+        /// Scheduled inflation parameters have been applied, and will be used for the recalculation.
+        ScheduledInflationParametersApplied {
+            era: EraNumber,
+            params: InflationParameters,
+        },
+        /// Cycle has ended, summary of the issuance minted & burned during the cycle.
+        CycleIssuanceFinalized { issuance: CycleIssuance },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Sum of all parts must be one whole (100%).
         InvalidInflationParameters,
+        /// Inflation parameters can only be scheduled for an era at or after the next recalculation era.
+        InvalidScheduleEra,
+        /// Maximum number of scheduled inflation parameter entries has been reached.
+        TooManyScheduledParams,
+        /// There are no inflation parameters scheduled for the specified era.
+        NoScheduledParams,
     }
 
     /// Active inflation configuration parameters.
@@ -197,6 +248,21 @@ pub mod pallet {
     #[pallet::whitelist_storage]
     pub type DoRecalculation<T: Config> = StorageValue<_, EraNumber, OptionQuery>;
 
+    /// Future inflation parameters, sorted by the era from which they apply, in ascending order.
+    ///
+    /// At each recalculation, the latest entry scheduled at or before the recalculation era replaces the inflation parameters.
+    #[pallet::storage]
+    pub type ScheduledInflationParams<T: Config> = StorageValue<
+        _,
+        BoundedVec<(EraNumber, InflationParameters), T::MaxScheduledParams>,
+        ValueQuery,
+    >;
+
+    /// Issuance minted & burned during the ongoing cycle.
+    #[pallet::storage]
+    #[pallet::whitelist_storage]
+    pub type CurrentCycleIssuance<T: Config> = StorageValue<_, CycleIssuance, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(DefaultNoBound)]
     pub struct GenesisConfig<T> {
@@ -227,7 +293,8 @@ pub mod pallet {
             //
             // ActiveInflationConfig - 1 DB read
             // DoRecalculation - 1 DB read
-            <T as frame_system::Config>::DbWeight::get().reads(2)
+            // CurrentCycleIssuance - 1 DB read, 1 DB write
+            <T as frame_system::Config>::DbWeight::get().reads_writes(3, 1)
         }
 
         fn on_finalize(_now: BlockNumberFor<T>) {
@@ -238,9 +305,11 @@ pub mod pallet {
             //
             // This should be done as late as possible, to ensure all operations that modify issuance are done.
             if let Some(next_era) = DoRecalculation::<T>::get() {
+                Self::apply_scheduled_params(next_era);
                 let config = Self::recalculate_inflation(next_era);
                 ActiveInflationConfig::<T>::put(config.clone());
                 DoRecalculation::<T>::kill();
+                Self::finalize_cycle_issuance();
 
                 Self::deposit_event(Event::<T>::NewInflationConfiguration { config });
             }
@@ -294,14 +363,81 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            Self::apply_scheduled_params(next_era);
             let config = Self::recalculate_inflation(next_era);
 
             ActiveInflationConfig::<T>::put(config.clone());
+            Self::finalize_cycle_issuance();
 
             Self::deposit_event(Event::<T>::ForcedInflationRecalculation { config });
 
             Ok(().into())
         }
+
+        /// Used to schedule inflation parameters, to be applied at the first recalculation at or after the specified era.
+        /// In case parameters are already scheduled for the specified era, they are replaced.
+        ///
+        /// The parameters must be valid, all parts summing up to one whole (100%), otherwise the call will fail.
+        /// The era must not be before the next recalculation era.
+        ///
+        /// Must be called by `ScheduleOrigin` origin.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::schedule_inflation_params())]
+        pub fn schedule_inflation_params(
+            origin: OriginFor<T>,
+            era: EraNumber,
+            params: InflationParameters,
+        ) -> DispatchResult {
+            T::ScheduleOrigin::ensure_origin(origin)?;
+
+            ensure!(params.is_valid(), Error::<T>::InvalidInflationParameters);
+            ensure!(
+                era >= ActiveInflationConfig::<T>::get().recalculation_era,
+                Error::<T>::InvalidScheduleEra
+            );
+
+            ScheduledInflationParams::<T>::try_mutate(|schedule| {
+                match schedule.binary_search_by_key(&era, |(entry_era, _)| *entry_era) {
+                    Ok(idx) => {
+                        if let Some(entry) = schedule.get_mut(idx) {
+                            *entry = (era, params);
+                        }
+                        Ok(())
+                    }
+                    Err(idx) => schedule
+                        .try_insert(idx, (era, params))
+                        .map_err(|_| Error::<T>::TooManyScheduledParams),
+                }
+            })?;
+
+            Self::deposit_event(Event::<T>::InflationParametersScheduled { era, params });
+
+            Ok(())
+        }
+
+        /// Used to cancel the inflation parameters scheduled for the specified era.
+        ///
+        /// Must be called by `ScheduleOrigin` origin.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_inflation_params())]
+        pub fn cancel_scheduled_inflation_params(
+            origin: OriginFor<T>,
+            era: EraNumber,
+        ) -> DispatchResult {
+            T::ScheduleOrigin::ensure_origin(origin)?;
+
+            ScheduledInflationParams::<T>::try_mutate(|schedule| {
+                let idx = schedule
+                    .binary_search_by_key(&era, |(entry_era, _)| *entry_era)
+                    .map_err(|_| Error::<T>::NoScheduledParams)?;
+                schedule.remove(idx);
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::ScheduledInflationParametersCancelled { era });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -317,6 +453,15 @@ pub mod pallet {
             T::PayoutPerBlock::collators(collator_amount);
             T::PayoutPerBlock::treasury(treasury_amount);
 
+            CurrentCycleIssuance::<T>::mutate(|issuance| {
+                issuance
+                    .collator_rewards
+                    .saturating_accrue(config.collator_reward_per_block);
+                issuance
+                    .treasury_rewards
+                    .saturating_accrue(config.treasury_reward_per_block);
+            });
+
             config.collator_reward_per_block + config.treasury_reward_per_block
         }
 
@@ -324,11 +469,25 @@ pub mod pallet {
         ///
         /// Returns the new inflation configuration.
         pub(crate) fn recalculate_inflation(next_era: EraNumber) -> InflationConfiguration {
-            let params = InflationParams::<T>::get();
+            Self::calculate_inflation_configuration(next_era, InflationParams::<T>::get())
+        }
+
+        /// Calculates the inflation configuration based on the total issuance & the provided inflation parameters.
+        pub(crate) fn calculate_inflation_configuration(
+            next_era: EraNumber,
+            params: InflationParameters,
+        ) -> InflationConfiguration {
             let total_issuance = T::Currency::total_issuance();
 
             // 1. Calculate maximum emission over the period before the next recalculation.
+            //    In case net inflation is targeted, the amount burned during the ending cycle is compensated.
             let max_emission = params.max_inflation_rate * total_issuance;
+            let max_emission = if T::TargetNetInflation::get() {
+                let burned = CurrentCycleIssuance::<T>::get().burned;
+                max_emission.saturating_add(burned.min(max_emission))
+            } else {
+                max_emission
+            };
             let issuance_safety_cap = total_issuance.saturating_add(max_emission);
 
             // 2. Calculate distribution of max emission between different purposes.
@@ -388,6 +547,68 @@ pub mod pallet {
             new_inflation_config
         }
 
+        /// Projects the inflation configuration for the next cycle, as if it was recalculated
+        /// at the current recalculation era, using the current total issuance & inflation parameters.
+        ///
+        /// Inflation parameters scheduled to be applied at that recalculation are taken into account.
+        pub fn projected_inflation_configuration() -> InflationConfiguration {
+            let recalculation_era = ActiveInflationConfig::<T>::get().recalculation_era;
+            let params = ScheduledInflationParams::<T>::get()
+                .into_iter()
+                .rev()
+                .find(|(era, _)| *era <= recalculation_era)
+                .map_or_else(InflationParams::<T>::get, |(_, params)| params);
+
+            Self::calculate_inflation_configuration(recalculation_era, params)
+        }
+
+        /// Applies the latest inflation parameters scheduled at or before the specified era, if any.
+        ///
+        /// All the entries scheduled at or before the specified era are removed from the schedule.
+        fn apply_scheduled_params(next_era: EraNumber) {
+            let mut schedule = ScheduledInflationParams::<T>::get();
+            let due_entries = schedule
+                .iter()
+                .take_while(|(era, _)| *era <= next_era)
+                .count();
+            if due_entries.is_zero() {
+                return;
+            }
+
+            let (era, params) = schedule[due_entries - 1];
+            schedule.retain(|(entry_era, _)| *entry_era > next_era);
+            ScheduledInflationParams::<T>::put(schedule);
+            InflationParams::<T>::put(params);
+
+            Self::deposit_event(Event::<T>::ScheduledInflationParametersApplied { era, params });
+        }
+
+        /// Takes the issuance summary of the ending cycle, and deposits an event about it.
+        ///
+        /// Tracking starts anew for the next cycle.
+        fn finalize_cycle_issuance() {
+            let issuance = CurrentCycleIssuance::<T>::take();
+            Self::deposit_event(Event::<T>::CycleIssuanceFinalized { issuance });
+        }
+
+        /// Effective yearly (per cycle) staker APR for the specified total value staked,
+        /// based on the active inflation configuration.
+        ///
+        /// Only the base & adjustable staker rewards are considered, bonus rewards are excluded.
+        /// Result is capped at 100%, and zero is returned in case total value staked is zero.
+        pub fn staker_apr(total_value_staked: Balance) -> Perquintill {
+            if total_value_staked.is_zero() {
+                return Perquintill::zero();
+            }
+
+            let (staker_reward_pool, _) = Self::staker_and_dapp_reward_pools(total_value_staked);
+            let staker_rewards_per_cycle = staker_reward_pool.saturating_mul(Balance::from(
+                T::CycleConfiguration::build_and_earn_eras_per_cycle(),
+            ));
+
+            Perquintill::from_rational(staker_rewards_per_cycle, total_value_staked)
+        }
+
         /// Check if payout cap limit would be reached after payout.
         fn is_payout_cap_limit_exceeded(payout: Balance) -> bool {
             let config = ActiveInflationConfig::<T>::get();
@@ -417,8 +638,11 @@ pub mod pallet {
             if config.recalculation_era <= new_era {
                 DoRecalculation::<T>::put(new_era);
 
-                // Need to account for write into a single whitelisted storage item.
-                T::WeightInfo::recalculation().saturating_add(T::DbWeight::get().writes(1))
+                // Need to account for writes into two whitelisted storage items,
+                // as well as applying the scheduled inflation parameters.
+                T::WeightInfo::recalculation()
+                    .saturating_add(T::DbWeight::get().writes(2))
+                    .saturating_add(T::DbWeight::get().reads_writes(1, 2))
             } else {
                 Weight::zero()
             }
@@ -448,7 +672,11 @@ pub mod pallet {
             ActiveInflationConfig::<T>::get().bonus_reward_pool_per_period
         }
 
-        fn payout_reward(account: &T::AccountId, reward: Balance) -> Result<(), ()> {
+        fn payout_reward(
+            account: &T::AccountId,
+            reward: Balance,
+            kind: RewardKind,
+        ) -> Result<(), ()> {
             // This is a safety measure to prevent excessive minting.
             ensure!(!Self::is_payout_cap_limit_exceeded(reward), ());
 
@@ -458,10 +686,47 @@ pub mod pallet {
             //
             // In both cases, the reward is lost but this can be ignored since it's extremely unlikely
             // to appear and doesn't bring any real harm.
-            let _ = T::Currency::deposit(account, reward, Precision::Exact);
+            if T::Currency::deposit(account, reward, Precision::Exact).is_ok() {
+                CurrentCycleIssuance::<T>::mutate(|issuance| {
+                    let minted = match kind {
+                        RewardKind::Staker => &mut issuance.staker_rewards,
+                        RewardKind::Bonus => &mut issuance.bonus_rewards,
+                        RewardKind::DApp => &mut issuance.dapp_rewards,
+                    };
+                    minted.saturating_accrue(reward);
+                });
+            }
             Ok(())
         }
     }
+
+    impl<T: Config> OnIssuanceBurned for Pallet<T> {
+        fn on_issuance_burned(amount: Balance) {
+            CurrentCycleIssuance::<T>::mutate(|issuance| issuance.burned.saturating_accrue(amount));
+        }
+    }
+}
+
+/// Burns the provided credit, and records the burned amount in the ongoing cycle issuance.
+///
+/// Intended to be used in place of simply dropping the credit, e.g. for the burned part of the transaction fees.
+pub struct BurnAndRecord<T>(PhantomData<T>);
+impl<T: Config> OnUnbalanced<CreditOf<T>> for BurnAndRecord<T> {
+    fn on_nonzero_unbalanced(amount: CreditOf<T>) {
+        Pallet::<T>::on_issuance_burned(amount.peek());
+        drop(amount);
+    }
+}
+
+/// Burns the provided `Currency` imbalance, and records the burned amount in the ongoing cycle issuance.
+///
+/// Counterpart of `BurnAndRecord` for pallets which still use the `Currency` trait, e.g. for slashed deposits.
+pub struct BurnImbalanceAndRecord<T>(PhantomData<T>);
+impl<T: Config, I: Imbalance<Balance>> OnUnbalanced<I> for BurnImbalanceAndRecord<T> {
+    fn on_nonzero_unbalanced(amount: I) {
+        Pallet::<T>::on_issuance_burned(amount.peek());
+        drop(amount);
+    }
 }
 
 /// Configuration of the inflation.
@@ -527,6 +792,55 @@ impl InflationConfiguration {
     }
 }
 
+/// Issuance minted & burned during a cycle.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CycleIssuance {
+    /// Amount minted for the block producing collators.
+    #[codec(compact)]
+    pub collator_rewards: Balance,
+    /// Amount minted for the treasury.
+    #[codec(compact)]
+    pub treasury_rewards: Balance,
+    /// Amount minted for the stakers, including the claim bounties & the shared dApp rewards.
+    #[codec(compact)]
+    pub staker_rewards: Balance,
+    /// Amount minted for the loyal stakers, as bonus rewards.
+    #[codec(compact)]
+    pub bonus_rewards: Balance,
+    /// Amount minted for the dApps, as tier rewards.
+    #[codec(compact)]
+    pub dapp_rewards: Balance,
+    /// Amount burned, e.g. through the transaction fees.
+    #[codec(compact)]
+    pub burned: Balance,
+}
+
+impl CycleIssuance {
+    /// Total amount minted during the cycle.
+    pub fn minted(&self) -> Balance {
+        self.collator_rewards
+            .saturating_add(self.treasury_rewards)
+            .saturating_add(self.staker_rewards)
+            .saturating_add(self.bonus_rewards)
+            .saturating_add(self.dapp_rewards)
+    }
+
+    /// Net amount by which the issuance has increased during the cycle.
+    ///
+    /// Zero in case more was burned than minted.
+    pub fn net_minted(&self) -> Balance {
+        self.minted().saturating_sub(self.burned)
+    }
+
+    /// Net amount by which the issuance has decreased during the cycle.
+    ///
+    /// Zero in case more was minted than burned.
+    pub fn net_burned(&self) -> Balance {
+        self.burned.saturating_sub(self.minted())
+    }
+}
+
 /// Inflation parameters.
 ///
 /// The parts of the inflation that go towards different purposes must add up to exactly 100%.
@@ -55,6 +55,7 @@ parameter_types! {
     pub const BlockHashCount: BlockNumber = 250;
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+    pub static TargetNetInflation: bool = false;
 }
 
 impl frame_system::Config for Test {
@@ -145,6 +146,9 @@ impl pallet_inflation::Config for Test {
     type PayoutPerBlock = DummyPayoutPerBlock;
     type CycleConfiguration = DummyCycleConfiguration;
     type RuntimeEvent = RuntimeEvent;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledParams = ConstU32<4>;
+    type TargetNetInflation = TargetNetInflation;
     type WeightInfo = ();
 }
 
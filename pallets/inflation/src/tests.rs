@@ -17,7 +17,14 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, Event, *};
-use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::Hooks};
+use frame_support::{
+    assert_noop, assert_ok, assert_storage_noop,
+    traits::{
+        fungible::{Balanced, Mutate},
+        tokens::{Fortitude, Precision, Preservation},
+        Hooks, OnUnbalanced,
+    },
+};
 use mock::*;
 use sp_runtime::{
     traits::{AccountIdConversion, BadOrigin, Zero},
@@ -377,7 +384,11 @@ fn basic_payout_reward_is_ok() {
         let init_issuance = Balances::total_issuance();
 
         // Payout reward and verify balances are as expected
-        assert_ok!(Inflation::payout_reward(&account, reward));
+        assert_ok!(Inflation::payout_reward(
+            &account,
+            reward,
+            RewardKind::Staker
+        ));
 
         assert_eq!(Balances::free_balance(&account), init_balance + reward);
         assert_eq!(Balances::total_issuance(), init_issuance + reward);
@@ -397,7 +408,11 @@ fn payout_reward_with_exceeded_cap_but_not_exceeded_relaxed_cap_is_ok() {
         let init_issuance = Balances::total_issuance();
 
         // Payout reward and verify balances are as expected
-        assert_ok!(Inflation::payout_reward(&account, reward));
+        assert_ok!(Inflation::payout_reward(
+            &account,
+            reward,
+            RewardKind::Staker
+        ));
 
         assert_eq!(Balances::free_balance(&account), init_balance + reward);
         assert_eq!(Balances::total_issuance(), init_issuance + reward);
@@ -415,7 +430,10 @@ fn payout_reward_fails_when_relaxed_cap_is_exceeded() {
         let reward = relaxed_cap - Balances::total_issuance() + 1;
 
         // Payout should be a failure, with storage noop.
-        assert_noop!(Inflation::payout_reward(&account, reward), ());
+        assert_noop!(
+            Inflation::payout_reward(&account, reward, RewardKind::Staker),
+            ()
+        );
     })
 }
 
@@ -467,3 +485,383 @@ fn test_genesis_build() {
         assert!(ActiveInflationConfig::<Test>::get().recalculation_era > 0);
     })
 }
+
+#[test]
+fn projected_inflation_configuration_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_config = ActiveInflationConfig::<Test>::get();
+
+        // Projection must be a storage noop, and match the configuration calculated at recalculation era
+        assert_storage_noop!(Inflation::projected_inflation_configuration());
+        let projected_config = Inflation::projected_inflation_configuration();
+        assert_eq!(
+            projected_config,
+            Inflation::recalculate_inflation(init_config.recalculation_era)
+        );
+
+        // Issue some additional tokens, projection should be adjusted accordingly
+        let issued = Balances::total_issuance() / 10;
+        assert_ok!(Balances::mint_into(&1, issued));
+        let new_projected_config = Inflation::projected_inflation_configuration();
+        assert!(new_projected_config.issuance_safety_cap > projected_config.issuance_safety_cap);
+        assert!(
+            new_projected_config.collator_reward_per_block
+                > projected_config.collator_reward_per_block
+        );
+
+        // Active config isn't affected by the projection
+        assert_eq!(ActiveInflationConfig::<Test>::get(), init_config);
+    })
+}
+
+#[test]
+fn staker_apr_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Zero total value staked
+        assert!(Inflation::staker_apr(0).is_zero());
+
+        // APR must match the staker reward pool, extrapolated over the whole cycle
+        let total_value_staked = Balances::total_issuance() / 4;
+        let (staker_reward_pool, _) = Inflation::staker_and_dapp_reward_pools(total_value_staked);
+        let build_and_earn_eras_per_cycle =
+            Balance::from(<Test as Config>::CycleConfiguration::build_and_earn_eras_per_cycle());
+        assert_eq!(
+            Inflation::staker_apr(total_value_staked),
+            Perquintill::from_rational(
+                staker_reward_pool * build_and_earn_eras_per_cycle,
+                total_value_staked
+            )
+        );
+
+        // APR decreases as more is staked, once the ideal staking rate is exceeded
+        let config = ActiveInflationConfig::<Test>::get();
+        let ideal_staked = config.ideal_staking_rate * Balances::total_issuance();
+        assert!(Inflation::staker_apr(ideal_staked) > Inflation::staker_apr(ideal_staked * 2));
+    })
+}
+
+#[test]
+fn schedule_inflation_params_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let recalculation_era = ActiveInflationConfig::<Test>::get().recalculation_era;
+        let mut params = InflationParams::<Test>::get();
+        params.max_inflation_rate = Perquintill::from_percent(3);
+
+        // Schedule params for a future era, ensure it works
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            recalculation_era + 5,
+            params
+        ));
+        System::assert_last_event(
+            Event::InflationParametersScheduled {
+                era: recalculation_era + 5,
+                params,
+            }
+            .into(),
+        );
+
+        // Schedule params for an earlier era, ensure schedule remains sorted
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            recalculation_era,
+            params
+        ));
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(recalculation_era, params), (recalculation_era + 5, params)]
+        );
+
+        // Scheduling params for an already scheduled era replaces the existing entry
+        let mut new_params = params;
+        new_params.max_inflation_rate = Perquintill::from_percent(4);
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            recalculation_era + 5,
+            new_params
+        ));
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![
+                (recalculation_era, params),
+                (recalculation_era + 5, new_params)
+            ]
+        );
+    })
+}
+
+#[test]
+fn schedule_inflation_params_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let recalculation_era = ActiveInflationConfig::<Test>::get().recalculation_era;
+        let params = InflationParams::<Test>::get();
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::schedule_inflation_params(
+                RuntimeOrigin::signed(1),
+                recalculation_era,
+                params
+            ),
+            BadOrigin
+        );
+
+        // Invalid params cannot be scheduled
+        let mut invalid_params = params;
+        invalid_params.base_stakers_part = Zero::zero();
+        assert_noop!(
+            Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                recalculation_era,
+                invalid_params
+            ),
+            Error::<Test>::InvalidInflationParameters
+        );
+
+        // Params cannot be scheduled before the next recalculation era
+        assert_noop!(
+            Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                recalculation_era - 1,
+                params
+            ),
+            Error::<Test>::InvalidScheduleEra
+        );
+
+        // Schedule is bounded
+        let max_scheduled: u32 = <Test as Config>::MaxScheduledParams::get();
+        for idx in 0..max_scheduled {
+            assert_ok!(Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                recalculation_era + idx,
+                params
+            ));
+        }
+        assert_noop!(
+            Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                recalculation_era + max_scheduled,
+                params
+            ),
+            Error::<Test>::TooManyScheduledParams
+        );
+    })
+}
+
+#[test]
+fn cancel_scheduled_inflation_params_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let recalculation_era = ActiveInflationConfig::<Test>::get().recalculation_era;
+        let params = InflationParams::<Test>::get();
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            recalculation_era,
+            params
+        ));
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::cancel_scheduled_inflation_params(
+                RuntimeOrigin::signed(1),
+                recalculation_era
+            ),
+            BadOrigin
+        );
+
+        // Nothing is scheduled for this era
+        assert_noop!(
+            Inflation::cancel_scheduled_inflation_params(
+                RuntimeOrigin::root(),
+                recalculation_era + 1
+            ),
+            Error::<Test>::NoScheduledParams
+        );
+
+        // Cancel the scheduled entry, ensure it works
+        assert_ok!(Inflation::cancel_scheduled_inflation_params(
+            RuntimeOrigin::root(),
+            recalculation_era
+        ));
+        System::assert_last_event(
+            Event::ScheduledInflationParametersCancelled {
+                era: recalculation_era,
+            }
+            .into(),
+        );
+        assert!(ScheduledInflationParams::<Test>::get().is_empty());
+    })
+}
+
+#[test]
+fn scheduled_inflation_params_are_applied_on_recalculation() {
+    ExternalityBuilder::build().execute_with(|| {
+        let recalculation_era = ActiveInflationConfig::<Test>::get().recalculation_era;
+
+        let mut first_params = InflationParams::<Test>::get();
+        first_params.max_inflation_rate = Perquintill::from_percent(3);
+        let mut second_params = first_params;
+        second_params.max_inflation_rate = Perquintill::from_percent(4);
+        let mut future_params = first_params;
+        future_params.max_inflation_rate = Perquintill::from_percent(5);
+
+        for (era, params) in [
+            (recalculation_era, first_params),
+            (recalculation_era + 1, second_params),
+            (recalculation_era + 100, future_params),
+        ] {
+            assert_ok!(Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                era,
+                params
+            ));
+        }
+
+        // Projection must account for the params scheduled for the recalculation era
+        let projected_config = Inflation::projected_inflation_configuration();
+        assert_eq!(
+            projected_config,
+            Inflation::calculate_inflation_configuration(recalculation_era, first_params)
+        );
+
+        // Trigger recalculation, only the latest due params should be applied
+        Inflation::block_before_new_era(recalculation_era + 1);
+        Inflation::on_finalize(100);
+
+        assert_eq!(InflationParams::<Test>::get(), second_params);
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(recalculation_era + 100, future_params)]
+        );
+        System::assert_has_event(
+            Event::ScheduledInflationParametersApplied {
+                era: recalculation_era + 1,
+                params: second_params,
+            }
+            .into(),
+        );
+
+        let new_config = ActiveInflationConfig::<Test>::get();
+        assert_eq!(
+            new_config.issuance_safety_cap,
+            Balances::total_issuance()
+                + second_params.max_inflation_rate * Balances::total_issuance()
+        );
+
+        // Forced recalculation also applies the due params
+        assert_ok!(Inflation::force_inflation_recalculation(
+            RuntimeOrigin::root(),
+            recalculation_era + 100,
+        ));
+        assert_eq!(InflationParams::<Test>::get(), future_params);
+        assert!(ScheduledInflationParams::<Test>::get().is_empty());
+    })
+}
+
+#[test]
+fn cycle_issuance_tracking_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let config = ActiveInflationConfig::<Test>::get();
+
+        // Block rewards have been paid out once, when the externality was built
+        let init_issuance = CurrentCycleIssuance::<Test>::get();
+        assert_eq!(
+            init_issuance,
+            CycleIssuance {
+                collator_rewards: config.collator_reward_per_block,
+                treasury_rewards: config.treasury_reward_per_block,
+                ..Default::default()
+            }
+        );
+
+        // Pay out rewards of all kinds, ensure they are tracked separately
+        let account = 1;
+        assert_ok!(Inflation::payout_reward(&account, 11, RewardKind::Staker));
+        assert_ok!(Inflation::payout_reward(&account, 13, RewardKind::Bonus));
+        assert_ok!(Inflation::payout_reward(&account, 17, RewardKind::DApp));
+
+        // Burn some of the issuance
+        let total_issuance = Balances::total_issuance();
+        let burned = 19;
+        let credit = Balances::withdraw(
+            &account,
+            burned,
+            Precision::Exact,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .expect("Account has enough funds.");
+        BurnAndRecord::<Test>::on_unbalanced(credit);
+        assert_eq!(Balances::total_issuance(), total_issuance - burned);
+
+        // Burn some more via the `Currency` imbalance, e.g. a slashed deposit
+        let slashed = 23;
+        let (imbalance, _) =
+            <Balances as frame_support::traits::Currency<_>>::slash(&account, slashed);
+        BurnImbalanceAndRecord::<Test>::on_unbalanced(imbalance);
+        let burned = burned + slashed;
+        assert_eq!(Balances::total_issuance(), total_issuance - burned);
+
+        let issuance = CurrentCycleIssuance::<Test>::get();
+        assert_eq!(
+            issuance,
+            CycleIssuance {
+                staker_rewards: 11,
+                bonus_rewards: 13,
+                dapp_rewards: 17,
+                burned,
+                ..init_issuance
+            }
+        );
+        assert_eq!(issuance.minted(), init_issuance.minted() + 11 + 13 + 17);
+        assert_eq!(issuance.net_minted(), issuance.minted() - burned);
+        assert!(issuance.net_burned().is_zero());
+
+        // Trigger recalculation, issuance summary is finalized & tracking starts anew
+        Inflation::block_before_new_era(config.recalculation_era);
+        Inflation::on_finalize(100);
+
+        System::assert_has_event(Event::CycleIssuanceFinalized { issuance }.into());
+        assert_eq!(
+            CurrentCycleIssuance::<Test>::get(),
+            CycleIssuance::default()
+        );
+    })
+}
+
+#[test]
+fn target_net_inflation_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let recalculation_era = ActiveInflationConfig::<Test>::get().recalculation_era;
+        let params = InflationParams::<Test>::get();
+
+        let gross_config = Inflation::projected_inflation_configuration();
+        let total_issuance = Balances::total_issuance();
+        let max_emission = params.max_inflation_rate * total_issuance;
+
+        // Burned amount is ignored when gross inflation is targeted
+        let burned = max_emission / 10;
+        Inflation::on_issuance_burned(burned);
+        assert_eq!(Inflation::projected_inflation_configuration(), gross_config);
+
+        // Burned amount is compensated when net inflation is targeted
+        TargetNetInflation::set(true);
+        let net_config = Inflation::projected_inflation_configuration();
+        assert_eq!(
+            net_config.issuance_safety_cap,
+            gross_config.issuance_safety_cap + burned
+        );
+        assert!(net_config.collator_reward_per_block > gross_config.collator_reward_per_block);
+        assert!(
+            net_config.base_staker_reward_pool_per_era
+                > gross_config.base_staker_reward_pool_per_era
+        );
+
+        // Compensation is limited to the max emission
+        Inflation::on_issuance_burned(max_emission);
+        assert_eq!(
+            Inflation::calculate_inflation_configuration(recalculation_era, params)
+                .issuance_safety_cap,
+            total_issuance + 2 * max_emission
+        );
+    })
+}
@@ -0,0 +1,302 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::{Pallet as System, RawOrigin};
+use pallet_dapp_staking_v3::{
+    BenchmarkHelper as DAppStakingBenchmarkHelper, PeriodInfo, ProtocolState, Subperiod,
+};
+use sp_std::prelude::*;
+
+const SEED: u32 = 9000;
+
+/// Assert that the last event equals the provided one.
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    System::<T>::assert_last_event(generic_event.into());
+}
+
+/// Minimum amount which can be locked in dApp staking.
+fn minimum_locked_amount<T: Config>() -> Balance {
+    <T as pallet_dapp_staking_v3::Config>::MinimumLockedAmount::get()
+}
+
+/// Funds the account with the specified amount.
+fn set_balance<T: Config>(account: &T::AccountId, amount: Balance) {
+    <T as pallet_dapp_staking_v3::Config>::BenchmarkHelper::set_balance(account, amount);
+}
+
+// Set up the initial dApp staking protocol state, so it's not empty.
+fn initial_config<T: Config>() {
+    ActiveProtocolState::<T>::put(ProtocolState {
+        era: 1,
+        next_era_start: BlockNumber::MAX,
+        period_info: PeriodInfo {
+            number: 1,
+            subperiod: Subperiod::Voting,
+            next_subperiod_start_era: 2,
+        },
+        maintenance: false,
+    });
+}
+
+/// Register a smart contract in dApp staking, and create a liquid staking pool for it.
+fn register_and_create_pool<T: Config>() -> Result<(T::SmartContract, T::AssetId), BenchmarkError> {
+    let owner: T::AccountId = account("dapp_owner", 0, SEED);
+    let smart_contract =
+        <T as pallet_dapp_staking_v3::Config>::BenchmarkHelper::get_smart_contract(1);
+    let register_origin =
+        <T as pallet_dapp_staking_v3::Config>::ContractRegisterOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    DAppStaking::<T>::register(register_origin, owner, smart_contract.clone())?;
+
+    let asset_id = <T as Config>::BenchmarkHelper::get_asset_id(u32::MAX);
+    let origin =
+        T::PoolCreateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    Pallet::<T>::create_pool(origin, smart_contract.clone(), asset_id, 1)?;
+
+    Ok((smart_contract, asset_id))
+}
+
+/// Prepare a pool with staked funds, and an unlock batch from a past era.
+///
+/// This is the worst case for pool interactions, since the batch has to be unstaked & unlocked in dApp staking.
+fn prepare_pool_with_unlock_batch<T: Config>(
+) -> Result<(T::SmartContract, T::AssetId), BenchmarkError> {
+    initial_config::<T>();
+    let (smart_contract, asset_id) = register_and_create_pool::<T>()?;
+
+    let staker: T::AccountId = account("staker", 0, SEED);
+    let amount = 100 * minimum_locked_amount::<T>();
+    set_balance::<T>(&staker, 2 * amount);
+    Pallet::<T>::stake(
+        RawOrigin::Signed(staker.clone()).into(),
+        smart_contract.clone(),
+        amount,
+    )?;
+    Pallet::<T>::unstake(
+        RawOrigin::Signed(staker).into(),
+        smart_contract.clone(),
+        amount / 10,
+    )?;
+
+    // Batch was opened in the ongoing era, so it can only be unlocked in the next one.
+    ActiveProtocolState::<T>::mutate(|state| state.era.saturating_accrue(1));
+
+    Ok((smart_contract, asset_id))
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_pool() -> Result<(), BenchmarkError> {
+        initial_config::<T>();
+
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let smart_contract =
+            <T as pallet_dapp_staking_v3::Config>::BenchmarkHelper::get_smart_contract(1);
+        let register_origin =
+            <T as pallet_dapp_staking_v3::Config>::ContractRegisterOrigin::try_successful_origin()
+                .map_err(|_| BenchmarkError::Weightless)?;
+        DAppStaking::<T>::register(register_origin, owner, smart_contract.clone())?;
+
+        let asset_id = <T as Config>::BenchmarkHelper::get_asset_id(u32::MAX);
+        let origin =
+            T::PoolCreateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            smart_contract.clone(),
+            asset_id,
+            1,
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::PoolCreated {
+                smart_contract,
+                asset_id,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn stake() -> Result<(), BenchmarkError> {
+        let (smart_contract, _) = prepare_pool_with_unlock_batch::<T>()?;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = 10 * minimum_locked_amount::<T>();
+        set_balance::<T>(&caller, 2 * amount);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            smart_contract.clone(),
+            amount,
+        );
+
+        assert_eq!(
+            Pools::<T>::get(&smart_contract).unwrap().unlock_batch.index,
+            1
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unstake() -> Result<(), BenchmarkError> {
+        let (smart_contract, asset_id) = prepare_pool_with_unlock_batch::<T>()?;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = 10 * minimum_locked_amount::<T>();
+        set_balance::<T>(&caller, 2 * amount);
+        Pallet::<T>::stake(
+            RawOrigin::Signed(caller.clone()).into(),
+            smart_contract.clone(),
+            amount,
+        )?;
+        let derivative_amount = T::Assets::balance(asset_id, &caller);
+
+        // Reset the batch, so it has to be unlocked during the call.
+        Pools::<T>::mutate(&smart_contract, |maybe_pool| {
+            if let Some(pool) = maybe_pool {
+                pool.unlock_batch.amount.saturating_accrue(amount / 10);
+                pool.total_stake.saturating_reduce(amount / 10);
+                pool.unlock_batch.era.saturating_reduce(1);
+            }
+        });
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            smart_contract.clone(),
+            derivative_amount / 2,
+        );
+
+        assert_eq!(
+            Pools::<T>::get(&smart_contract).unwrap().unlock_batch.index,
+            2
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn withdraw_unlocked(
+        x: Linear<1, { T::MaxUnlockRequests::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        initial_config::<T>();
+        let (smart_contract, asset_id) = register_and_create_pool::<T>()?;
+
+        // Worst case is when all the requests belong to different unlocked batches which have matured.
+        let amount = minimum_locked_amount::<T>();
+        let current_block: BlockNumber = System::<T>::block_number().saturated_into();
+        let mut requests = BoundedVec::<UnlockRequest, T::MaxUnlockRequests>::default();
+        for batch in 0..x {
+            UnlockedBatches::<T>::insert(
+                &smart_contract,
+                batch,
+                UnlockedBatch {
+                    unlock_block: current_block,
+                    remaining: 2 * amount,
+                },
+            );
+            requests
+                .try_push(UnlockRequest { batch, amount })
+                .expect("Bounded by MaxUnlockRequests.");
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        UnlockRequests::<T>::insert(&caller, &smart_contract, requests);
+
+        let total_amount = amount * Balance::from(x);
+        Pools::<T>::mutate(&smart_contract, |maybe_pool| {
+            if let Some(pool) = maybe_pool {
+                pool.unlock_batch.index = x;
+                pool.pending_withdrawals = 2 * total_amount;
+            }
+        });
+        set_balance::<T>(&Pallet::<T>::pool_account(asset_id), 3 * total_amount);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), smart_contract.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::Withdrawn {
+                account: caller,
+                smart_contract,
+                amount: total_amount,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn compound() -> Result<(), BenchmarkError> {
+        let (smart_contract, asset_id) = prepare_pool_with_unlock_batch::<T>()?;
+
+        // Simulate rewards which reached the pool account, but haven't been added to the total stake yet.
+        let rewards = 10 * minimum_locked_amount::<T>();
+        let pool_account = Pallet::<T>::pool_account(asset_id);
+        set_balance::<T>(
+            &pool_account,
+            T::NativeCurrency::total_balance(&pool_account) + rewards,
+        );
+
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), smart_contract.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::Compounded {
+                smart_contract,
+                rewards,
+                locked: rewards,
+                staked: rewards,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
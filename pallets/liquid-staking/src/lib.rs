@@ -0,0 +1,794 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Liquid Staking Pallet
+//!
+//! Companion pallet for `pallet-dapp-staking-v3`, which represents staked positions with transferable receipt tokens.
+//!
+//! ## Overview
+//!
+//! Funds staked in dApp staking are frozen, and cannot be used elsewhere.
+//! This pallet allows privileged origin to create a liquid staking _pool_ for a registered smart contract.
+//! Each pool has its own account, which locks & stakes the deposited funds on the smart contract, and its own
+//! derivative asset, which is minted to the depositors.
+//!
+//! Derivative asset is created via `pallet-assets` as a _sufficient_ asset, so it can be held & transferred like any other asset,
+//! e.g. via the `assets-erc20` precompile from EVM.
+//!
+//! Exchange rate between the native currency and the derivative asset is defined by the pool's _total stake_ -
+//! the deposited funds and the claimed rewards, reduced by the unstaked amounts.
+//! Additionally, a virtual share & a virtual unit of stake are included in the exchange rate calculation,
+//! so the rate cannot be manipulated while the pool is (nearly) empty.
+//!
+//! Staker rewards & bonus rewards of the pool are claimed whenever the pool is interacted with, before the exchange rate
+//! is calculated. This increases the value of the derivative asset, without letting new depositors capture
+//! the already claimable rewards.
+//! Rewards can also reach the pool account outside of these claims, e.g. when they are claimed on behalf of the pool
+//! by other accounts, or when shared dApp rewards are paid out while the pool stake is modified.
+//! Therefore, rewards aren't measured per claim. Instead, all the pool funds which aren't accounted for by the total stake,
+//! the ongoing unlock batch or the pending withdrawals are treated as rewards, and are added to the total stake.
+//! This includes funds transferred directly to the pool account, which are effectively donated to the derivative holders.
+//!
+//! ### Unlocking
+//!
+//! dApp staking limits the number of concurrent unlocking chunks per account, so the pool cannot start unlocking
+//! for every `unstake` call. Instead, unstaked amounts are collected into an _unlock batch_.
+//! Once the era in which the batch was opened has passed, the whole batch is unlocked in dApp staking at once,
+//! on the next interaction with the pool. This means at most one unlocking chunk is created per era.
+//! In case unlocking isn't possible at that moment, e.g. because all the unlocking chunks are in use,
+//! the batch keeps collecting the unstaked amounts, and unlocking is retried on the next interaction.
+//!
+//! ### Calls
+//!
+//! * `create_pool` - creates a liquid staking pool & derivative asset for the registered smart contract.
+//! * `stake` - deposits native funds into the pool, which are locked & staked on the smart contract. Derivative asset is minted in return.
+//! * `unstake` - burns the derivative asset, and adds the equivalent amount of native funds to the ongoing unlock batch.
+//! * `withdraw_unlocked` - transfers the native funds of the unlocked batches which have undergone the `UnlockingPeriod` to the caller.
+//! * `compound` - permissionless call which claims the pool's rewards, unlocks the pending unlock batch,
+//!                and locks & stakes all the pool funds which should be staked.
+//!
+//! Each of the dApp staking operations performed on behalf of the pool is best-effort, and is retried on the next interaction,
+//! so e.g. unclaimed rewards of the pool never prevent the users from staking or unstaking.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    pallet_prelude::*,
+    storage::with_storage_layer,
+    traits::{
+        fungible::{Inspect as FunInspect, Mutate as FunMutate},
+        fungibles::{Create as FunsCreate, Inspect as FunsInspect, Mutate as FunsMutate},
+        tokens::{Fortitude, Precision, Preservation},
+    },
+    PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AccountIdConversion, Saturating, Zero},
+    Rounding, SaturatedConversion,
+};
+use sp_std::prelude::*;
+
+use astar_primitives::{dapp_staking::EraNumber, Balance, BlockNumber};
+use pallet_dapp_staking_v3::{
+    AccountLedgerFor, ActiveProtocolState, IntegratedDApps, Ledger, Pallet as DAppStaking,
+    WeightInfo as DAppStakingWeightInfo,
+};
+
+pub use pallet::*;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Unstaked amounts collected during an era, which are unlocked in dApp staking together.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub struct UnlockBatch {
+    /// Index of the batch, unique per pool.
+    #[codec(compact)]
+    pub index: u32,
+    /// Era in which the first amount was added to the batch.
+    #[codec(compact)]
+    pub era: EraNumber,
+    /// Total amount collected in the batch.
+    #[codec(compact)]
+    pub amount: Balance,
+}
+
+/// Batch which has been unlocked in dApp staking, but hasn't been fully withdrawn yet.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct UnlockedBatch {
+    /// Block at which the batch funds can be withdrawn.
+    #[codec(compact)]
+    pub unlock_block: BlockNumber,
+    /// Amount of the batch which hasn't been withdrawn yet.
+    #[codec(compact)]
+    pub remaining: Balance,
+}
+
+/// Amount requested for unlocking by an account, as part of an unlock batch.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct UnlockRequest {
+    /// Index of the unlock batch.
+    #[codec(compact)]
+    pub batch: u32,
+    /// Requested amount.
+    #[codec(compact)]
+    pub amount: Balance,
+}
+
+/// Liquid staking pool of a single smart contract.
+#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PoolInfo<AssetId> {
+    /// Id of the derivative asset.
+    pub asset_id: AssetId,
+    /// Native funds backing the derivative asset - deposits & claimed rewards, reduced by the unstaked amounts.
+    #[codec(compact)]
+    pub total_stake: Balance,
+    /// Ongoing unlock batch.
+    pub unlock_batch: UnlockBatch,
+    /// Total amount of the unlocked batches, owed to accounts which haven't withdrawn the funds yet.
+    #[codec(compact)]
+    pub pending_withdrawals: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<AssetId> {
+        fn get_asset_id(id: u32) -> AssetId;
+    }
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_dapp_staking_v3::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Native currency, used to move the funds in & out of the pools.
+        type NativeCurrency: FunMutate<Self::AccountId, Balance = Balance>;
+
+        /// Asset Id type of the derivative assets.
+        type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+
+        /// Assets used to create, mint & burn the derivative assets.
+        type Assets: FunsCreate<Self::AccountId, AssetId = Self::AssetId, Balance = Balance>
+            + FunsMutate<Self::AccountId, AssetId = Self::AssetId, Balance = Balance>;
+
+        /// Privileged origin allowed to create liquid staking pools.
+        type PoolCreateOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Pallet Id, used to derive the pool accounts.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Maximum number of pending unlock requests per account, per pool.
+        #[pallet::constant]
+        type MaxUnlockRequests: Get<u32>;
+
+        /// Weight info for various calls & operations in the pallet.
+        type WeightInfo: WeightInfo;
+
+        /// Helper trait for benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Liquid staking pool was created for the smart contract.
+        PoolCreated {
+            smart_contract: T::SmartContract,
+            asset_id: T::AssetId,
+        },
+        /// Account deposited native funds into the pool, and received the derivative asset.
+        Staked {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+            derivative_amount: Balance,
+        },
+        /// Account burned the derivative asset, and added the equivalent native funds to the unlock batch.
+        Unstaked {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            derivative_amount: Balance,
+            amount: Balance,
+            batch: u32,
+        },
+        /// Unlock batch of the pool was unlocked in dApp staking.
+        BatchUnlocked {
+            smart_contract: T::SmartContract,
+            batch: u32,
+            amount: Balance,
+            unlock_block: BlockNumber,
+        },
+        /// Account withdrew the native funds which have undergone the unlocking period.
+        Withdrawn {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        },
+        /// Part of the pool stake couldn't be locked or staked, and remains idle until the next interaction with the pool.
+        StakeIdle {
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        },
+        /// Pool rewards were claimed, and pool funds were locked & staked.
+        Compounded {
+            smart_contract: T::SmartContract,
+            rewards: Balance,
+            locked: Balance,
+            staked: Balance,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Smart contract isn't registered in dApp staking.
+        ContractNotFound,
+        /// Liquid staking pool already exists for the smart contract.
+        PoolAlreadyExists,
+        /// Liquid staking pool doesn't exist for the smart contract.
+        PoolNotFound,
+        /// Amount must be greater than zero.
+        ZeroAmount,
+        /// Amount is too small to be converted into a non-zero amount at the current exchange rate.
+        AmountTooSmall,
+        /// Account has too many pending unlock requests for the pool.
+        TooManyUnlockRequests,
+        /// There are no unlocked funds to withdraw.
+        NothingToWithdraw,
+    }
+
+    /// Liquid staking pools, per smart contract.
+    #[pallet::storage]
+    pub type Pools<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, PoolInfo<T::AssetId>, OptionQuery>;
+
+    /// Pending unlock requests of an account, per smart contract pool.
+    #[pallet::storage]
+    pub type UnlockRequests<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::SmartContract,
+        BoundedVec<UnlockRequest, T::MaxUnlockRequests>,
+        ValueQuery,
+    >;
+
+    /// Unlocked batches which haven't been fully withdrawn yet, per smart contract pool & batch index.
+    #[pallet::storage]
+    pub type UnlockedBatches<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::SmartContract,
+        Twox64Concat,
+        u32,
+        UnlockedBatch,
+        OptionQuery,
+    >;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Creates a liquid staking pool for the registered smart contract.
+        ///
+        /// The derivative asset is created as a sufficient asset, owned by the pool account.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            asset_id: T::AssetId,
+            #[pallet::compact] min_balance: Balance,
+        ) -> DispatchResult {
+            T::PoolCreateOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                IntegratedDApps::<T>::contains_key(&smart_contract),
+                Error::<T>::ContractNotFound
+            );
+            ensure!(
+                !Pools::<T>::contains_key(&smart_contract),
+                Error::<T>::PoolAlreadyExists
+            );
+
+            T::Assets::create(asset_id, Self::pool_account(asset_id), true, min_balance)?;
+
+            Pools::<T>::insert(
+                &smart_contract,
+                PoolInfo {
+                    asset_id,
+                    total_stake: Balance::zero(),
+                    unlock_batch: Default::default(),
+                    pending_withdrawals: Balance::zero(),
+                },
+            );
+
+            Self::deposit_event(Event::<T>::PoolCreated {
+                smart_contract,
+                asset_id,
+            });
+
+            Ok(())
+        }
+
+        /// Deposits the native funds into the pool, where they are locked & staked on the smart contract.
+        ///
+        /// Derivative asset is minted to the caller, according to the current exchange rate.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::stake().saturating_add(Pallet::<T>::claim_rewards_weight()))]
+        pub fn stake(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+            let mut pool = Pools::<T>::get(&smart_contract).ok_or(Error::<T>::PoolNotFound)?;
+            let pool_account = Self::pool_account(pool.asset_id);
+            ensure!(
+                IntegratedDApps::<T>::contains_key(&smart_contract),
+                Error::<T>::ContractNotFound
+            );
+
+            // Rewards must be claimed before the exchange rate is calculated, so the depositor doesn't get a share of them.
+            Self::claim_rewards(&mut pool, &pool_account, &smart_contract);
+
+            let derivative_amount = Self::to_derivative(&pool, amount);
+            ensure!(!derivative_amount.is_zero(), Error::<T>::AmountTooSmall);
+
+            T::NativeCurrency::transfer(&account, &pool_account, amount, Preservation::Preserve)?;
+            T::Assets::mint_into(pool.asset_id, &account, derivative_amount)?;
+            pool.total_stake.saturating_accrue(amount);
+
+            Self::unlock_batch(&mut pool, &pool_account, &smart_contract);
+            Self::lock_and_stake(&pool, &pool_account, &smart_contract);
+            let idle_stake = Self::idle_stake(&pool, &pool_account);
+            Pools::<T>::insert(&smart_contract, pool);
+
+            Self::deposit_event(Event::<T>::Staked {
+                account,
+                smart_contract: smart_contract.clone(),
+                amount,
+                derivative_amount,
+            });
+            if !idle_stake.is_zero() {
+                Self::deposit_event(Event::<T>::StakeIdle {
+                    smart_contract,
+                    amount: idle_stake,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Burns the derivative asset, and adds the equivalent amount of native funds to the ongoing unlock batch.
+        ///
+        /// Funds can be withdrawn via `withdraw_unlocked` once the batch has been unlocked,
+        /// and the dApp staking unlocking period has passed.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::unstake().saturating_add(Pallet::<T>::claim_rewards_weight()))]
+        pub fn unstake(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] derivative_amount: Balance,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            ensure!(!derivative_amount.is_zero(), Error::<T>::ZeroAmount);
+
+            let mut pool = Pools::<T>::get(&smart_contract).ok_or(Error::<T>::PoolNotFound)?;
+            let pool_account = Self::pool_account(pool.asset_id);
+
+            // Rewards must be claimed before the exchange rate is calculated, so the caller gets their share of them.
+            Self::claim_rewards(&mut pool, &pool_account, &smart_contract);
+
+            let amount = Self::to_native(&pool, derivative_amount);
+            ensure!(!amount.is_zero(), Error::<T>::AmountTooSmall);
+
+            // Batch from a past era is unlocked first, so the request is added to a new batch.
+            Self::unlock_batch(&mut pool, &pool_account, &smart_contract);
+            if pool.unlock_batch.amount.is_zero() {
+                pool.unlock_batch.era = ActiveProtocolState::<T>::get().era;
+            }
+
+            let batch = pool.unlock_batch.index;
+            let mut requests = UnlockRequests::<T>::get(&account, &smart_contract);
+            match requests.iter_mut().find(|request| request.batch == batch) {
+                Some(request) => request.amount.saturating_accrue(amount),
+                None => requests
+                    .try_push(UnlockRequest { batch, amount })
+                    .map_err(|_| Error::<T>::TooManyUnlockRequests)?,
+            }
+
+            T::Assets::burn_from(
+                pool.asset_id,
+                &account,
+                derivative_amount,
+                Precision::Exact,
+                Fortitude::Polite,
+            )?;
+
+            pool.total_stake.saturating_reduce(amount);
+            pool.unlock_batch.amount.saturating_accrue(amount);
+
+            Self::lock_and_stake(&pool, &pool_account, &smart_contract);
+            UnlockRequests::<T>::insert(&account, &smart_contract, requests);
+            Pools::<T>::insert(&smart_contract, pool);
+
+            Self::deposit_event(Event::<T>::Unstaked {
+                account,
+                smart_contract,
+                derivative_amount,
+                amount,
+                batch,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraws the native funds of all the unlocked batches which have undergone the unlocking period.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_unlocked(T::MaxUnlockRequests::get()))]
+        pub fn withdraw_unlocked(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+
+            let mut pool = Pools::<T>::get(&smart_contract).ok_or(Error::<T>::PoolNotFound)?;
+            let pool_account = Self::pool_account(pool.asset_id);
+
+            let current_block: BlockNumber =
+                frame_system::Pallet::<T>::block_number().saturated_into();
+            let mut requests = UnlockRequests::<T>::get(&account, &smart_contract);
+            let requests_len: u32 = requests.len().saturated_into();
+
+            let mut amount = Balance::zero();
+            requests.retain(|request| {
+                // Requests of the ongoing batch haven't been unlocked yet.
+                if request.batch >= pool.unlock_batch.index {
+                    return true;
+                }
+
+                match UnlockedBatches::<T>::get(&smart_contract, request.batch) {
+                    Some(mut batch) if batch.unlock_block <= current_block => {
+                        amount.saturating_accrue(request.amount);
+                        batch.remaining.saturating_reduce(request.amount);
+                        if batch.remaining.is_zero() {
+                            UnlockedBatches::<T>::remove(&smart_contract, request.batch);
+                        } else {
+                            UnlockedBatches::<T>::insert(&smart_contract, request.batch, batch);
+                        }
+                        false
+                    }
+                    _ => true,
+                }
+            });
+            ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+            // Matured chunks might have already been claimed by an earlier withdrawal, so failure is expected.
+            let _ = with_storage_layer(|| {
+                DAppStaking::<T>::claim_unlocked(RawOrigin::Signed(pool_account.clone()).into())
+            });
+
+            T::NativeCurrency::transfer(&pool_account, &account, amount, Preservation::Expendable)?;
+
+            if requests.is_empty() {
+                UnlockRequests::<T>::remove(&account, &smart_contract);
+            } else {
+                UnlockRequests::<T>::insert(&account, &smart_contract, requests);
+            }
+
+            pool.pending_withdrawals.saturating_reduce(amount);
+            Pools::<T>::insert(&smart_contract, pool);
+
+            Self::deposit_event(Event::<T>::Withdrawn {
+                account,
+                smart_contract,
+                amount,
+            });
+
+            Ok(Some(<T as Config>::WeightInfo::withdraw_unlocked(requests_len)).into())
+        }
+
+        /// Claims the pool rewards, unlocks the pending unlock batch, and locks & stakes the pool funds on the smart contract.
+        ///
+        /// Can be called by anyone. Each step is best-effort, e.g. rewards which aren't claimable are skipped.
+        /// In case the pool has more staker rewards pending than can be claimed in a single call,
+        /// staking will only succeed once all of them have been claimed.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::compound().saturating_add(Pallet::<T>::claim_rewards_weight()))]
+        pub fn compound(origin: OriginFor<T>, smart_contract: T::SmartContract) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut pool = Pools::<T>::get(&smart_contract).ok_or(Error::<T>::PoolNotFound)?;
+            let pool_account = Self::pool_account(pool.asset_id);
+
+            let rewards = Self::claim_rewards(&mut pool, &pool_account, &smart_contract);
+            Self::unlock_batch(&mut pool, &pool_account, &smart_contract);
+            let (locked, staked) = Self::lock_and_stake(&pool, &pool_account, &smart_contract);
+            Pools::<T>::insert(&smart_contract, pool);
+
+            Self::deposit_event(Event::<T>::Compounded {
+                smart_contract,
+                rewards,
+                locked,
+                staked,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Account of the pool with the specified derivative asset.
+        pub fn pool_account(asset_id: T::AssetId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(asset_id)
+        }
+
+        /// Converts the native amount into the derivative asset amount, according to the current exchange rate.
+        ///
+        /// A virtual share & a virtual unit of stake are included, so the exchange rate cannot be inflated
+        /// while the pool is (nearly) empty.
+        pub fn to_derivative(pool: &PoolInfo<T::AssetId>, amount: Balance) -> Balance {
+            let supply = T::Assets::total_issuance(pool.asset_id);
+
+            multiply_by_rational_with_rounding(
+                amount,
+                supply.saturating_add(1),
+                pool.total_stake.saturating_add(1),
+                Rounding::Down,
+            )
+            .unwrap_or_default()
+        }
+
+        /// Converts the derivative asset amount into the native amount, according to the current exchange rate.
+        pub fn to_native(pool: &PoolInfo<T::AssetId>, derivative_amount: Balance) -> Balance {
+            let supply = T::Assets::total_issuance(pool.asset_id);
+
+            multiply_by_rational_with_rounding(
+                derivative_amount,
+                pool.total_stake.saturating_add(1),
+                supply.saturating_add(1),
+                Rounding::Down,
+            )
+            .unwrap_or_default()
+        }
+
+        /// Claims the staker & bonus rewards of the pool, and adds all the untracked pool funds to the pool's total stake.
+        ///
+        /// Untracked funds include the rewards claimed by this call, as well as the rewards which reached
+        /// the pool account in any other way. Claiming is best-effort. Returns the amount added to the total stake.
+        fn claim_rewards(
+            pool: &mut PoolInfo<T::AssetId>,
+            pool_account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> Balance {
+            let pool_origin: OriginFor<T> = RawOrigin::Signed(pool_account.clone()).into();
+
+            let _ =
+                with_storage_layer(|| DAppStaking::<T>::claim_staker_rewards(pool_origin.clone()));
+            let _ = with_storage_layer(|| {
+                DAppStaking::<T>::claim_bonus_reward(pool_origin, smart_contract.clone())
+            });
+
+            let rewards = Self::untracked_amount(pool, pool_account);
+            pool.total_stake.saturating_accrue(rewards);
+
+            rewards
+        }
+
+        /// Unlocks the ongoing unlock batch in dApp staking, in case it was opened in one of the past eras.
+        ///
+        /// Unlocking is best-effort, e.g. in case the pool has no unlocking chunks available,
+        /// the batch remains open, and unlocking is retried on the next interaction with the pool.
+        fn unlock_batch(
+            pool: &mut PoolInfo<T::AssetId>,
+            pool_account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) {
+            let batch = pool.unlock_batch;
+            let protocol_state = ActiveProtocolState::<T>::get();
+            if batch.amount.is_zero() || batch.era >= protocol_state.era {
+                return;
+            }
+
+            let pool_origin: OriginFor<T> = RawOrigin::Signed(pool_account.clone()).into();
+
+            // Release the matured unlocking chunks, so they don't occupy the unlocking chunk slots.
+            let _ = with_storage_layer(|| DAppStaking::<T>::claim_unlocked(pool_origin.clone()));
+
+            let current_block: BlockNumber =
+                frame_system::Pallet::<T>::block_number().saturated_into();
+            let result = with_storage_layer(|| -> Result<BlockNumber, DispatchError> {
+                let ledger = Ledger::<T>::get(pool_account);
+
+                // Part of the batch might have never been locked, in which case it's already available.
+                let unlock_amount = batch.amount.min(ledger.active_locked_amount());
+                if unlock_amount.is_zero() {
+                    return Ok(current_block);
+                }
+
+                // dApp staking unlocks everything in case the remaining locked amount would fall below the minimum,
+                // so everything needs to be unstaked in that case.
+                let remaining_amount = ledger.active_locked_amount().saturating_sub(unlock_amount);
+                let required_amount = if remaining_amount
+                    < <T as pallet_dapp_staking_v3::Config>::MinimumLockedAmount::get()
+                {
+                    ledger.active_locked_amount()
+                } else {
+                    unlock_amount
+                };
+                let unstake_amount = required_amount
+                    .saturating_sub(ledger.unlockable_amount(protocol_state.period_number()));
+
+                if !unstake_amount.is_zero() {
+                    if IntegratedDApps::<T>::contains_key(smart_contract) {
+                        DAppStaking::<T>::unstake(
+                            pool_origin.clone(),
+                            smart_contract.clone(),
+                            unstake_amount,
+                        )
+                        .map_err(|e| e.error)?;
+                    } else {
+                        DAppStaking::<T>::unstake_from_unregistered(
+                            pool_origin.clone(),
+                            smart_contract.clone(),
+                        )
+                        .map_err(|e| e.error)?;
+                    }
+                }
+                DAppStaking::<T>::unlock(pool_origin, unlock_amount)?;
+
+                Ok(current_block.saturating_add(DAppStaking::<T>::unlocking_period()))
+            });
+
+            if let Ok(unlock_block) = result {
+                UnlockedBatches::<T>::insert(
+                    smart_contract,
+                    batch.index,
+                    UnlockedBatch {
+                        unlock_block,
+                        remaining: batch.amount,
+                    },
+                );
+                pool.pending_withdrawals.saturating_accrue(batch.amount);
+                pool.unlock_batch = UnlockBatch {
+                    index: batch.index.saturating_add(1),
+                    era: protocol_state.era,
+                    amount: Balance::zero(),
+                };
+
+                Self::deposit_event(Event::<T>::BatchUnlocked {
+                    smart_contract: smart_contract.clone(),
+                    batch: batch.index,
+                    amount: batch.amount,
+                    unlock_block,
+                });
+            }
+        }
+
+        /// Locks the pool funds which should be locked, and stakes everything that can be staked on the smart contract.
+        ///
+        /// Each step is best-effort. Returns the locked & staked amounts.
+        fn lock_and_stake(
+            pool: &PoolInfo<T::AssetId>,
+            pool_account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> (Balance, Balance) {
+            let pool_origin: OriginFor<T> = RawOrigin::Signed(pool_account.clone()).into();
+
+            // Total stake & the ongoing batch must be locked, but funds which aren't tracked by the pool are never locked.
+            let ledger = Ledger::<T>::get(pool_account);
+            let lock_amount = pool
+                .total_stake
+                .saturating_add(pool.unlock_batch.amount)
+                .saturating_sub(ledger.active_locked_amount())
+                .min(Self::idle_amount(pool, pool_account, &ledger));
+            let locked = if lock_amount.is_zero() {
+                Balance::zero()
+            } else {
+                with_storage_layer(|| DAppStaking::<T>::lock(pool_origin.clone(), lock_amount))
+                    .map(|_| lock_amount)
+                    .unwrap_or_default()
+            };
+
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+            let stakeable_amount = Ledger::<T>::get(pool_account).stakeable_amount(current_period);
+            let staked = if stakeable_amount.is_zero()
+                || !IntegratedDApps::<T>::contains_key(smart_contract)
+            {
+                Balance::zero()
+            } else {
+                with_storage_layer(|| {
+                    DAppStaking::<T>::stake(pool_origin, smart_contract.clone(), stakeable_amount)
+                })
+                .map(|_| stakeable_amount)
+                .unwrap_or_default()
+            };
+
+            (locked, staked)
+        }
+
+        /// Pool funds which aren't accounted for by the total stake, the ongoing unlock batch or the pending withdrawals.
+        fn untracked_amount(pool: &PoolInfo<T::AssetId>, pool_account: &T::AccountId) -> Balance {
+            T::NativeCurrency::total_balance(pool_account)
+                .saturating_sub(pool.total_stake)
+                .saturating_sub(pool.unlock_batch.amount)
+                .saturating_sub(pool.pending_withdrawals)
+        }
+
+        /// Part of the total stake & the ongoing unlock batch which isn't staked on the smart contract.
+        fn idle_stake(pool: &PoolInfo<T::AssetId>, pool_account: &T::AccountId) -> Balance {
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+
+            pool.total_stake
+                .saturating_add(pool.unlock_batch.amount)
+                .saturating_sub(Ledger::<T>::get(pool_account).staked_amount(current_period))
+        }
+
+        /// Pool funds which are neither locked in dApp staking, nor owed to accounts waiting to withdraw.
+        fn idle_amount(
+            pool: &PoolInfo<T::AssetId>,
+            pool_account: &T::AccountId,
+            ledger: &AccountLedgerFor<T>,
+        ) -> Balance {
+            // Owed funds which have already been released from the unlocking chunks.
+            let owed_free_amount = pool
+                .pending_withdrawals
+                .saturating_sub(ledger.unlocking_amount());
+
+            T::NativeCurrency::total_balance(pool_account)
+                .saturating_sub(ledger.total_locked_amount())
+                .saturating_sub(owed_free_amount)
+        }
+
+        /// Weight of claiming the pool rewards, as declared by dApp staking.
+        ///
+        /// Includes the shared dApp rewards, which are paid out when the pool stake is modified.
+        pub(crate) fn claim_rewards_weight() -> Weight {
+            Self::dapp_staking_weight(pallet_dapp_staking_v3::Call::claim_staker_rewards {})
+                .saturating_add(
+                    <T as pallet_dapp_staking_v3::Config>::WeightInfo::claim_bonus_reward(),
+                )
+                .saturating_add(pallet_dapp_staking_v3::Pallet::<T>::shared_rewards_payout_weight())
+        }
+
+        /// Weight of the dApp staking call, as declared by dApp staking.
+        pub(crate) fn dapp_staking_weight(call: pallet_dapp_staking_v3::Call<T>) -> Weight {
+            call.get_dispatch_info().weight
+        }
+    }
+}
@@ -0,0 +1,407 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_liquid_staking, *};
+
+use frame_support::{
+    assert_ok, construct_runtime, parameter_types,
+    traits::{
+        fungible::Mutate as FunMutate, AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, Hooks,
+    },
+    weights::Weight,
+    PalletId,
+};
+use frame_system::RawOrigin;
+use sp_arithmetic::{fixed_point::FixedU128, Permill};
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Perbill,
+};
+use sp_std::marker::PhantomData;
+
+use astar_primitives::{
+    dapp_staking::{
+        CycleConfiguration, EraNumber, PeriodNumber, RewardKind, SmartContract,
+        StakingRewardHandler, StandardTierSlots,
+    },
+    oracle::PriceProvider,
+    Balance, BlockNumber,
+};
+use pallet_dapp_staking_v3::TierThreshold;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u128;
+pub(crate) type MockSmartContract = SmartContract<AccountId>;
+
+pub(crate) const EXISTENTIAL_DEPOSIT: Balance = 2;
+pub(crate) const INIT_BALANCE: Balance = 1_000_000;
+pub(crate) const MINIMUM_LOCK_AMOUNT: Balance = 10;
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        DappStaking: pallet_dapp_staking_v3,
+        LiquidStaking: pallet_liquid_staking,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Block = Block;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<4>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type AccountStore = System;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type MaxFreezes = ConstU32<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type AssetIdParameter = AssetId;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<1>;
+    type AssetAccountDeposit = ConstU128<10>;
+    type MetadataDepositBase = ConstU128<1>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type ApprovalDeposit = ConstU128<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type WeightInfo = ();
+    type CallbackHandle = ();
+    type Extra = ();
+    type RemoveItemsLimit = ConstU32<5>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+pub struct DummyPriceProvider;
+impl PriceProvider for DummyPriceProvider {
+    fn average_price() -> FixedU128 {
+        FixedU128::from_rational(1, 10)
+    }
+}
+
+pub struct DummyStakingRewardHandler;
+impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
+    fn staker_and_dapp_reward_pools(_total_staked_value: Balance) -> (Balance, Balance) {
+        (1_000_000, 1_000)
+    }
+
+    fn bonus_reward_pool() -> Balance {
+        3_000
+    }
+
+    fn payout_reward(beneficiary: &AccountId, reward: Balance, _: RewardKind) -> Result<(), ()> {
+        let _ = Balances::mint_into(beneficiary, reward);
+        Ok(())
+    }
+}
+
+pub struct DummyCycleConfiguration;
+impl CycleConfiguration for DummyCycleConfiguration {
+    fn periods_per_cycle() -> u32 {
+        4
+    }
+
+    fn eras_per_voting_subperiod() -> u32 {
+        2
+    }
+
+    fn eras_per_build_and_earn_subperiod() -> u32 {
+        4
+    }
+
+    fn blocks_per_era() -> u32 {
+        10
+    }
+}
+
+// Just to satisfy the trait bound
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_dapp_staking_v3::BenchmarkHelper<MockSmartContract, AccountId> for BenchmarkHelper {
+    fn get_smart_contract(id: u32) -> MockSmartContract {
+        MockSmartContract::Wasm(id as AccountId)
+    }
+
+    fn set_balance(account: &AccountId, amount: Balance) {
+        let _ = Balances::mint_into(account, amount);
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<AssetId> for BenchmarkHelper {
+    fn get_asset_id(id: u32) -> AssetId {
+        id.into()
+    }
+}
+
+parameter_types! {
+    pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const DappStakingMaxClaimBountyRate: Perbill = Perbill::from_percent(0);
+}
+
+impl pallet_dapp_staking_v3::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type SmartContract = MockSmartContract;
+    type ContractRegisterOrigin = frame_system::EnsureRoot<AccountId>;
+    type ContractUnregisterOrigin = frame_system::EnsureRoot<AccountId>;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type NativePriceProvider = DummyPriceProvider;
+    type StakingRewardHandler = DummyStakingRewardHandler;
+    type CycleConfiguration = DummyCycleConfiguration;
+    type Observers = ();
+    type AccountCheck = ();
+    type TierSlots = StandardTierSlots;
+    type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
+    type EraRewardSpanLength = ConstU32<8>;
+    type RewardRetentionInPeriods = ConstU32<2>;
+    type MaxNumberOfContracts = ConstU32<10>;
+    type MaxUnlockingChunks = ConstU32<5>;
+    type MinimumLockedAmount = ConstU128<MINIMUM_LOCK_AMOUNT>;
+    type UnlockingPeriod = ConstU32<2>;
+    type MaxNumberOfStakedContracts = ConstU32<5>;
+    type MinimumStakeAmount = ConstU128<3>;
+    type NumberOfTiers = ConstU32<4>;
+    type RankingEnabled = ConstBool<true>;
+    type TierAssignment = pallet_dapp_staking_v3::StandardTierAssignment;
+    type MaxClaimBountyRate = DappStakingMaxClaimBountyRate;
+    type MaxClaimBounty = ConstU128<0>;
+    type MaxArchivedPeriods = ConstU32<8>;
+    type MetadataDepositBase = ConstU128<10>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type MaxMetadataFieldLength = ConstU32<32>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type MaxContractAliases = ConstU32<4>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+parameter_types! {
+    pub const LiquidStakingPalletId: PalletId = PalletId(*b"py/liqst");
+}
+
+impl pallet_liquid_staking::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type NativeCurrency = Balances;
+    type AssetId = AssetId;
+    type Assets = Assets;
+    type PoolCreateOrigin = frame_system::EnsureRoot<AccountId>;
+    type PalletId = LiquidStakingPalletId;
+    type MaxUnlockRequests = ConstU32<3>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let mut storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Test> {
+            balances: (1..=10).map(|account| (account, INIT_BALANCE)).collect(),
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
+        pallet_dapp_staking_v3::GenesisConfig::<Test> {
+            reward_portion: vec![
+                Permill::from_percent(40),
+                Permill::from_percent(30),
+                Permill::from_percent(20),
+                Permill::from_percent(10),
+            ],
+            slot_distribution: vec![
+                Permill::from_percent(10),
+                Permill::from_percent(20),
+                Permill::from_percent(30),
+                Permill::from_percent(40),
+            ],
+            tier_thresholds: vec![
+                TierThreshold::FixedPercentage {
+                    required_percentage: Perbill::from_percent(4),
+                },
+                TierThreshold::FixedPercentage {
+                    required_percentage: Perbill::from_percent(3),
+                },
+                TierThreshold::FixedPercentage {
+                    required_percentage: Perbill::from_percent(2),
+                },
+                TierThreshold::FixedPercentage {
+                    required_percentage: Perbill::from_percent(1),
+                },
+            ],
+            slots_per_tier: vec![10, 20, 30, 40],
+            min_stakers: vec![],
+            safeguard: None,
+            _config: PhantomData,
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            DappStaking::on_initialize(System::block_number());
+            run_to_block(2);
+        });
+        ext
+    }
+}
+
+/// Run to the specified block number.
+/// Function assumes first block has been initialized.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    while System::block_number() < n {
+        DappStaking::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        DappStaking::on_initialize(System::block_number());
+    }
+}
+
+/// Run for the specified number of blocks.
+/// Function assumes first block has been initialized.
+pub(crate) fn run_for_blocks(n: BlockNumber) {
+    run_to_block(System::block_number() + n);
+}
+
+/// Advance blocks until the specified era has been reached.
+///
+/// Function has no effect if era is already passed.
+pub(crate) fn advance_to_era(era: EraNumber) {
+    assert!(era >= ActiveProtocolState::<Test>::get().era);
+    while ActiveProtocolState::<Test>::get().era < era {
+        run_for_blocks(1);
+    }
+}
+
+/// Advance blocks until next era has been reached.
+pub(crate) fn advance_to_next_era() {
+    advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+}
+
+/// Advance blocks until the specified period has been reached.
+///
+/// Function has no effect if period is already passed.
+pub(crate) fn advance_to_period(period: PeriodNumber) {
+    assert!(period >= ActiveProtocolState::<Test>::get().period_number());
+    while ActiveProtocolState::<Test>::get().period_number() < period {
+        run_for_blocks(1);
+    }
+}
+
+/// Advance blocks until next period has been reached.
+pub(crate) fn advance_to_next_period() {
+    advance_to_period(ActiveProtocolState::<Test>::get().period_number() + 1);
+}
+
+/// Register the smart contract in dApp staking, and create a liquid staking pool for it.
+pub(crate) fn register_and_create_pool(
+    owner: AccountId,
+    smart_contract: &MockSmartContract,
+    asset_id: AssetId,
+) {
+    assert_ok!(DappStaking::register(
+        RawOrigin::Root.into(),
+        owner,
+        smart_contract.clone(),
+    ));
+    assert_ok!(LiquidStaking::create_pool(
+        RawOrigin::Root.into(),
+        smart_contract.clone(),
+        asset_id,
+        1,
+    ));
+}
+
+/// Return all liquid staking events from the event buffer.
+pub(crate) fn liquid_staking_events() -> Vec<crate::Event<Test>> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let RuntimeEvent::LiquidStaking(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,670 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, Event, *};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{fungible::Inspect as FunInspect, fungibles::Inspect as FunsInspect},
+};
+use mock::*;
+use pallet_dapp_staking_v3::StakerInfo;
+use sp_runtime::traits::{BadOrigin, Zero};
+
+#[test]
+fn create_pool_works() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+
+        // Only registered smart contracts can have a pool
+        assert_noop!(
+            LiquidStaking::create_pool(RuntimeOrigin::root(), smart_contract.clone(), asset_id, 1),
+            Error::<Test>::ContractNotFound
+        );
+
+        assert_ok!(DappStaking::register(
+            RuntimeOrigin::root(),
+            1,
+            smart_contract.clone()
+        ));
+
+        // Only privileged origin can create a pool
+        assert_noop!(
+            LiquidStaking::create_pool(
+                RuntimeOrigin::signed(1),
+                smart_contract.clone(),
+                asset_id,
+                1
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(LiquidStaking::create_pool(
+            RuntimeOrigin::root(),
+            smart_contract.clone(),
+            asset_id,
+            1
+        ));
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::PoolCreated {
+            smart_contract: smart_contract.clone(),
+            asset_id,
+        }));
+        assert_eq!(
+            Pools::<Test>::get(&smart_contract),
+            Some(PoolInfo {
+                asset_id,
+                total_stake: 0,
+                unlock_batch: Default::default(),
+                pending_withdrawals: 0,
+            })
+        );
+        assert!(Assets::asset_exists(asset_id));
+
+        // Pool can only be created once
+        assert_noop!(
+            LiquidStaking::create_pool(RuntimeOrigin::root(), smart_contract, asset_id + 1, 1),
+            Error::<Test>::PoolAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn stake_works() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+
+        assert_noop!(
+            LiquidStaking::stake(RuntimeOrigin::signed(1), smart_contract.clone(), 100),
+            Error::<Test>::PoolNotFound
+        );
+
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        assert_noop!(
+            LiquidStaking::stake(RuntimeOrigin::signed(2), smart_contract.clone(), 0),
+            Error::<Test>::ZeroAmount
+        );
+
+        // First deposit is minted 1:1
+        let amount = 100;
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Staked {
+            account: 2,
+            smart_contract: smart_contract.clone(),
+            amount,
+            derivative_amount: amount,
+        }));
+        assert_eq!(Assets::balance(asset_id, 2), amount);
+        assert_eq!(Balances::total_balance(&2), INIT_BALANCE - amount);
+
+        // Deposit is locked & staked by the pool account
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&pool_account);
+        assert_eq!(ledger.active_locked_amount(), amount);
+        assert_eq!(ledger.staked_amount(current_period), amount);
+        assert_eq!(
+            StakerInfo::<Test>::get(&pool_account, &smart_contract)
+                .expect("Pool must have staked.")
+                .total_staked_amount(),
+            amount
+        );
+
+        // Exchange rate hasn't changed, so the next deposit is minted 1:1 as well
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone(),
+            amount
+        ));
+        assert_eq!(Assets::balance(asset_id, 3), amount);
+        assert_eq!(Assets::total_issuance(asset_id), 2 * amount);
+        assert_eq!(
+            Ledger::<Test>::get(&pool_account).staked_amount(current_period),
+            2 * amount
+        );
+    })
+}
+
+#[test]
+fn unstake_and_withdraw_unlocked_works() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        let amount = 100;
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+
+        assert_noop!(
+            LiquidStaking::unstake(RuntimeOrigin::signed(2), smart_contract.clone(), 0),
+            Error::<Test>::ZeroAmount
+        );
+
+        // Unstake part of the derivative amount, which is added to the ongoing unlock batch
+        let unstake_amount = 40;
+        let unstake_era = ActiveProtocolState::<Test>::get().era;
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            unstake_amount
+        ));
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Unstaked {
+            account: 2,
+            smart_contract: smart_contract.clone(),
+            derivative_amount: unstake_amount,
+            amount: unstake_amount,
+            batch: 0,
+        }));
+        assert_eq!(Assets::balance(asset_id, 2), amount - unstake_amount);
+        let pool = Pools::<Test>::get(&smart_contract).unwrap();
+        assert_eq!(pool.total_stake, amount - unstake_amount);
+        assert_eq!(
+            pool.unlock_batch,
+            UnlockBatch {
+                index: 0,
+                era: unstake_era,
+                amount: unstake_amount,
+            }
+        );
+        assert!(pool.pending_withdrawals.is_zero());
+        assert_eq!(
+            UnlockRequests::<Test>::get(&2, &smart_contract).into_inner(),
+            vec![UnlockRequest {
+                batch: 0,
+                amount: unstake_amount,
+            }]
+        );
+
+        // Batch isn't unlocked in dApp staking yet
+        assert_eq!(
+            Ledger::<Test>::get(&pool_account).active_locked_amount(),
+            amount
+        );
+        assert_noop!(
+            LiquidStaking::withdraw_unlocked(RuntimeOrigin::signed(2), smart_contract.clone()),
+            Error::<Test>::NothingToWithdraw
+        );
+
+        // Batch is unlocked on the first interaction in the next era
+        advance_to_next_era();
+        assert_ok!(LiquidStaking::compound(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone()
+        ));
+        let unlock_block = System::block_number() + DappStaking::unlocking_period();
+        assert!(liquid_staking_events().contains(&Event::BatchUnlocked {
+            smart_contract: smart_contract.clone(),
+            batch: 0,
+            amount: unstake_amount,
+            unlock_block,
+        }));
+        let pool = Pools::<Test>::get(&smart_contract).unwrap();
+        assert_eq!(pool.unlock_batch.index, 1);
+        assert!(pool.unlock_batch.amount.is_zero());
+        assert_eq!(pool.pending_withdrawals, unstake_amount);
+        assert_eq!(
+            UnlockedBatches::<Test>::get(&smart_contract, 0),
+            Some(UnlockedBatch {
+                unlock_block,
+                remaining: unstake_amount,
+            })
+        );
+
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&pool_account);
+        assert_eq!(ledger.active_locked_amount(), amount - unstake_amount);
+        assert_eq!(ledger.unlocking_amount(), unstake_amount);
+        assert_eq!(
+            ledger.staked_amount(current_period),
+            amount - unstake_amount
+        );
+
+        // Funds cannot be withdrawn before the unlocking period passes
+        assert_noop!(
+            LiquidStaking::withdraw_unlocked(RuntimeOrigin::signed(2), smart_contract.clone()),
+            Error::<Test>::NothingToWithdraw
+        );
+
+        run_to_block(unlock_block);
+        let init_balance = Balances::total_balance(&2);
+        assert_ok!(LiquidStaking::withdraw_unlocked(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Withdrawn {
+            account: 2,
+            smart_contract: smart_contract.clone(),
+            amount: unstake_amount,
+        }));
+        assert_eq!(Balances::total_balance(&2), init_balance + unstake_amount);
+        assert!(Pools::<Test>::get(&smart_contract)
+            .unwrap()
+            .pending_withdrawals
+            .is_zero());
+        assert!(!UnlockRequests::<Test>::contains_key(&2, &smart_contract));
+        assert!(!UnlockedBatches::<Test>::contains_key(&smart_contract, 0));
+        assert!(Ledger::<Test>::get(&pool_account)
+            .unlocking_amount()
+            .is_zero());
+
+        // Nothing left to withdraw
+        assert_noop!(
+            LiquidStaking::withdraw_unlocked(RuntimeOrigin::signed(2), smart_contract),
+            Error::<Test>::NothingToWithdraw
+        );
+    })
+}
+
+#[test]
+fn unstake_requests_are_batched_per_era() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        for account in [2, 3] {
+            assert_ok!(LiquidStaking::stake(
+                RuntimeOrigin::signed(account),
+                smart_contract.clone(),
+                100
+            ));
+        }
+
+        // Many unstake calls in the same era are collected into a single batch & request
+        let max_unlocking_chunks =
+            <Test as pallet_dapp_staking_v3::Config>::MaxUnlockingChunks::get();
+        for _ in 0..=max_unlocking_chunks {
+            for account in [2, 3] {
+                assert_ok!(LiquidStaking::unstake(
+                    RuntimeOrigin::signed(account),
+                    smart_contract.clone(),
+                    2
+                ));
+            }
+        }
+        let batch_amount = 2 * 2 * (max_unlocking_chunks as Balance + 1);
+        assert_eq!(
+            Pools::<Test>::get(&smart_contract)
+                .unwrap()
+                .unlock_batch
+                .amount,
+            batch_amount
+        );
+        for account in [2, 3] {
+            assert_eq!(
+                UnlockRequests::<Test>::get(&account, &smart_contract).into_inner(),
+                vec![UnlockRequest {
+                    batch: 0,
+                    amount: batch_amount / 2,
+                }]
+            );
+        }
+
+        // Unstaking in the next era unlocks the previous batch as a single chunk, and opens a new one
+        advance_to_next_era();
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            2
+        ));
+        let ledger = Ledger::<Test>::get(&pool_account);
+        assert_eq!(ledger.unlocking.len(), 1);
+        assert_eq!(ledger.unlocking_amount(), batch_amount);
+
+        let pool = Pools::<Test>::get(&smart_contract).unwrap();
+        assert_eq!(pool.unlock_batch.index, 1);
+        assert_eq!(pool.unlock_batch.amount, 2);
+        assert_eq!(pool.pending_withdrawals, batch_amount);
+        assert_eq!(
+            UnlockRequests::<Test>::get(&2, &smart_contract).into_inner(),
+            vec![
+                UnlockRequest {
+                    batch: 0,
+                    amount: batch_amount / 2,
+                },
+                UnlockRequest {
+                    batch: 1,
+                    amount: 2,
+                }
+            ]
+        );
+    })
+}
+
+#[test]
+fn unstake_fails_with_too_many_unlock_requests() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            100
+        ));
+
+        // Each era opens a new batch, and a new request
+        let max_requests = <Test as Config>::MaxUnlockRequests::get();
+        for _ in 0..max_requests {
+            assert_ok!(LiquidStaking::unstake(
+                RuntimeOrigin::signed(2),
+                smart_contract.clone(),
+                5
+            ));
+            advance_to_next_era();
+        }
+
+        assert_noop!(
+            LiquidStaking::unstake(RuntimeOrigin::signed(2), smart_contract, 5),
+            Error::<Test>::TooManyUnlockRequests
+        );
+    })
+}
+
+#[test]
+fn compound_increases_exchange_rate() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        // Stake during the voting subperiod, so the pool is eligible for the bonus reward
+        let amount = 1000;
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Advance to the next period, so all the rewards can be claimed
+        advance_to_next_period();
+        assert_eq!(Balances::total_balance(&pool_account), amount);
+
+        assert_ok!(LiquidStaking::compound(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone()
+        ));
+
+        // Rewards are paid out to the pool, added to the total stake, and everything is locked & staked again
+        let total_stake = Balances::total_balance(&pool_account);
+        assert!(total_stake > amount);
+        let pool = Pools::<Test>::get(&smart_contract).unwrap();
+        assert_eq!(pool.total_stake, total_stake);
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&pool_account);
+        assert_eq!(ledger.active_locked_amount(), total_stake);
+        assert_eq!(ledger.staked_amount(current_period), total_stake);
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Compounded {
+            smart_contract: smart_contract.clone(),
+            rewards: total_stake - amount,
+            locked: total_stake - amount,
+            staked: total_stake,
+        }));
+
+        // Derivative asset is now worth more than the native currency
+        let native_amount = LiquidStaking::to_native(&pool, amount);
+        assert_eq!(native_amount, amount * (total_stake + 1) / (amount + 1));
+        assert!(native_amount > amount);
+
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone(),
+            amount
+        ));
+        let derivative_amount = Assets::balance(asset_id, 3);
+        assert!(derivative_amount < amount);
+        assert_eq!(derivative_amount, amount * (amount + 1) / (total_stake + 1));
+
+        // Unstaking everything returns the deposit including the rewards
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+        assert!(UnlockRequests::<Test>::get(&2, &smart_contract)[0].amount >= native_amount);
+    })
+}
+
+#[test]
+fn stake_and_unstake_claim_pending_rewards() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        let amount = 1000;
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Pool has unclaimed rewards from the past period, but compound hasn't been called
+        advance_to_next_period();
+
+        // Rewards are claimed before the deposit, so the depositor doesn't get a share of them
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone(),
+            amount
+        ));
+        let pool = Pools::<Test>::get(&smart_contract).unwrap();
+        let rewards = pool.total_stake - 2 * amount;
+        assert!(!rewards.is_zero());
+        assert_eq!(
+            Assets::balance(asset_id, 3),
+            amount * (amount + 1) / (amount + rewards + 1)
+        );
+
+        // Everything is locked & staked in the new period
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&pool_account);
+        assert_eq!(ledger.active_locked_amount(), pool.total_stake);
+        assert_eq!(ledger.staked_amount(current_period), pool.total_stake);
+
+        // Unstaking works as well
+        assert_ok!(LiquidStaking::unstake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount / 2
+        ));
+    })
+}
+
+#[test]
+fn rewards_claimed_by_third_party_are_added_to_total_stake() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        let amount = 1000;
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Third party claims all of the pool rewards directly in dApp staking
+        advance_to_next_period();
+        while DappStaking::claim_staker_rewards_for(RuntimeOrigin::signed(3), pool_account).is_ok()
+        {
+        }
+        assert_ok!(DappStaking::claim_bonus_reward_for(
+            RuntimeOrigin::signed(3),
+            pool_account,
+            smart_contract.clone()
+        ));
+        let total_balance = Balances::total_balance(&pool_account);
+        assert!(total_balance > amount);
+
+        // Rewards are still added to the total stake, and locked & staked
+        assert_ok!(LiquidStaking::compound(
+            RuntimeOrigin::signed(4),
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Compounded {
+            smart_contract: smart_contract.clone(),
+            rewards: total_balance - amount,
+            locked: total_balance - amount,
+            staked: total_balance,
+        }));
+        assert_eq!(
+            Pools::<Test>::get(&smart_contract).unwrap().total_stake,
+            total_balance
+        );
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&pool_account);
+        assert_eq!(ledger.active_locked_amount(), total_balance);
+        assert_eq!(ledger.staked_amount(current_period), total_balance);
+    })
+}
+
+#[test]
+fn donation_is_added_to_total_stake() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        // Attacker is the first depositor, and tries to inflate the exchange rate via a direct transfer
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            MINIMUM_LOCK_AMOUNT
+        ));
+        let donation = 10_000;
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(2),
+            pool_account,
+            donation
+        ));
+
+        // Donation is treated as a reward, so deposits which would be minted no derivative asset are rejected
+        assert_noop!(
+            LiquidStaking::stake(RuntimeOrigin::signed(3), smart_contract.clone(), 100),
+            Error::<Test>::AmountTooSmall
+        );
+
+        // Donation is locked & staked on the next interaction with the pool
+        assert_ok!(LiquidStaking::compound(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone()
+        ));
+        let total_stake = MINIMUM_LOCK_AMOUNT + donation;
+        assert_eq!(
+            Pools::<Test>::get(&smart_contract).unwrap().total_stake,
+            total_stake
+        );
+        assert_eq!(
+            Ledger::<Test>::get(&pool_account).active_locked_amount(),
+            total_stake
+        );
+    })
+}
+
+#[test]
+fn stake_reports_idle_stake() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+        register_and_create_pool(1, &smart_contract, asset_id);
+        let pool_account = LiquidStaking::pool_account(asset_id);
+
+        // First deposit is below the minimum locked amount, so it cannot be locked & staked
+        let amount = MINIMUM_LOCK_AMOUNT - 1;
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            amount
+        ));
+        assert_eq!(Assets::balance(asset_id, 2), amount);
+        assert!(Ledger::<Test>::get(&pool_account).is_empty());
+        System::assert_last_event(RuntimeEvent::LiquidStaking(Event::StakeIdle {
+            smart_contract: smart_contract.clone(),
+            amount,
+        }));
+
+        // Once the minimum is reached, everything is locked & staked
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone(),
+            amount
+        ));
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        assert_eq!(
+            Ledger::<Test>::get(&pool_account).staked_amount(current_period),
+            2 * amount
+        );
+        assert!(matches!(
+            liquid_staking_events().last(),
+            Some(Event::Staked { account: 3, .. })
+        ));
+    })
+}
+
+#[test]
+fn compound_without_rewards_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::Wasm(100);
+        let asset_id = 7;
+
+        assert_noop!(
+            LiquidStaking::compound(RuntimeOrigin::signed(3), smart_contract.clone()),
+            Error::<Test>::PoolNotFound
+        );
+
+        register_and_create_pool(1, &smart_contract, asset_id);
+        assert_ok!(LiquidStaking::stake(
+            RuntimeOrigin::signed(2),
+            smart_contract.clone(),
+            100
+        ));
+
+        // Nothing to claim, lock or stake
+        assert_ok!(LiquidStaking::compound(
+            RuntimeOrigin::signed(3),
+            smart_contract.clone()
+        ));
+        assert_eq!(
+            liquid_staking_events().last(),
+            Some(&Event::Compounded {
+                smart_contract,
+                rewards: 0,
+                locked: 0,
+                staked: 0,
+            })
+        );
+    })
+}
@@ -0,0 +1,111 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_liquid_staking
+//!
+//! Values are composed from the `pallet_dapp_staking_v3` weights of the calls made on behalf of the pool,
+//! and must be replaced with the output of:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//! --pallet=pallet_liquid_staking --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//! --output=./pallets/liquid-staking/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_liquid_staking.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn stake() -> Weight;
+	fn unstake() -> Weight;
+	fn withdraw_unlocked(x: u32, ) -> Weight;
+	fn compound() -> Weight;
+}
+
+/// Weights for pallet_liquid_staking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_pool() -> Weight {
+		Weight::from_parts(38_000_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn stake() -> Weight {
+		Weight::from_parts(305_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	fn unstake() -> Weight {
+		Weight::from_parts(295_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// The range of component `x` is `[1, 8]`.
+	fn withdraw_unlocked(x: u32, ) -> Weight {
+		Weight::from_parts(82_000_000, 4764)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2509).saturating_mul(x.into()))
+	}
+	fn compound() -> Weight {
+		Weight::from_parts(245_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_pool() -> Weight {
+		Weight::from_parts(38_000_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn stake() -> Weight {
+		Weight::from_parts(305_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	fn unstake() -> Weight {
+		Weight::from_parts(295_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// The range of component `x` is `[1, 8]`.
+	fn withdraw_unlocked(x: u32, ) -> Weight {
+		Weight::from_parts(82_000_000, 4764)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2509).saturating_mul(x.into()))
+	}
+	fn compound() -> Weight {
+		Weight::from_parts(245_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::oracle::CurrencyId;
+use sp_std::vec::Vec;
+
+pub use pallet_price_aggregator::{AggregatedValue, AggregationState};
+
+sp_api::decl_runtime_apis! {
+
+    /// Price Aggregator Api.
+    ///
+    /// Used to inspect the internal state of the price aggregator, e.g. the moving average inputs.
+    pub trait PriceAggregatorApi {
+
+        /// Get the currencies whose price is tracked, starting with the native currency.
+        fn tracked_currencies() -> Vec<CurrencyId>;
+
+        /// Get the aggregation state of the specified currency, or `None` if it isn't tracked.
+        fn aggregation_state(currency_id: CurrencyId) -> Option<AggregationState>;
+    }
+}
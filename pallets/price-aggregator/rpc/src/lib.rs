@@ -0,0 +1,116 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Price aggregator JSON-RPC interface.
+//!
+//! Exposes the `priceAggregator_*` methods, which are thin wrappers around the `PriceAggregatorApi` runtime API.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use astar_primitives::oracle::CurrencyId;
+pub use price_aggregator_runtime_api::{
+    AggregatedValue, AggregationState, PriceAggregatorApi as PriceAggregatorRuntimeApi,
+};
+
+#[rpc(server)]
+pub trait PriceAggregatorApi<BlockHash> {
+    /// Currencies whose price is tracked, starting with the native currency.
+    #[method(name = "priceAggregator_trackedCurrencies")]
+    fn tracked_currencies(&self, at: Option<BlockHash>) -> RpcResult<Vec<CurrencyId>>;
+
+    /// Moving average, circular buffer values & the ongoing aggregation period state of the specified currency.
+    #[method(name = "priceAggregator_aggregationState")]
+    fn aggregation_state(
+        &self,
+        currency_id: CurrencyId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AggregationState>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to inspect the price aggregator state.
+pub struct PriceAggregator<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> PriceAggregator<C, Block> {
+    /// Create new `PriceAggregator` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error.
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        message.to_string(),
+        Some(format!("{:?}", e)),
+    )
+}
+
+impl<C, Block> PriceAggregatorApiServer<<Block as BlockT>::Hash> for PriceAggregator<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: PriceAggregatorRuntimeApi<Block>,
+{
+    fn tracked_currencies(&self, at: Option<Block::Hash>) -> RpcResult<Vec<CurrencyId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .tracked_currencies(at)
+            .map_err(|e| runtime_error("Unable to query tracked currencies.", e))
+    }
+
+    fn aggregation_state(
+        &self,
+        currency_id: CurrencyId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<AggregationState>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .aggregation_state(at, currency_id)
+            .map_err(|e| runtime_error("Unable to query aggregation state.", e))
+    }
+}
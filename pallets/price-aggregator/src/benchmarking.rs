@@ -27,32 +27,46 @@ mod benchmarks {
 
     #[benchmark]
     fn process_block_aggregated_values() {
-        // Fill up the current block buffer with some values
+        let currency_id = <T as Config>::NativeCurrencyId::get();
+
+        // Fill up the current block buffer with some values, each submitted by a distinct account
         let size_limit = <T as Config>::MaxValuesPerBlock::get();
         let mut result = BoundedVec::<CurrencyAmount, <T as Config>::MaxValuesPerBlock>::default();
+        let mut submitters =
+            BoundedVec::<T::AccountId, <T as Config>::MaxValuesPerBlock>::default();
         for x in 1..=size_limit {
             let value = CurrencyAmount::from_rational(x as u128 + 3, 10);
             result
                 .try_push(value)
                 .expect("Must succeed since we are iterating to the limit");
+            submitters
+                .try_push(account("submitter", x, 0))
+                .expect("Must succeed since we are iterating to the limit");
         }
-        CurrentBlockValues::<T>::put(result);
+        CurrentBlockValues::<T>::insert(currency_id, result);
+        CurrentBlockSubmitters::<T>::insert(currency_id, submitters);
+
+        // Worst case is when the price feed is restored from being stale
+        PriceFeedStatus::<T>::mutate(currency_id, |status| status.is_stale = true);
 
         #[block]
         {
-            Pallet::<T>::process_block_aggregated_values();
+            Pallet::<T>::process_block_aggregated_values(currency_id);
         }
 
         assert!(
-            CurrentBlockValues::<T>::get().is_empty(),
+            CurrentBlockValues::<T>::get(currency_id).is_empty(),
             "Should have been cleaned up."
         );
+        assert!(!PriceFeedStatus::<T>::get(currency_id).is_stale);
     }
 
     #[benchmark]
     fn process_intermediate_aggregated_values() {
+        let currency_id = <T as Config>::NativeCurrencyId::get();
+
         // 1. Fill up the current aggregator and make it trigger on the current block end
-        IntermediateValueAggregator::<T>::mutate(|a| {
+        IntermediateValueAggregator::<T>::mutate(currency_id, |a| {
             a.limit_block = frame_system::Pallet::<T>::block_number().saturated_into();
 
             a.total = CurrencyAmount::from_rational(1234, 10);
@@ -61,27 +75,72 @@ mod benchmarks {
 
         // 2. Fill up the circular buffer with some values
         let buffer_length = <T as Config>::CircularBufferLength::get();
-        ValuesCircularBuffer::<T>::mutate(|b| {
+        ValuesCircularBuffer::<T>::mutate(currency_id, |b| {
             for x in 1..=buffer_length {
-                b.add(CurrencyAmount::from_rational(x as u128 + 3, 10));
+                b.add(AggregatedValue::new(
+                    CurrencyAmount::from_rational(x as u128 + 3, 10),
+                    x,
+                ));
             }
         });
         assert_eq!(
-            ValuesCircularBuffer::<T>::get().buffer.len(),
+            ValuesCircularBuffer::<T>::get(currency_id).buffer.len(),
             buffer_length as usize,
             "Sanity check."
         );
 
         // 3. Prepare local variables
-        let buffer_snapshot = ValuesCircularBuffer::<T>::get();
+        let buffer_snapshot = ValuesCircularBuffer::<T>::get(currency_id);
         let current_block = frame_system::Pallet::<T>::block_number();
 
         #[block]
         {
-            Pallet::<T>::process_intermediate_aggregated_values(current_block);
+            Pallet::<T>::process_intermediate_aggregated_values(currency_id, current_block);
+        }
+
+        assert!(ValuesCircularBuffer::<T>::get(currency_id) != buffer_snapshot);
+    }
+
+    #[benchmark]
+    fn add_tracked_currency() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Worst case is when all but one of the tracked currency slots are taken
+        let max_tracked = <T as Config>::MaxTrackedCurrencies::get();
+        for x in 1..max_tracked {
+            TrackedCurrencies::<T>::try_append(T::BenchmarkHelper::get_supported_currency_id(x))
+                .expect("Must succeed since we are iterating to the limit");
         }
+        let currency_id = T::BenchmarkHelper::get_supported_currency_id(max_tracked);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, currency_id);
+
+        assert!(Pallet::<T>::is_tracked(&currency_id));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_tracked_currency() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Worst case is when the removed currency is the last one in the full list
+        let max_tracked = <T as Config>::MaxTrackedCurrencies::get();
+        for x in 1..=max_tracked {
+            TrackedCurrencies::<T>::try_append(T::BenchmarkHelper::get_supported_currency_id(x))
+                .expect("Must succeed since we are iterating to the limit");
+        }
+        let currency_id = T::BenchmarkHelper::get_supported_currency_id(max_tracked);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, currency_id);
+
+        assert!(!Pallet::<T>::is_tracked(&currency_id));
 
-        assert!(ValuesCircularBuffer::<T>::get() != buffer_snapshot);
+        Ok(())
     }
 
     impl_benchmark_test_suite!(
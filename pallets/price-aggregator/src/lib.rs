@@ -45,25 +45,56 @@
 //!
 //! The moving average is calculated from the circular buffer, and is used to provide the 'average' price of the native currency, over some time period.
 //! It's important to note that the moving average is not a 'real-time' value, but rather a 'lagging' indicator.
+//!
+//! Each value in the circular buffer also records the number of blocks which contributed to it.
+//! The strategy used to calculate the moving average is configurable, e.g. a simple moving average,
+//! a time-weighted average based on the number of covered blocks, an exponential moving average, or a trimmed mean.
+//! All strategies only consider the values currently held in the circular buffer.
+//!
+//! ### Data Feed Guards
+//!
+//! To limit the impact of a faulty data feeder, new values which deviate too much from the moving average are rejected.
+//! The moving average used for this check is calculated once, at the start of each block.
+//! This check is skipped while the price feed is stale, so the feed can recover from a large price move.
+//! In addition, values accumulated in a block are only processed if they were submitted by a minimum number of distinct submitters.
+//!
+//! In case no value has been successfully processed for a configurable number of blocks, the price feed is considered stale.
+//! While stale, the price provider returns the configured fallback price, if any, or the last moving average flagged as stale.
+//!
+//! ### Tracked Currencies
+//!
+//! The native currency price is always tracked. In addition, the manager origin can start tracking the price of any supported currency,
+//! e.g. a registered cross-chain asset. Each tracked currency is aggregated independently, using its own circular buffer.
+//!
+//! Prices of the tracked currencies are exposed via `PriceProvider::price_of`. Unlike the native currency,
+//! no price is provided for other currencies while their price feed is stale.
+//!
+//! ### Inspection
+//!
+//! The internal aggregation state of each tracked currency, i.e. the circular buffer entries, the moving average,
+//! the in-progress intermediate average and the next limit block, can be inspected via `aggregation_state`.
+//! It is exposed to clients through the `price-aggregator-runtime-api` & `price-aggregator-rpc` crates.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{pallet_prelude::*, DefaultNoBound};
+use frame_support::{pallet_prelude::*, traits::Contains, DefaultNoBound};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_arithmetic::{
-    fixed_point::FixedU128,
+    fixed_point::{FixedPointNumber, FixedU128},
     traits::{CheckedAdd, SaturatedConversion, Saturating, Zero},
+    PerThing, Perbill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use orml_traits::OnNewData;
 
 use astar_primitives::{
-    oracle::{CurrencyAmount, CurrencyId, PriceProvider},
+    oracle::{CurrencyAmount, CurrencyId, Price, PriceProvider},
     BlockNumber,
 };
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -85,6 +116,14 @@ pub trait ProcessBlockValues {
     fn process(values: &[CurrencyAmount]) -> Result<CurrencyAmount, &'static str>;
 }
 
+/// Helper trait for benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// Get a supported currency Id, unique for the given index.
+    /// Currency must be supported by the time this function returns.
+    fn get_supported_currency_id(index: u32) -> CurrencyId;
+}
+
 /// Used to calculate the simple average of the accumulated values.
 pub struct AverageBlockValue;
 impl ProcessBlockValues for AverageBlockValue {
@@ -179,6 +218,25 @@ impl ValueAggregator {
     }
 }
 
+/// Average value of a single aggregation period, stored in the circular buffer.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AggregatedValue {
+    /// Average value over the aggregation period.
+    #[codec(compact)]
+    pub(crate) value: CurrencyAmount,
+    /// Number of blocks which contributed to the average value.
+    #[codec(compact)]
+    pub(crate) blocks: u32,
+}
+
+impl AggregatedValue {
+    /// New aggregated value, covering the given number of blocks.
+    pub fn new(value: CurrencyAmount, blocks: u32) -> Self {
+        Self { value, blocks }
+    }
+}
+
 /// Used to store the aggregated intermediate values into a circular buffer.
 ///
 /// Inserts values sequentially into the buffer, until the buffer has been filled out.
@@ -196,8 +254,8 @@ impl ValueAggregator {
 )]
 #[scale_info(skip_type_params(L))]
 pub struct CircularBuffer<L: Get<u32>> {
-    /// Currency values store.
-    pub(crate) buffer: BoundedVec<CurrencyAmount, L>,
+    /// Aggregated values store.
+    pub(crate) buffer: BoundedVec<AggregatedValue, L>,
     /// Next index to write to.
     #[codec(compact)]
     pub(crate) head: u32,
@@ -205,7 +263,7 @@ pub struct CircularBuffer<L: Get<u32>> {
 
 impl<L: Get<u32>> CircularBuffer<L> {
     /// Adds a new value to the circular buffer, possibly overriding the oldest value if capacity is filled.
-    pub fn add(&mut self, value: CurrencyAmount) {
+    pub fn add(&mut self, value: AggregatedValue) {
         // This can never happen, parameters must ensure that.
         // But we still check it and log an error if it does.
         if self.head >= L::get() || self.head as usize > self.buffer.len() {
@@ -230,24 +288,175 @@ impl<L: Get<u32>> CircularBuffer<L> {
         self.head = self.head.saturating_add(1) % L::get();
     }
 
-    /// Returns the average of the accumulated values.
-    pub fn average(&self) -> CurrencyAmount {
-        if self.buffer.is_empty() {
+    /// Returns the stored values, ordered from the oldest to the newest one.
+    pub fn ordered_values(&self) -> Vec<AggregatedValue> {
+        // Head always points to the oldest value once the buffer has been filled out,
+        // and to the end of the buffer before that.
+        let head = (self.head as usize).min(self.buffer.len());
+        self.buffer[head..]
+            .iter()
+            .chain(self.buffer[..head].iter())
+            .copied()
+            .collect()
+    }
+
+    /// Returns the average of the accumulated values, calculated using the specified strategy.
+    pub fn average<A: CalculateMovingAverage>(&self) -> CurrencyAmount {
+        A::calculate(&self.ordered_values())
+    }
+}
+
+/// Trait for calculating the moving average from the values stored in the circular buffer.
+pub trait CalculateMovingAverage {
+    /// Calculate the moving average from the values, ordered from the oldest to the newest one.
+    ///
+    /// In case there are no values, zero is returned.
+    fn calculate(values: &[AggregatedValue]) -> CurrencyAmount;
+}
+
+/// Used to calculate the simple arithmetic mean of the values, all weighted equally.
+pub struct SimpleMovingAverage;
+impl CalculateMovingAverage for SimpleMovingAverage {
+    fn calculate(values: &[AggregatedValue]) -> CurrencyAmount {
+        if values.is_empty() {
+            return CurrencyAmount::zero();
+        }
+
+        let sum = values
+            .iter()
+            .fold(CurrencyAmount::zero(), |acc, aggregated| {
+                acc.saturating_add(aggregated.value)
+            });
+
+        sum.saturating_mul(FixedU128::from_rational(1, values.len() as u128))
+    }
+}
+
+/// Used to calculate the time-weighted average of the values, each weighted by the number of blocks it covers.
+pub struct TimeWeightedAverage;
+impl CalculateMovingAverage for TimeWeightedAverage {
+    fn calculate(values: &[AggregatedValue]) -> CurrencyAmount {
+        let total_blocks = values.iter().fold(0_u128, |acc, aggregated| {
+            acc.saturating_add(aggregated.blocks.into())
+        });
+        if total_blocks.is_zero() {
             return CurrencyAmount::zero();
         }
 
-        let sum = self
-            .buffer
+        let weighted_sum = values
             .iter()
-            .fold(CurrencyAmount::zero(), |acc, &value| {
-                acc.saturating_add(value)
+            .fold(CurrencyAmount::zero(), |acc, aggregated| {
+                acc.saturating_add(
+                    aggregated
+                        .value
+                        .saturating_mul(FixedU128::saturating_from_integer(aggregated.blocks)),
+                )
             });
 
-        // At this point, length of the buffer is guaranteed to be greater than zero.
-        sum.saturating_mul(FixedU128::from_rational(1, self.buffer.len() as u128))
+        weighted_sum.saturating_mul(FixedU128::from_rational(1, total_blocks))
     }
 }
 
+/// Used to calculate the exponential moving average of the values.
+///
+/// Each new value is weighted by `Alpha`, while the average of the older values is weighted by `1 - Alpha`.
+///
+/// The average isn't persisted, but recalculated over the circular buffer each time, seeded with the oldest value.
+/// Values which have been overwritten in the circular buffer no longer contribute to it,
+/// so this is a windowed exponential moving average, not a running one.
+pub struct ExponentialMovingAverage<Alpha>(PhantomData<Alpha>);
+impl<Alpha: Get<Perbill>> CalculateMovingAverage for ExponentialMovingAverage<Alpha> {
+    fn calculate(values: &[AggregatedValue]) -> CurrencyAmount {
+        let alpha = Alpha::get();
+        let complement = Perbill::one().saturating_sub(alpha);
+
+        let mut iter = values.iter();
+        let Some(first) = iter.next() else {
+            return CurrencyAmount::zero();
+        };
+
+        iter.fold(first.value, |average, aggregated| {
+            CurrencyAmount::from_inner(alpha * aggregated.value.into_inner()).saturating_add(
+                CurrencyAmount::from_inner(complement * average.into_inner()),
+            )
+        })
+    }
+}
+
+/// Used to calculate the arithmetic mean of the values, after discarding the outliers.
+///
+/// `TrimPortion` of the values is discarded from both the lower and the upper end. At least one value is always kept.
+pub struct TrimmedMeanAverage<TrimPortion>(PhantomData<TrimPortion>);
+impl<TrimPortion: Get<Perbill>> CalculateMovingAverage for TrimmedMeanAverage<TrimPortion> {
+    fn calculate(values: &[AggregatedValue]) -> CurrencyAmount {
+        if values.is_empty() {
+            return CurrencyAmount::zero();
+        }
+
+        let mut sorted_values = values.to_vec();
+        sorted_values.sort_unstable_by(|a, b| a.value.cmp(&b.value));
+
+        let len = sorted_values.len();
+        let trim = TrimPortion::get().mul_floor(len as u32) as usize;
+        let trim = trim.min(len.saturating_sub(1) / 2);
+
+        SimpleMovingAverage::calculate(&sorted_values[trim..len.saturating_sub(trim)])
+    }
+}
+
+/// Status of the price data feed, used to detect whether it has become stale.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub struct FeedStatus {
+    /// Block number at which the accumulated block values were last successfully processed.
+    #[codec(compact)]
+    pub(crate) last_update: BlockNumber,
+    /// `true` if the price feed is considered stale, `false` otherwise.
+    pub(crate) is_stale: bool,
+}
+
+/// Snapshot of the aggregation state of a single currency, used for inspection.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AggregationState {
+    /// Moving average, calculated using the configured strategy.
+    pub moving_average: CurrencyAmount,
+    /// Values stored in the circular buffer, ordered from the oldest to the newest one.
+    pub buffer: Vec<AggregatedValue>,
+    /// Average of the values aggregated so far in the ongoing aggregation period.
+    pub intermediate_average: CurrencyAmount,
+    /// Number of blocks which contributed to the intermediate average.
+    pub intermediate_count: u32,
+    /// Block number at which the ongoing aggregation period ends.
+    pub limit_block: BlockNumber,
+    /// Block number at which the price feed was last updated.
+    pub last_update: BlockNumber,
+    /// `true` if the price feed is considered stale, `false` otherwise.
+    pub is_stale: bool,
+}
+
+/// Checks whether the value deviates from the reference value by more than the allowed deviation.
+///
+/// Deviation is expressed as the portion of the larger of the two values, e.g. `50%` allows values
+/// between half and double the reference value. Zero reference value means there's nothing to compare against.
+pub fn exceeds_deviation(
+    value: CurrencyAmount,
+    reference: CurrencyAmount,
+    max_deviation: Perbill,
+) -> bool {
+    if reference.is_zero() {
+        return false;
+    }
+
+    let (larger, smaller) = if value > reference {
+        (value, reference)
+    } else {
+        (reference, value)
+    };
+    let allowed_deviation = CurrencyAmount::from_inner(max_deviation * larger.into_inner());
+
+    larger.saturating_sub(smaller) > allowed_deviation
+}
+
 const LOG_TARGET: &str = "price-aggregator";
 
 #[frame_support::pallet]
@@ -255,7 +464,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -280,11 +489,45 @@ pub mod pallet {
         #[pallet::constant]
         type CircularBufferLength: Get<u32>;
 
+        /// Used to calculate the moving average from the values stored in the circular buffer.
+        type CalculateMovingAverage: CalculateMovingAverage;
+
         /// Duration of aggregation period expressed in the number of blocks.
         /// During this time, currency values are aggregated, and are then used to calculate the average value.
         #[pallet::constant]
         type AggregationDuration: Get<BlockNumberFor<Self>>;
 
+        /// Maximum deviation of a new value from the current moving average, for it to be accepted.
+        /// Deviation is expressed as the portion of the larger of the two values.
+        #[pallet::constant]
+        type MaxDeviation: Get<Perbill>;
+
+        /// Minimum number of distinct submitters required for the block values to be processed.
+        #[pallet::constant]
+        type MinSubmitters: Get<u32>;
+
+        /// Number of blocks without any successfully processed block value, after which the price feed is considered stale.
+        #[pallet::constant]
+        type StalenessThreshold: Get<BlockNumberFor<Self>>;
+
+        /// Price returned by the price provider while the native currency price feed is stale.
+        /// If `None`, the last moving average is returned, flagged as stale.
+        type StalePriceFallback: Get<Option<Price>>;
+
+        /// Currencies, other than the native currency, which are allowed to be tracked.
+        type SupportedCurrencies: Contains<CurrencyId>;
+
+        /// Maximum number of tracked currencies, excluding the native currency.
+        #[pallet::constant]
+        type MaxTrackedCurrencies: Get<u32>;
+
+        /// Privileged origin allowed to add & remove tracked currencies.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Helper for benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -297,12 +540,26 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            ValuesCircularBuffer::<T>::put(CircularBuffer::<T::CircularBufferLength> {
-                buffer: self.circular_buffer.clone(),
-                head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
-            });
+            let native_currency_id = T::NativeCurrencyId::get();
+
+            // Genesis values are assumed to cover the entire aggregation period.
+            let blocks: u32 = T::AggregationDuration::get().saturated_into();
+            let buffer = BoundedVec::truncate_from(
+                self.circular_buffer
+                    .iter()
+                    .map(|value| AggregatedValue::new(*value, blocks))
+                    .collect(),
+            );
+
+            ValuesCircularBuffer::<T>::insert(
+                native_currency_id,
+                CircularBuffer::<T::CircularBufferLength> {
+                    buffer,
+                    head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
+                },
+            );
 
-            IntermediateValueAggregator::<T>::mutate(|aggregator| {
+            IntermediateValueAggregator::<T>::mutate(native_currency_id, |aggregator| {
                 aggregator.limit_block = T::AggregationDuration::get().saturated_into();
             });
         }
@@ -311,53 +568,153 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// New average native currency value has been calculated and pushed into the moving average buffer.
-        AverageAggregatedValue { value: CurrencyAmount },
+        /// New average currency value has been calculated and pushed into the moving average buffer.
+        AverageAggregatedValue {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+        },
+        /// Submitted value has been rejected since it deviates too much from the moving average.
+        ValueRejected {
+            currency_id: CurrencyId,
+            submitter: T::AccountId,
+            value: CurrencyAmount,
+            moving_average: CurrencyAmount,
+        },
+        /// Accumulated block values have been discarded due to an insufficient number of distinct submitters.
+        InsufficientSubmitters {
+            currency_id: CurrencyId,
+            submitters: u32,
+        },
+        /// Price feed has become stale, since no value has been processed since the specified block.
+        PriceFeedStale {
+            currency_id: CurrencyId,
+            last_update: BlockNumber,
+        },
+        /// Price feed is no longer stale.
+        PriceFeedRestored { currency_id: CurrencyId },
+        /// Currency price is now being tracked.
+        CurrencyTracked { currency_id: CurrencyId },
+        /// Currency price is no longer being tracked.
+        CurrencyUntracked { currency_id: CurrencyId },
     }
 
-    /// Storage for the accumulated native currency price in the current block.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Currency isn't supported, so its price cannot be tracked.
+        UnsupportedCurrency,
+        /// Currency price is already being tracked.
+        CurrencyAlreadyTracked,
+        /// Currency price isn't being tracked.
+        CurrencyNotTracked,
+        /// Maximum number of tracked currencies has been reached.
+        TooManyTrackedCurrencies,
+    }
+
+    /// Currencies whose price is tracked, in addition to the native currency.
+    #[pallet::storage]
+    pub type TrackedCurrencies<T: Config> =
+        StorageValue<_, BoundedVec<CurrencyId, T::MaxTrackedCurrencies>, ValueQuery>;
+
+    /// Storage for the accumulated currency price in the current block.
+    #[pallet::storage]
+    pub type CurrentBlockValues<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Distinct accounts which have submitted the accumulated currency price in the current block.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type CurrentBlockValues<T: Config> =
-        StorageValue<_, BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>, ValueQuery>;
+    pub type CurrentBlockSubmitters<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<T::AccountId, T::MaxValuesPerBlock>,
+        ValueQuery,
+    >;
 
     /// Used to store the aggregated processed block values during some time period.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type IntermediateValueAggregator<T: Config> = StorageValue<_, ValueAggregator, ValueQuery>;
+    pub type IntermediateValueAggregator<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, ValueAggregator, ValueQuery>;
 
     /// Used to store aggregated intermediate values for some time period.
     #[pallet::storage]
-    pub type ValuesCircularBuffer<T: Config> =
-        StorageValue<_, CircularBuffer<T::CircularBufferLength>, ValueQuery>;
+    pub type ValuesCircularBuffer<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        CircularBuffer<T::CircularBufferLength>,
+        ValueQuery,
+    >;
+
+    /// Status of the currency price data feed.
+    #[pallet::storage]
+    pub type PriceFeedStatus<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, FeedStatus, ValueQuery>;
+
+    /// Moving average of the currency at the start of the current block, used to check new values for deviation.
+    ///
+    /// Not set while the price feed is stale, since the deviation check is skipped then.
+    #[pallet::storage]
+    pub type BlockMovingAverage<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, CurrencyAmount, OptionQuery>;
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-            // Need to account for the reads and writes of:
-            // - CurrentBlockValues
-            // - IntermediateValueAggregator
-            //
-            // Also need to account for the weight of processing block accumulated values.
-            let mut total_weight = T::DbWeight::get()
-                .reads_writes(2, 2)
-                .saturating_add(T::WeightInfo::process_block_aggregated_values());
-
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                total_weight
-                    .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+            // Need to account for the read of TrackedCurrencies.
+            let mut total_weight = T::DbWeight::get().reads(1);
+
+            for currency_id in Self::tracked_currencies() {
+                // Need to account for the reads and writes of:
+                // - CurrentBlockValues
+                // - CurrentBlockSubmitters
+                // - IntermediateValueAggregator
+                // - PriceFeedStatus
+                // - ValuesCircularBuffer & BlockMovingAverage
+                //
+                // Also need to account for the weight of processing block accumulated values.
+                total_weight.saturating_accrue(
+                    T::DbWeight::get()
+                        .reads_writes(5, 5)
+                        .saturating_add(T::WeightInfo::process_block_aggregated_values()),
+                );
+
+                // Moving average is only calculated once per block, instead of on each new oracle value.
+                if PriceFeedStatus::<T>::get(currency_id).is_stale {
+                    BlockMovingAverage::<T>::remove(currency_id);
+                } else {
+                    BlockMovingAverage::<T>::insert(currency_id, Self::moving_average(currency_id));
+                }
+
+                if IntermediateValueAggregator::<T>::get(currency_id).limit_block
+                    <= now.saturated_into()
+                {
+                    total_weight
+                        .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+                }
             }
 
             total_weight
         }
 
         fn on_finalize(now: BlockNumberFor<T>) {
-            // 1. Process the accumulated native currency values in the current block.
-            Self::process_block_aggregated_values();
+            for currency_id in Self::tracked_currencies() {
+                // 1. Process the accumulated currency values in the current block.
+                Self::process_block_aggregated_values(currency_id);
+
+                // 2. Check if we need to push the average aggregated value to the storage.
+                if IntermediateValueAggregator::<T>::get(currency_id).limit_block
+                    <= now.saturated_into()
+                {
+                    Self::process_intermediate_aggregated_values(currency_id, now);
+                }
 
-            // 2. Check if we need to push the average aggregated value to the storage.
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                Self::process_intermediate_aggregated_values(now);
+                // 3. Check whether the price feed has become stale.
+                Self::check_price_feed_staleness(currency_id, now);
             }
         }
 
@@ -368,45 +725,183 @@ pub mod pallet {
         }
     }
 
+    #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Used to process the native currency values accumulated in the current block.
+        /// Start tracking the price of the specified currency.
+        ///
+        /// Currency must be supported, and the native currency is always tracked.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_tracked_currency())]
+        pub fn add_tracked_currency(
+            origin: OriginFor<T>,
+            currency_id: CurrencyId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                T::SupportedCurrencies::contains(&currency_id),
+                Error::<T>::UnsupportedCurrency
+            );
+            ensure!(
+                !Self::is_tracked(&currency_id),
+                Error::<T>::CurrencyAlreadyTracked
+            );
+
+            TrackedCurrencies::<T>::try_append(currency_id)
+                .map_err(|_| Error::<T>::TooManyTrackedCurrencies)?;
+
+            // Start the aggregation & the staleness tracking from the current block.
+            let now = frame_system::Pallet::<T>::block_number();
+            IntermediateValueAggregator::<T>::insert(
+                currency_id,
+                ValueAggregator::new(
+                    now.saturating_add(T::AggregationDuration::get())
+                        .saturated_into(),
+                ),
+            );
+            PriceFeedStatus::<T>::insert(
+                currency_id,
+                FeedStatus {
+                    last_update: now.saturated_into(),
+                    is_stale: false,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::CurrencyTracked { currency_id });
+
+            Ok(())
+        }
+
+        /// Stop tracking the price of the specified currency, removing all of its price data.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_tracked_currency())]
+        pub fn remove_tracked_currency(
+            origin: OriginFor<T>,
+            currency_id: CurrencyId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            TrackedCurrencies::<T>::try_mutate(|currencies| {
+                let idx = currencies
+                    .iter()
+                    .position(|tracked| *tracked == currency_id)
+                    .ok_or(Error::<T>::CurrencyNotTracked)?;
+                currencies.remove(idx);
+                Ok::<_, Error<T>>(())
+            })?;
+
+            CurrentBlockValues::<T>::remove(currency_id);
+            CurrentBlockSubmitters::<T>::remove(currency_id);
+            IntermediateValueAggregator::<T>::remove(currency_id);
+            ValuesCircularBuffer::<T>::remove(currency_id);
+            PriceFeedStatus::<T>::remove(currency_id);
+            BlockMovingAverage::<T>::remove(currency_id);
+
+            Self::deposit_event(Event::<T>::CurrencyUntracked { currency_id });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// All the currencies whose price is tracked, starting with the native currency.
+        pub fn tracked_currencies() -> impl Iterator<Item = CurrencyId> {
+            sp_std::iter::once(T::NativeCurrencyId::get())
+                .chain(TrackedCurrencies::<T>::get().into_iter())
+        }
+
+        /// `true` if the currency price is tracked, `false` otherwise.
+        pub fn is_tracked(currency_id: &CurrencyId) -> bool {
+            T::NativeCurrencyId::get() == *currency_id
+                || TrackedCurrencies::<T>::get().contains(currency_id)
+        }
+
+        /// Moving average of the currency, calculated using the configured strategy.
+        pub fn moving_average(currency_id: CurrencyId) -> CurrencyAmount {
+            ValuesCircularBuffer::<T>::get(currency_id).average::<T::CalculateMovingAverage>()
+        }
+
+        /// Aggregation state of the currency, or `None` if the currency isn't tracked.
+        pub fn aggregation_state(currency_id: CurrencyId) -> Option<AggregationState> {
+            if !Self::is_tracked(&currency_id) {
+                return None;
+            }
+
+            let circular_buffer = ValuesCircularBuffer::<T>::get(currency_id);
+            let intermediate_value = IntermediateValueAggregator::<T>::get(currency_id);
+            let status = PriceFeedStatus::<T>::get(currency_id);
+
+            Some(AggregationState {
+                moving_average: circular_buffer.average::<T::CalculateMovingAverage>(),
+                buffer: circular_buffer.ordered_values(),
+                intermediate_average: intermediate_value.average(),
+                intermediate_count: intermediate_value.count,
+                limit_block: intermediate_value.limit_block,
+                last_update: status.last_update,
+                is_stale: status.is_stale,
+            })
+        }
+
+        /// Used to process the currency values accumulated in the current block.
         ///
         /// Guarantees that the accumulated values are cleared after processing.
         /// In case of an error during processing, intermediate aggregated value is not updated.
-        pub(crate) fn process_block_aggregated_values() {
-            // 1. Take the accumulated block values, clearing the existing storage.
-            let accumulated_values = CurrentBlockValues::<T>::take();
+        pub(crate) fn process_block_aggregated_values(currency_id: CurrencyId) {
+            // 1. Take the accumulated block values & submitters, clearing the existing storage.
+            let accumulated_values = CurrentBlockValues::<T>::take(currency_id);
+            let submitters = CurrentBlockSubmitters::<T>::take(currency_id);
+
+            // Values must be submitted by enough distinct submitters, to limit the impact of a single faulty feeder.
+            let submitters_count = submitters.len() as u32;
+            if !accumulated_values.is_empty() && submitters_count < T::MinSubmitters::get() {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Discarding the accumulated {:?} values in the current block due to insufficient submitters. \
+                    Submitters: {:?}, Required: {:?}",
+                    currency_id,
+                    submitters_count,
+                    T::MinSubmitters::get()
+                );
+                Self::deposit_event(Event::InsufficientSubmitters {
+                    currency_id,
+                    submitters: submitters_count,
+                });
 
-            // 2. Attempt to process accumulated block values.
-            let processed_value = match T::ProcessBlockValues::process(
-                accumulated_values.as_slice(),
-            ) {
-                Ok(value) => value,
-                Err(message) => {
-                    log::trace!(
-                        target: LOG_TARGET,
-                        "Failed to process the accumulated native currency values in the current block. \
-                        Reason: {:?}",
-                        message
-                    );
+                return;
+            }
 
-                    // Nothing to do if we have no valid value to store.
-                    return;
-                }
-            };
+            // 2. Attempt to process accumulated block values.
+            let processed_value =
+                match T::ProcessBlockValues::process(accumulated_values.as_slice()) {
+                    Ok(value) => value,
+                    Err(message) => {
+                        log::trace!(
+                            target: LOG_TARGET,
+                            "Failed to process the accumulated {:?} values in the current block. \
+                            Reason: {:?}",
+                            currency_id,
+                            message
+                        );
+
+                        // Nothing to do if we have no valid value to store.
+                        return;
+                    }
+                };
 
             // 3. Attempt to store the processed value.
             // This operation is practically infallible, but we check the results for the additional safety.
-            let intermediate_value = IntermediateValueAggregator::<T>::get();
+            let intermediate_value = IntermediateValueAggregator::<T>::get(currency_id);
             match intermediate_value.try_add(processed_value) {
                 Ok(new_aggregator) => {
-                    IntermediateValueAggregator::<T>::put(new_aggregator);
+                    IntermediateValueAggregator::<T>::insert(currency_id, new_aggregator);
+                    Self::mark_price_feed_updated(currency_id);
                 }
                 Err(message) => {
                     log::error!(
                         target: LOG_TARGET,
-                        "Failed to add the processed native currency value to the intermediate storage. \
+                        "Failed to add the processed {:?} value to the intermediate storage. \
                         Reason: {:?}",
+                        currency_id,
                         message
                     );
                 }
@@ -414,50 +909,140 @@ pub mod pallet {
         }
 
         /// Used to process the intermediate aggregated values, and push them to the moving average storage.
-        pub(crate) fn process_intermediate_aggregated_values(now: BlockNumberFor<T>) {
-            // 1. Get the average value from the intermediate aggregator.
-            let average_value = IntermediateValueAggregator::<T>::get().average();
+        pub(crate) fn process_intermediate_aggregated_values(
+            currency_id: CurrencyId,
+            now: BlockNumberFor<T>,
+        ) {
+            // 1. Get the average value & the number of covered blocks from the intermediate aggregator.
+            let intermediate_value = IntermediateValueAggregator::<T>::get(currency_id);
+            let average_value = intermediate_value.average();
 
             // 2. Reset the aggregator back to zero, and set the new limit block.
-            IntermediateValueAggregator::<T>::put(ValueAggregator::new(
-                now.saturating_add(T::AggregationDuration::get())
-                    .saturated_into(),
-            ));
+            IntermediateValueAggregator::<T>::insert(
+                currency_id,
+                ValueAggregator::new(
+                    now.saturating_add(T::AggregationDuration::get())
+                        .saturated_into(),
+                ),
+            );
 
             // 3. In case aggregated value equals 0, it means something has gone wrong since it's extremely unlikely
             // that price goes to absolute zero. The much more likely case is that there's a problem with the oracle data feed.
             if average_value.is_zero() {
                 log::error!(
                     target: LOG_TARGET,
-                    "The average aggregated price equals zero, which most likely means that oracle data feed is faulty. \
-                    Not pushing the 'zero' value to the moving average storage."
+                    "The average aggregated {:?} price equals zero, which most likely means that oracle data feed is faulty. \
+                    Not pushing the 'zero' value to the moving average storage.",
+                    currency_id
                 );
                 return;
             }
 
             // 4. Push the 'valid' average aggregated value to the circular buffer.
-            ValuesCircularBuffer::<T>::mutate(|buffer| buffer.add(average_value));
+            ValuesCircularBuffer::<T>::mutate(currency_id, |buffer| {
+                buffer.add(AggregatedValue::new(
+                    average_value,
+                    intermediate_value.count,
+                ))
+            });
             Self::deposit_event(Event::AverageAggregatedValue {
+                currency_id,
                 value: average_value,
             });
         }
+
+        /// Records that the price feed has just been updated, restoring it in case it was stale.
+        fn mark_price_feed_updated(currency_id: CurrencyId) {
+            let was_stale = PriceFeedStatus::<T>::get(currency_id).is_stale;
+            PriceFeedStatus::<T>::insert(
+                currency_id,
+                FeedStatus {
+                    last_update: frame_system::Pallet::<T>::block_number().saturated_into(),
+                    is_stale: false,
+                },
+            );
+
+            if was_stale {
+                Self::deposit_event(Event::PriceFeedRestored { currency_id });
+            }
+        }
+
+        /// Marks the price feed as stale in case it hasn't been updated for longer than the staleness threshold.
+        pub(crate) fn check_price_feed_staleness(currency_id: CurrencyId, now: BlockNumberFor<T>) {
+            let mut status = PriceFeedStatus::<T>::get(currency_id);
+            if status.is_stale {
+                return;
+            }
+
+            let threshold: BlockNumber = T::StalenessThreshold::get().saturated_into();
+            let now: BlockNumber = now.saturated_into();
+            if now.saturating_sub(status.last_update) > threshold {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "{:?} price feed is stale, last update was at block {:?}.",
+                    currency_id,
+                    status.last_update
+                );
+
+                status.is_stale = true;
+                PriceFeedStatus::<T>::insert(currency_id, status);
+                Self::deposit_event(Event::PriceFeedStale {
+                    currency_id,
+                    last_update: status.last_update,
+                });
+            }
+        }
     }
 
     // Make this pallet an 'observer' ('listener') of the new oracle data feed.
     impl<T: Config> OnNewData<T::AccountId, CurrencyId, CurrencyAmount> for Pallet<T> {
         fn on_new_data(who: &T::AccountId, key: &CurrencyId, value: &CurrencyAmount) {
-            // Ignore any currency that is not native currency.
-            if T::NativeCurrencyId::get() != *key {
+            // Ignore any currency that is not tracked.
+            if !Self::is_tracked(key) {
                 return;
             }
 
-            CurrentBlockValues::<T>::mutate(|v| match v.try_push(*value) {
-                Ok(()) => {}
+            // Reject values which deviate too much from the moving average, most likely submitted by a faulty feeder.
+            // Moving average is cached at the start of the block, to keep the cost of each oracle submission low.
+            //
+            // The check is skipped while the price feed is stale, otherwise a large but genuine price move
+            // would get all new values rejected, locking the feed permanently.
+            if let Some(moving_average) = BlockMovingAverage::<T>::get(key) {
+                if exceeds_deviation(*value, moving_average, T::MaxDeviation::get()) {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Rejecting {:?} value {:?} submitted by {:?}, since it deviates too much from the moving average {:?}.",
+                        key,
+                        value,
+                        who,
+                        moving_average
+                    );
+                    Self::deposit_event(Event::ValueRejected {
+                        currency_id: *key,
+                        submitter: who.clone(),
+                        value: *value,
+                        moving_average,
+                    });
+
+                    return;
+                }
+            }
+
+            CurrentBlockValues::<T>::mutate(key, |v| match v.try_push(*value) {
+                Ok(()) => {
+                    CurrentBlockSubmitters::<T>::mutate(key, |submitters| {
+                        if !submitters.contains(who) {
+                            // Can never exceed the capacity since number of distinct submitters is limited by the number of values.
+                            let _ignorable = submitters.try_push(who.clone());
+                        }
+                    });
+                }
                 Err(_) => {
                     log::error!(
                     target: LOG_TARGET,
-                        "Failed to push native currency value into the ongoing block due to exceeded capacity. \
+                        "Failed to push {:?} value into the ongoing block due to exceeded capacity. \
                         Value was submitted by: {:?}",
+                        key,
                         who
                     );
                 }
@@ -465,12 +1050,36 @@ pub mod pallet {
         }
     }
 
-    // Make this pallet a `price provider` for the native currency.
+    // Make this pallet a `price provider` for the native currency, as well as for the other tracked currencies.
     //
-    // For this particular implementation, a simple moving average is used to calculate the average price.
+    // Average price is the moving average, calculated using the configured `T::CalculateMovingAverage` strategy.
     impl<T: Config> PriceProvider for Pallet<T> {
         fn average_price() -> FixedU128 {
-            ValuesCircularBuffer::<T>::get().average()
+            if Self::is_price_stale() {
+                if let Some(fallback_price) = T::StalePriceFallback::get() {
+                    return fallback_price;
+                }
+            }
+
+            Self::moving_average(T::NativeCurrencyId::get())
+        }
+
+        fn is_price_stale() -> bool {
+            PriceFeedStatus::<T>::get(T::NativeCurrencyId::get()).is_stale
+        }
+
+        fn price_of(currency_id: CurrencyId) -> Option<Price> {
+            if T::NativeCurrencyId::get() == currency_id {
+                return Some(Self::average_price());
+            }
+
+            // Stale or missing prices of other currencies are never provided.
+            if !Self::is_tracked(&currency_id) || PriceFeedStatus::<T>::get(currency_id).is_stale {
+                return None;
+            }
+
+            let average_price = Self::moving_average(currency_id);
+            (!average_price.is_zero()).then_some(average_price)
         }
     }
 }
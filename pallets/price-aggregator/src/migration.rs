@@ -0,0 +1,181 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{storage_alias, traits::UncheckedOnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Exports for versioned migration `type`s for this pallet.
+pub mod versioned_migrations {
+    use super::*;
+
+    /// Migration V1 to V3 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 1.
+    pub type V1ToV3<T> = frame_support::migrations::VersionedMigration<
+        1,
+        3,
+        v3::VersionMigrateV1ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Move the native currency price data from single values into the per-currency maps,
+/// and translate circular buffer values to include the number of covered blocks.
+mod v3 {
+    use super::*;
+
+    /// v1 type for [`crate::CircularBuffer`]
+    #[derive(Encode, Decode, RuntimeDebugNoBound, PartialEqNoBound, DefaultNoBound)]
+    pub struct CircularBuffer<L: Get<u32>> {
+        pub buffer: BoundedVec<CurrencyAmount, L>,
+        #[codec(compact)]
+        pub head: u32,
+    }
+
+    /// v1 type for [`crate::CurrentBlockValues`]
+    #[storage_alias]
+    pub type CurrentBlockValues<T: Config> = StorageValue<
+        Pallet<T>,
+        BoundedVec<CurrencyAmount, <T as Config>::MaxValuesPerBlock>,
+        OptionQuery,
+    >;
+
+    /// v1 type for [`crate::CurrentBlockSubmitters`]
+    #[storage_alias]
+    pub type CurrentBlockSubmitters<T: Config> = StorageValue<
+        Pallet<T>,
+        BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxValuesPerBlock>,
+        OptionQuery,
+    >;
+
+    /// v1 type for [`crate::IntermediateValueAggregator`]
+    #[storage_alias]
+    pub type IntermediateValueAggregator<T: Config> =
+        StorageValue<Pallet<T>, ValueAggregator, OptionQuery>;
+
+    /// v1 type for [`crate::ValuesCircularBuffer`]
+    #[storage_alias]
+    pub type ValuesCircularBuffer<T: Config> =
+        StorageValue<Pallet<T>, CircularBuffer<<T as Config>::CircularBufferLength>, OptionQuery>;
+
+    pub struct VersionMigrateV1ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV1ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let native_currency_id = T::NativeCurrencyId::get();
+
+            if let Some(values) = CurrentBlockValues::<T>::take() {
+                crate::CurrentBlockValues::<T>::insert(native_currency_id, values);
+            }
+            if let Some(submitters) = CurrentBlockSubmitters::<T>::take() {
+                crate::CurrentBlockSubmitters::<T>::insert(native_currency_id, submitters);
+            }
+            if let Some(aggregator) = IntermediateValueAggregator::<T>::take() {
+                crate::IntermediateValueAggregator::<T>::insert(native_currency_id, aggregator);
+            }
+            if let Some(old_buffer) = ValuesCircularBuffer::<T>::take() {
+                // Existing values are assumed to cover the entire aggregation period.
+                let blocks: u32 = T::AggregationDuration::get().saturated_into();
+                crate::ValuesCircularBuffer::<T>::insert(
+                    native_currency_id,
+                    crate::CircularBuffer {
+                        buffer: BoundedVec::truncate_from(
+                            old_buffer
+                                .buffer
+                                .into_iter()
+                                .map(|value| AggregatedValue::new(value, blocks))
+                                .collect(),
+                        ),
+                        head: old_buffer.head,
+                    },
+                );
+            }
+
+            // Price feed status didn't exist before, so staleness tracking starts from the current block.
+            // Otherwise, the feed would be considered stale right away.
+            crate::PriceFeedStatus::<T>::insert(
+                native_currency_id,
+                FeedStatus {
+                    last_update: frame_system::Pallet::<T>::block_number().saturated_into(),
+                    is_stale: false,
+                },
+            );
+
+            T::DbWeight::get().reads_writes(5, 9)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let old_buffer = ValuesCircularBuffer::<T>::get().unwrap_or_default();
+            let old_aggregator = IntermediateValueAggregator::<T>::get().unwrap_or_default();
+            Ok((old_buffer, old_aggregator).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (old_buffer, old_aggregator) = <(
+                CircularBuffer<T::CircularBufferLength>,
+                ValueAggregator,
+            )>::decode(&mut &data[..])
+            .map_err(|_| {
+                TryRuntimeError::Other(
+                    "price-aggregator::migration::v3: Failed to decode old v1 storage values",
+                )
+            })?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 3,
+                "price-aggregator::migration::v3: Wrong storage version."
+            );
+
+            let native_currency_id = T::NativeCurrencyId::get();
+            let new_buffer = crate::ValuesCircularBuffer::<T>::get(native_currency_id);
+            ensure!(
+                new_buffer.head == old_buffer.head
+                    && new_buffer
+                        .buffer
+                        .iter()
+                        .map(|aggregated| aggregated.value)
+                        .eq(old_buffer.buffer.into_iter()),
+                "price-aggregator::migration::v3: Circular buffer values have changed."
+            );
+            ensure!(
+                crate::IntermediateValueAggregator::<T>::get(native_currency_id) == old_aggregator,
+                "price-aggregator::migration::v3: Intermediate value aggregator has changed."
+            );
+            ensure!(
+                ValuesCircularBuffer::<T>::get().is_none()
+                    && IntermediateValueAggregator::<T>::get().is_none(),
+                "price-aggregator::migration::v3: Old storage values must be removed."
+            );
+            ensure!(
+                crate::PriceFeedStatus::<T>::get(native_currency_id).last_update
+                    == frame_system::Pallet::<T>::block_number().saturated_into::<BlockNumber>(),
+                "price-aggregator::migration::v3: Price feed status must start from the current block."
+            );
+
+            Ok(())
+        }
+    }
+}
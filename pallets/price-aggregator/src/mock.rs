@@ -17,22 +17,27 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor, IntermediateValueAggregator,
+    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor,
+    IntermediateValueAggregator, SimpleMovingAverage,
 };
 
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU128, ConstU32, Hooks},
+    traits::{ConstU128, ConstU32, Contains, Hooks},
     weights::Weight,
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Perbill,
 };
 
-use astar_primitives::{oracle::CurrencyId, Balance, BlockNumber};
+use astar_primitives::{
+    oracle::{CurrencyId, Price},
+    Balance, BlockNumber,
+};
 type AccountId = u64;
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
@@ -94,6 +99,27 @@ impl pallet_balances::Config for Test {
 parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
     pub const AggregationDuration: BlockNumberFor<Test> = 16;
+    pub static MaxDeviation: Perbill = Perbill::one();
+    pub static MinSubmitters: u32 = 1;
+    pub const StalenessThreshold: BlockNumberFor<Test> = 8;
+    pub static StalePriceFallback: Option<Price> = None;
+}
+
+/// Only the cross-chain assets are supported, in addition to the native currency.
+pub struct SupportedXcAssets;
+impl Contains<CurrencyId> for SupportedXcAssets {
+    fn contains(currency_id: &CurrencyId) -> bool {
+        matches!(currency_id, CurrencyId::XcAsset(_))
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper for BenchmarkHelper {
+    fn get_supported_currency_id(index: u32) -> CurrencyId {
+        CurrencyId::XcAsset(index.into())
+    }
 }
 
 impl pallet_price_aggregator::Config for Test {
@@ -103,7 +129,17 @@ impl pallet_price_aggregator::Config for Test {
     type ProcessBlockValues = AverageBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
     type CircularBufferLength = ConstU32<7>;
+    type CalculateMovingAverage = SimpleMovingAverage;
     type AggregationDuration = AggregationDuration;
+    type MaxDeviation = MaxDeviation;
+    type MinSubmitters = MinSubmitters;
+    type StalenessThreshold = StalenessThreshold;
+    type StalePriceFallback = StalePriceFallback;
+    type SupportedCurrencies = SupportedXcAssets;
+    type MaxTrackedCurrencies = ConstU32<2>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
     type WeightInfo = ();
 }
 
@@ -125,7 +161,7 @@ impl ExtBuilder {
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
             // 1. Set the initial limit block for the intermediate value aggregator
-            IntermediateValueAggregator::<Test>::mutate(|v| {
+            IntermediateValueAggregator::<Test>::mutate(NativeCurrencyId::get(), |v| {
                 v.limit_block =
                     <Test as pallet_price_aggregator::Config>::AggregationDuration::get() + 1
             });
@@ -18,21 +18,23 @@
 
 use crate::mock::*;
 use crate::{
-    pallet::Config, AverageBlockValue, CircularBuffer, CurrentBlockValues, Event,
-    IntermediateValueAggregator, MedianBlockValue, ProcessBlockValues, ValueAggregator,
-    ValuesCircularBuffer,
+    exceeds_deviation, pallet::Config, AggregatedValue, AverageBlockValue, BlockMovingAverage,
+    CalculateMovingAverage, CircularBuffer, CurrentBlockSubmitters, CurrentBlockValues, Error,
+    Event, ExponentialMovingAverage, FeedStatus, IntermediateValueAggregator, MedianBlockValue,
+    PriceFeedStatus, ProcessBlockValues, SimpleMovingAverage, TimeWeightedAverage,
+    TrackedCurrencies, TrimmedMeanAverage, ValueAggregator, ValuesCircularBuffer,
 };
 
-use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
+use astar_primitives::oracle::{CurrencyAmount, CurrencyId, PriceProvider};
 
 use orml_traits::OnNewData;
 
 use frame_support::{
-    assert_storage_noop,
+    assert_noop, assert_ok, assert_storage_noop,
     traits::{Get, Hooks},
     BoundedVec,
 };
-use sp_runtime::{traits::Zero, Saturating};
+use sp_runtime::{traits::Zero, DispatchError, Perbill, Saturating};
 
 pub use num_traits::Bounded;
 
@@ -219,26 +221,29 @@ fn circular_buffer_basic_checks() {
     assert!(circular_buffer.head.is_zero());
 
     // 2. Add a value, verify state is as expected
-    let amount_1 = CurrencyAmount::from_rational(19, 10);
+    let amount_1 = AggregatedValue::new(CurrencyAmount::from_rational(19, 10), 1);
     let mut expected_buffer = vec![amount_1];
     circular_buffer.add(amount_1);
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert_eq!(circular_buffer.head, 1);
-    assert_eq!(circular_buffer.average(), amount_1);
+    assert_eq!(
+        circular_buffer.average::<SimpleMovingAverage>(),
+        amount_1.value
+    );
 
     // 3. Add another value, verify state is as expected
-    let amount_2 = CurrencyAmount::from_rational(7, 10);
+    let amount_2 = AggregatedValue::new(CurrencyAmount::from_rational(7, 10), 1);
     circular_buffer.add(amount_2);
     expected_buffer.push(amount_2);
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert_eq!(circular_buffer.head, 2);
     assert_eq!(
-        circular_buffer.average(),
+        circular_buffer.average::<SimpleMovingAverage>(),
         CurrencyAmount::from_rational(13, 10)
     );
 
     // 4. Fill up the buffer, verify state is as expected
-    let amount_3 = CurrencyAmount::from_rational(27, 10);
+    let amount_3 = AggregatedValue::new(CurrencyAmount::from_rational(27, 10), 1);
     for _ in 2..BUFFER_SIZE {
         circular_buffer.add(amount_3);
         expected_buffer.push(amount_3);
@@ -247,7 +252,7 @@ fn circular_buffer_basic_checks() {
     assert!(circular_buffer.head.is_zero());
 
     // 5. Add another value, verify 0-th element is replaced
-    let amount_4 = CurrencyAmount::from_rational(9, 10);
+    let amount_4 = AggregatedValue::new(CurrencyAmount::from_rational(9, 10), 1);
     circular_buffer.add(amount_4);
     expected_buffer[0] = amount_4;
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
@@ -259,7 +264,10 @@ fn circular_buffer_basic_checks() {
         let init_head = circular_buffer.head;
 
         // Generate a new amount
-        let amount = amount_3 * CurrencyAmount::from_rational(x as u128 + 1, 1);
+        let amount = AggregatedValue::new(
+            amount_3.value * CurrencyAmount::from_rational(x as u128 + 1, 1),
+            1,
+        );
 
         assert!(circular_buffer.buffer[init_head as usize] != amount);
         circular_buffer.add(amount);
@@ -280,7 +288,7 @@ fn circular_buffer_inconsistency_safeguard_checks() {
     }
 
     // 1. Check that if head is ahead of length, operation does nothing
-    let amount = CurrencyAmount::from_rational(15, 100);
+    let amount = AggregatedValue::new(CurrencyAmount::from_rational(15, 100), 1);
     let mut inconsistent_buffer = CircularBuffer::<BufferSize> {
         buffer: Default::default(),
         head: 1,
@@ -303,12 +311,198 @@ fn circular_buffer_inconsistency_safeguard_checks() {
     assert_eq!(inconsistent_buffer.head, BUFFER_SIZE);
 }
 
+#[test]
+fn circular_buffer_ordered_values_works() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 3;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+
+    let value = |x: u128| AggregatedValue::new(CurrencyAmount::from_rational(x, 10), 1);
+
+    // 1. Empty buffer has no values
+    let mut circular_buffer = CircularBuffer::<BufferSize>::default();
+    assert!(circular_buffer.ordered_values().is_empty());
+
+    // 2. Values are ordered as inserted until the buffer is filled out
+    circular_buffer.add(value(1));
+    circular_buffer.add(value(2));
+    assert_eq!(circular_buffer.ordered_values(), vec![value(1), value(2)]);
+
+    circular_buffer.add(value(3));
+    assert_eq!(
+        circular_buffer.ordered_values(),
+        vec![value(1), value(2), value(3)]
+    );
+
+    // 3. Once the oldest values are overwritten, ordering is still from the oldest to the newest
+    circular_buffer.add(value(4));
+    assert_eq!(
+        circular_buffer.ordered_values(),
+        vec![value(2), value(3), value(4)]
+    );
+
+    circular_buffer.add(value(5));
+    assert_eq!(
+        circular_buffer.ordered_values(),
+        vec![value(3), value(4), value(5)]
+    );
+}
+
+#[test]
+fn simple_moving_average_works() {
+    // 0. Empty values yield zero
+    assert!(SimpleMovingAverage::calculate(&[]).is_zero());
+
+    // 1. All values are weighted equally, regardless of the covered blocks
+    let values = vec![
+        AggregatedValue::new(CurrencyAmount::from_rational(10, 10), 1),
+        AggregatedValue::new(CurrencyAmount::from_rational(20, 10), 3),
+    ];
+    assert_eq!(
+        SimpleMovingAverage::calculate(&values),
+        CurrencyAmount::from_rational(15, 10)
+    );
+}
+
+#[test]
+fn time_weighted_average_works() {
+    // 0. Empty values, or values without any covered blocks, yield zero
+    assert!(TimeWeightedAverage::calculate(&[]).is_zero());
+    assert!(TimeWeightedAverage::calculate(&[AggregatedValue::new(
+        CurrencyAmount::from_rational(10, 10),
+        0
+    )])
+    .is_zero());
+
+    // 1. Values are weighted by the number of covered blocks
+    let values = vec![
+        AggregatedValue::new(CurrencyAmount::from_rational(10, 10), 1),
+        AggregatedValue::new(CurrencyAmount::from_rational(20, 10), 3),
+    ];
+    assert_eq!(
+        TimeWeightedAverage::calculate(&values),
+        CurrencyAmount::from_rational(175, 100)
+    );
+
+    // 2. Values without any covered blocks don't contribute to the average
+    let mut values = values;
+    values.push(AggregatedValue::new(
+        CurrencyAmount::from_rational(100, 10),
+        0,
+    ));
+    assert_eq!(
+        TimeWeightedAverage::calculate(&values),
+        CurrencyAmount::from_rational(175, 100)
+    );
+}
+
+#[test]
+fn exponential_moving_average_works() {
+    struct HalfAlpha;
+    impl Get<Perbill> for HalfAlpha {
+        fn get() -> Perbill {
+            Perbill::from_percent(50)
+        }
+    }
+
+    struct FullAlpha;
+    impl Get<Perbill> for FullAlpha {
+        fn get() -> Perbill {
+            Perbill::one()
+        }
+    }
+
+    // 0. Empty values yield zero
+    assert!(ExponentialMovingAverage::<HalfAlpha>::calculate(&[]).is_zero());
+
+    // 1. Single value is the average itself
+    let value = |x: u128| AggregatedValue::new(CurrencyAmount::from_rational(x, 10), 1);
+    assert_eq!(
+        ExponentialMovingAverage::<HalfAlpha>::calculate(&[value(10)]),
+        value(10).value
+    );
+
+    // 2. Newer values have larger weight than the older ones
+    // ((10 + 20) / 2 + 40) / 2 = 27.5
+    let values = vec![value(10), value(20), value(40)];
+    assert_eq!(
+        ExponentialMovingAverage::<HalfAlpha>::calculate(&values),
+        CurrencyAmount::from_rational(275, 100)
+    );
+
+    // 3. With full alpha, only the newest value matters
+    assert_eq!(
+        ExponentialMovingAverage::<FullAlpha>::calculate(&values),
+        value(40).value
+    );
+}
+
+#[test]
+fn trimmed_mean_average_works() {
+    struct QuarterTrim;
+    impl Get<Perbill> for QuarterTrim {
+        fn get() -> Perbill {
+            Perbill::from_percent(25)
+        }
+    }
+
+    struct FullTrim;
+    impl Get<Perbill> for FullTrim {
+        fn get() -> Perbill {
+            Perbill::one()
+        }
+    }
+
+    // 0. Empty values yield zero
+    assert!(TrimmedMeanAverage::<QuarterTrim>::calculate(&[]).is_zero());
+
+    // 1. Outliers are discarded from both ends, regardless of the ordering
+    let value = |x: u128| AggregatedValue::new(CurrencyAmount::from_rational(x, 10), 1);
+    let values = vec![
+        value(10),
+        value(1000),
+        value(14),
+        value(1),
+        value(12),
+        value(16),
+        value(18),
+        value(20),
+    ];
+    assert_eq!(
+        TrimmedMeanAverage::<QuarterTrim>::calculate(&values),
+        CurrencyAmount::from_rational(15, 10)
+    );
+
+    // 2. Too few values to trim anything
+    let values = vec![value(10), value(1000), value(1)];
+    assert_eq!(
+        TrimmedMeanAverage::<QuarterTrim>::calculate(&values),
+        SimpleMovingAverage::calculate(&values)
+    );
+
+    // 3. At least one value is always kept
+    assert_eq!(
+        TrimmedMeanAverage::<FullTrim>::calculate(&values),
+        value(10).value
+    );
+    let values = vec![value(10), value(1000), value(1), value(20)];
+    assert_eq!(
+        TrimmedMeanAverage::<FullTrim>::calculate(&values),
+        CurrencyAmount::from_rational(15, 10)
+    );
+}
+
 #[test]
 fn on_new_data_works_as_expected() {
     ExtBuilder::build().execute_with(|| {
         // 0. Initial sanity check
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).is_empty(),
             "Init state must be empty."
         );
 
@@ -318,11 +512,11 @@ fn on_new_data_works_as_expected() {
         let amount_1 = CurrencyAmount::from_rational(15, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_1);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
             vec![amount_1],
         );
 
-        // 2. Try to add non-native currency, verify no state change
+        // 2. Try to add untracked currency, verify no state change
         let non_native_currency_id = CurrencyId::SDN;
         assert!(
             non_native_currency_id != native_currency_id,
@@ -340,7 +534,7 @@ fn on_new_data_works_as_expected() {
         let amount_2 = CurrencyAmount::from_rational(3, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_2);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
             vec![amount_1, amount_2],
         );
 
@@ -355,7 +549,10 @@ fn on_new_data_works_as_expected() {
         }
 
         assert_eq!(result.len(), limit as usize, "Sanity check.");
-        assert_eq!(CurrentBlockValues::<Test>::get().into_inner(), result);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            result
+        );
 
         // 5. Try to add one more value, overflowing the buffer, verify no state change
         assert_storage_noop!(PriceAggregator::on_new_data(
@@ -382,17 +579,19 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_finalize(block_number_1);
 
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(native_currency_id).is_empty(),
             "Buffer must be empty after the finalization."
         );
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(intermediate_value_aggregator.count, 1);
 
         let average_amount_1 = CurrencyAmount::from_rational(15, 10);
         assert_eq!(intermediate_value_aggregator.total, average_amount_1);
 
         // 3. Move to the next block, but for this one no new data is added
-        let intermediate_value_snapshot = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_snapshot =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
 
         let block_number_2 = block_number_1 + 1;
         System::set_block_number(block_number_2);
@@ -401,7 +600,7 @@ fn on_finalize_updates_aggregated_data() {
         // No new data is added, everything must still work without breaking
         PriceAggregator::on_finalize(block_number_2);
         assert_eq!(
-            IntermediateValueAggregator::<Test>::get(),
+            IntermediateValueAggregator::<Test>::get(native_currency_id),
             intermediate_value_snapshot,
             "No new data was added, so the state must remain the same."
         );
@@ -416,7 +615,8 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_3);
         PriceAggregator::on_finalize(block_number_3);
 
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(
             intermediate_value_aggregator.count, 2,
             "Count must be 2 since we added only 2 new values."
@@ -438,7 +638,7 @@ fn on_finalize_updates_circular_buffer() {
         // 1. Advance just until limit block is reached, checking appropriate storage items along the way
         let mut total = CurrencyAmount::zero();
         let current_block = System::block_number();
-        let limit_block = IntermediateValueAggregator::<Test>::get().limit_block;
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
 
         for block in current_block..limit_block {
             // Add new data
@@ -449,12 +649,14 @@ fn on_finalize_updates_circular_buffer() {
             // Finalize the block
             PriceAggregator::on_finalize(block);
             assert_eq!(
-                IntermediateValueAggregator::<Test>::get().total,
+                IntermediateValueAggregator::<Test>::get(native_currency_id).total,
                 total,
                 "Check total is updated as expected."
             );
             assert!(
-                ValuesCircularBuffer::<Test>::get().buffer.is_empty(),
+                ValuesCircularBuffer::<Test>::get(native_currency_id)
+                    .buffer
+                    .is_empty(),
                 "Circular buffer is expected to remain empty until limit block is reached."
             );
 
@@ -472,7 +674,8 @@ fn on_finalize_updates_circular_buffer() {
         PriceAggregator::on_finalize(current_block);
 
         // Check that value aggregator is reset & new block limit is correct
-        let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+        let reset_intermediate_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
         assert_eq!(reset_intermediate_aggregator.count, 0);
         assert_eq!(
@@ -481,17 +684,18 @@ fn on_finalize_updates_circular_buffer() {
         );
 
         // Check that circular buffer was updated as expected
-        let circular_buffer = ValuesCircularBuffer::<Test>::get();
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
         let expected_average = total * CurrencyAmount::from_rational(1, limit_block as u128 - 1);
         assert_eq!(
             circular_buffer.buffer.clone().into_inner(),
-            vec![expected_average]
+            vec![AggregatedValue::new(expected_average, limit_block - 1)]
         );
         assert_eq!(circular_buffer.head, 1);
 
         // Verify deposited event
         System::assert_last_event(RuntimeEvent::PriceAggregator(
             Event::AverageAggregatedValue {
+                currency_id: native_currency_id,
                 value: expected_average,
             },
         ));
@@ -526,16 +730,18 @@ fn circular_buffer_really_is_circular() {
         // 1. Fill up the circular buffer
         for x in 0..circular_buffer_length {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
             assert_eq!(circular_buffer.buffer.len(), x as usize + 1);
             assert_eq!(circular_buffer.head, (x + 1) % circular_buffer_length);
 
             // Check that intermediate aggregator is reset & limit block is updated
-            let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let reset_intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
             assert_eq!(reset_intermediate_aggregator.count, 0);
             assert_eq!(
@@ -547,11 +753,12 @@ fn circular_buffer_really_is_circular() {
         // 2. Continue adding the data, verify circular buffer is updated as expected
         for x in 0..circular_buffer_length * 3 {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
             assert_eq!(
                 circular_buffer.buffer.len(),
                 circular_buffer_length as usize
@@ -560,3 +767,521 @@ fn circular_buffer_really_is_circular() {
         }
     })
 }
+
+#[test]
+fn exceeds_deviation_works() {
+    let reference = CurrencyAmount::from_rational(10, 10);
+    let max_deviation = Perbill::from_percent(50);
+
+    // Zero reference value means there's nothing to compare against
+    assert!(!exceeds_deviation(
+        CurrencyAmount::from_rational(1000, 10),
+        CurrencyAmount::zero(),
+        max_deviation
+    ));
+
+    // Values between half and double the reference value are within the allowed deviation
+    for value in [5, 7, 10, 15, 20] {
+        assert!(!exceeds_deviation(
+            CurrencyAmount::from_rational(value, 10),
+            reference,
+            max_deviation
+        ));
+    }
+
+    // Values outside of that range exceed the allowed deviation
+    for value in [0, 4, 21, 100] {
+        assert!(exceeds_deviation(
+            CurrencyAmount::from_rational(value, 10),
+            reference,
+            max_deviation
+        ));
+    }
+}
+
+#[test]
+fn on_new_data_rejects_deviating_values() {
+    ExtBuilder::build().execute_with(|| {
+        MaxDeviation::set(Perbill::from_percent(50));
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let dummy_account = 123;
+
+        // 1. Without moving average, any value is accepted
+        let amount_1 = CurrencyAmount::from_rational(100, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_1);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![amount_1]
+        );
+        CurrentBlockValues::<Test>::remove(native_currency_id);
+        CurrentBlockSubmitters::<Test>::remove(native_currency_id);
+
+        // 2. Value within the allowed deviation from the moving average is accepted
+        let moving_average = CurrencyAmount::from_rational(10, 10);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |buffer| {
+            buffer.add(AggregatedValue::new(moving_average, 1))
+        });
+
+        // Moving average is only picked up at the start of the next block
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_1);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![amount_1]
+        );
+        CurrentBlockValues::<Test>::remove(native_currency_id);
+        CurrentBlockSubmitters::<Test>::remove(native_currency_id);
+        PriceAggregator::on_initialize(System::block_number());
+        assert_eq!(
+            BlockMovingAverage::<Test>::get(native_currency_id),
+            Some(moving_average)
+        );
+
+        let amount_2 = CurrencyAmount::from_rational(19, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_2);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![amount_2]
+        );
+        assert_eq!(
+            CurrentBlockSubmitters::<Test>::get(native_currency_id).into_inner(),
+            vec![dummy_account]
+        );
+
+        // 3. Values exceeding the allowed deviation are rejected
+        for amount in [
+            CurrencyAmount::from_rational(21, 10),
+            CurrencyAmount::from_rational(4, 10),
+        ] {
+            PriceAggregator::on_new_data(&456, &native_currency_id, &amount);
+            System::assert_last_event(RuntimeEvent::PriceAggregator(Event::ValueRejected {
+                currency_id: native_currency_id,
+                submitter: 456,
+                value: amount,
+                moving_average,
+            }));
+        }
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![amount_2]
+        );
+        assert_eq!(
+            CurrentBlockSubmitters::<Test>::get(native_currency_id).into_inner(),
+            vec![dummy_account]
+        );
+
+        // 4. While the price feed is stale, deviating values are accepted so the feed can recover
+        PriceFeedStatus::<Test>::mutate(native_currency_id, |status| status.is_stale = true);
+        PriceAggregator::on_initialize(System::block_number());
+        assert!(BlockMovingAverage::<Test>::get(native_currency_id).is_none());
+        let amount_3 = CurrencyAmount::from_rational(30, 10);
+        PriceAggregator::on_new_data(&456, &native_currency_id, &amount_3);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![amount_2, amount_3]
+        );
+        assert_eq!(
+            CurrentBlockSubmitters::<Test>::get(native_currency_id).into_inner(),
+            vec![dummy_account, 456]
+        );
+    })
+}
+
+#[test]
+fn insufficient_submitters_discards_block_values() {
+    ExtBuilder::build().execute_with(|| {
+        MinSubmitters::set(2);
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let amount = CurrencyAmount::from_rational(15, 10);
+
+        // 1. Multiple values from the same submitter are not enough
+        PriceAggregator::on_new_data(&123, &native_currency_id, &amount);
+        PriceAggregator::on_new_data(&123, &native_currency_id, &amount);
+        assert_eq!(
+            CurrentBlockSubmitters::<Test>::get(native_currency_id).len(),
+            1
+        );
+
+        let block_number = System::block_number();
+        PriceAggregator::on_finalize(block_number);
+
+        assert!(CurrentBlockValues::<Test>::get(native_currency_id).is_empty());
+        assert!(CurrentBlockSubmitters::<Test>::get(native_currency_id).is_empty());
+        assert!(IntermediateValueAggregator::<Test>::get(native_currency_id)
+            .count
+            .is_zero());
+        System::assert_last_event(RuntimeEvent::PriceAggregator(
+            Event::InsufficientSubmitters {
+                currency_id: native_currency_id,
+                submitters: 1,
+            },
+        ));
+
+        // 2. Values from enough distinct submitters are processed
+        let block_number = block_number + 1;
+        System::set_block_number(block_number);
+        PriceAggregator::on_initialize(block_number);
+
+        PriceAggregator::on_new_data(&123, &native_currency_id, &amount);
+        PriceAggregator::on_new_data(&456, &native_currency_id, &amount);
+        PriceAggregator::on_finalize(block_number);
+
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
+        assert_eq!(intermediate_value_aggregator.count, 1);
+        assert_eq!(intermediate_value_aggregator.total, amount);
+        assert_eq!(
+            PriceFeedStatus::<Test>::get(native_currency_id).last_update,
+            block_number,
+            "Price feed update must be recorded."
+        );
+    })
+}
+
+#[test]
+fn stale_price_feed_is_detected() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let moving_average = CurrencyAmount::from_rational(15, 10);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |buffer| {
+            buffer.add(AggregatedValue::new(moving_average, 1))
+        });
+
+        // 1. Advance blocks without any new data, until just before the staleness threshold is exceeded
+        let threshold: u32 = <Test as Config>::StalenessThreshold::get();
+        for block in System::block_number()..=threshold {
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        }
+        assert!(!PriceAggregator::is_price_stale());
+
+        // 2. Once the threshold is exceeded, the price feed is marked as stale
+        let block_number = System::block_number();
+        PriceAggregator::on_finalize(block_number);
+        assert!(PriceAggregator::is_price_stale());
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::PriceFeedStale {
+            currency_id: native_currency_id,
+            last_update: 0,
+        }));
+
+        // Stale event is only deposited once
+        assert_storage_noop!(PriceAggregator::check_price_feed_staleness(
+            native_currency_id,
+            block_number + 1
+        ));
+
+        // 3. Without a fallback price, the last moving average is returned, flagged as stale
+        assert_eq!(PriceAggregator::average_price(), moving_average);
+
+        // With a fallback price, it is returned instead
+        let fallback_price = CurrencyAmount::from_rational(1, 10);
+        StalePriceFallback::set(Some(fallback_price));
+        assert_eq!(PriceAggregator::average_price(), fallback_price);
+
+        // 4. New data restores the price feed
+        let block_number = block_number + 1;
+        System::set_block_number(block_number);
+        PriceAggregator::on_initialize(block_number);
+
+        PriceAggregator::on_new_data(&123, &native_currency_id, &moving_average);
+        PriceAggregator::on_finalize(block_number);
+
+        assert!(!PriceAggregator::is_price_stale());
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::PriceFeedRestored {
+            currency_id: native_currency_id,
+        }));
+        assert_eq!(PriceAggregator::average_price(), moving_average);
+    })
+}
+
+#[test]
+fn add_tracked_currency_works() {
+    ExtBuilder::build().execute_with(|| {
+        let currency_id = CurrencyId::XcAsset(1);
+        assert!(!PriceAggregator::is_tracked(&currency_id));
+
+        // Start tracking the currency, verify state is as expected
+        assert_ok!(PriceAggregator::add_tracked_currency(
+            RuntimeOrigin::root(),
+            currency_id
+        ));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::CurrencyTracked {
+            currency_id,
+        }));
+
+        assert!(PriceAggregator::is_tracked(&currency_id));
+        assert_eq!(
+            TrackedCurrencies::<Test>::get().into_inner(),
+            vec![currency_id]
+        );
+        assert_eq!(
+            PriceAggregator::tracked_currencies().collect::<Vec<_>>(),
+            vec![NativeCurrencyId::get(), currency_id]
+        );
+
+        let block_number = System::block_number();
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(currency_id),
+            ValueAggregator::new(block_number + AggregationDuration::get())
+        );
+        assert_eq!(
+            PriceFeedStatus::<Test>::get(currency_id),
+            FeedStatus {
+                last_update: block_number,
+                is_stale: false,
+            }
+        );
+    })
+}
+
+#[test]
+fn add_tracked_currency_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Only the manager origin can add tracked currencies
+        assert_noop!(
+            PriceAggregator::add_tracked_currency(RuntimeOrigin::signed(1), CurrencyId::XcAsset(1)),
+            DispatchError::BadOrigin
+        );
+
+        // Unsupported currencies cannot be tracked
+        assert_noop!(
+            PriceAggregator::add_tracked_currency(RuntimeOrigin::root(), CurrencyId::SDN),
+            Error::<Test>::UnsupportedCurrency
+        );
+
+        // Same currency cannot be tracked twice
+        assert_ok!(PriceAggregator::add_tracked_currency(
+            RuntimeOrigin::root(),
+            CurrencyId::XcAsset(1)
+        ));
+        assert_noop!(
+            PriceAggregator::add_tracked_currency(RuntimeOrigin::root(), CurrencyId::XcAsset(1)),
+            Error::<Test>::CurrencyAlreadyTracked
+        );
+
+        // Number of tracked currencies is limited
+        let limit = <Test as Config>::MaxTrackedCurrencies::get();
+        for x in 2..=limit {
+            assert_ok!(PriceAggregator::add_tracked_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::XcAsset(x.into())
+            ));
+        }
+        assert_noop!(
+            PriceAggregator::add_tracked_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::XcAsset((limit + 1).into())
+            ),
+            Error::<Test>::TooManyTrackedCurrencies
+        );
+    })
+}
+
+#[test]
+fn remove_tracked_currency_works() {
+    ExtBuilder::build().execute_with(|| {
+        let currency_id = CurrencyId::XcAsset(1);
+        assert_ok!(PriceAggregator::add_tracked_currency(
+            RuntimeOrigin::root(),
+            currency_id
+        ));
+
+        // Accumulate some data for the currency
+        let amount = CurrencyAmount::from_rational(3, 10);
+        PriceAggregator::on_new_data(&123, &currency_id, &amount);
+        ValuesCircularBuffer::<Test>::mutate(currency_id, |buffer| {
+            buffer.add(AggregatedValue::new(amount, 1))
+        });
+
+        // Stop tracking the currency, verify all of its data is removed
+        assert_ok!(PriceAggregator::remove_tracked_currency(
+            RuntimeOrigin::root(),
+            currency_id
+        ));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::CurrencyUntracked {
+            currency_id,
+        }));
+
+        assert!(!PriceAggregator::is_tracked(&currency_id));
+        assert!(TrackedCurrencies::<Test>::get().is_empty());
+        assert!(!CurrentBlockValues::<Test>::contains_key(currency_id));
+        assert!(!CurrentBlockSubmitters::<Test>::contains_key(currency_id));
+        assert!(!IntermediateValueAggregator::<Test>::contains_key(
+            currency_id
+        ));
+        assert!(!ValuesCircularBuffer::<Test>::contains_key(currency_id));
+        assert!(!PriceFeedStatus::<Test>::contains_key(currency_id));
+    })
+}
+
+#[test]
+fn remove_tracked_currency_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let currency_id = CurrencyId::XcAsset(1);
+        assert_ok!(PriceAggregator::add_tracked_currency(
+            RuntimeOrigin::root(),
+            currency_id
+        ));
+
+        // Only the manager origin can remove tracked currencies
+        assert_noop!(
+            PriceAggregator::remove_tracked_currency(RuntimeOrigin::signed(1), currency_id),
+            DispatchError::BadOrigin
+        );
+
+        // Untracked currencies cannot be removed
+        assert_noop!(
+            PriceAggregator::remove_tracked_currency(RuntimeOrigin::root(), CurrencyId::XcAsset(2)),
+            Error::<Test>::CurrencyNotTracked
+        );
+
+        // Native currency is always tracked
+        assert_noop!(
+            PriceAggregator::remove_tracked_currency(
+                RuntimeOrigin::root(),
+                NativeCurrencyId::get()
+            ),
+            Error::<Test>::CurrencyNotTracked
+        );
+    })
+}
+
+#[test]
+fn tracked_currencies_are_aggregated_independently() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let currency_id = CurrencyId::XcAsset(1);
+        assert_ok!(PriceAggregator::add_tracked_currency(
+            RuntimeOrigin::root(),
+            currency_id
+        ));
+
+        // No price is provided until the first value is pushed into the circular buffer
+        assert!(PriceAggregator::price_of(currency_id).is_none());
+
+        // 1. Advance until the limit block, submitting different values for each currency
+        let native_amount = CurrencyAmount::from_rational(15, 10);
+        let amount = CurrencyAmount::from_rational(4, 10);
+        let limit_block = IntermediateValueAggregator::<Test>::get(currency_id).limit_block;
+        for block in System::block_number()..=limit_block {
+            PriceAggregator::on_new_data(&123, &native_currency_id, &native_amount);
+            PriceAggregator::on_new_data(&123, &currency_id, &amount);
+
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        }
+
+        // 2. Each currency has its own moving average
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![AggregatedValue::new(native_amount, limit_block)]
+        );
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(currency_id)
+                .buffer
+                .into_inner(),
+            vec![AggregatedValue::new(amount, limit_block)]
+        );
+        System::assert_has_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency_id,
+                value: amount,
+            },
+        ));
+
+        assert_eq!(PriceAggregator::average_price(), native_amount);
+        assert_eq!(
+            PriceAggregator::price_of(native_currency_id),
+            Some(native_amount)
+        );
+        assert_eq!(PriceAggregator::price_of(currency_id), Some(amount));
+
+        // 3. Untracked currencies have no price
+        assert!(PriceAggregator::price_of(CurrencyId::XcAsset(2)).is_none());
+
+        // 4. Stale price of a non-native currency isn't provided
+        PriceFeedStatus::<Test>::mutate(currency_id, |status| status.is_stale = true);
+        assert!(PriceAggregator::price_of(currency_id).is_none());
+        assert_eq!(
+            PriceAggregator::price_of(native_currency_id),
+            Some(native_amount)
+        );
+    })
+}
+
+#[test]
+fn aggregation_state_works() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+
+        // 1. Untracked currencies have no aggregation state
+        assert!(PriceAggregator::aggregation_state(CurrencyId::XcAsset(1)).is_none());
+
+        // 2. Initially, nothing has been aggregated yet
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        let state = PriceAggregator::aggregation_state(native_currency_id)
+            .expect("Native currency is always tracked.");
+        assert!(state.moving_average.is_zero());
+        assert!(state.buffer.is_empty());
+        assert!(state.intermediate_average.is_zero());
+        assert!(state.intermediate_count.is_zero());
+        assert_eq!(state.limit_block, limit_block);
+
+        // 3. Submit values for a few blocks, and check the in-progress intermediate average
+        let amounts = [
+            CurrencyAmount::from_rational(1, 10),
+            CurrencyAmount::from_rational(3, 10),
+        ];
+        for amount in amounts {
+            let block = System::block_number();
+            PriceAggregator::on_new_data(&123, &native_currency_id, &amount);
+
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        }
+
+        let state = PriceAggregator::aggregation_state(native_currency_id).unwrap();
+        assert!(state.buffer.is_empty());
+        assert_eq!(
+            state.intermediate_average,
+            CurrencyAmount::from_rational(2, 10)
+        );
+        assert_eq!(state.intermediate_count, amounts.len() as u32);
+        assert_eq!(state.limit_block, limit_block);
+
+        let status = PriceFeedStatus::<Test>::get(native_currency_id);
+        assert_eq!(state.last_update, status.last_update);
+        assert_eq!(state.is_stale, status.is_stale);
+
+        // 4. Advance past the limit block, and check the circular buffer entries & moving average
+        for block in System::block_number()..=limit_block {
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        }
+
+        let state = PriceAggregator::aggregation_state(native_currency_id).unwrap();
+        assert_eq!(
+            state.buffer,
+            ValuesCircularBuffer::<Test>::get(native_currency_id).ordered_values()
+        );
+        assert_eq!(
+            state.buffer,
+            vec![AggregatedValue::new(
+                CurrencyAmount::from_rational(2, 10),
+                amounts.len() as u32
+            )]
+        );
+        assert_eq!(state.moving_average, CurrencyAmount::from_rational(2, 10));
+        assert!(state.intermediate_count.is_zero());
+        assert_eq!(
+            state.limit_block,
+            IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block
+        );
+        assert!(state.limit_block > limit_block);
+    })
+}
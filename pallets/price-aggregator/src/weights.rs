@@ -51,6 +51,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn process_block_aggregated_values() -> Weight;
 	fn process_intermediate_aggregated_values() -> Weight;
+	fn add_tracked_currency() -> Weight;
+	fn remove_tracked_currency() -> Weight;
 }
 
 /// Weights for pallet_price_aggregator using the Substrate node and recommended hardware.
@@ -64,16 +66,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_520_000, 0)
 	}
 	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:1 w:1)
-	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
 	fn process_intermediate_aggregated_values() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `102`
-		//  Estimated: `1602`
+		//  Estimated: `3607`
 		// Minimum execution time: 9_093_000 picoseconds.
-		Weight::from_parts(9_297_000, 1602)
+		Weight::from_parts(9_297_000, 3607)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(138), added: 633, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::PriceFeedStatus` (r:0 w:1)
+	/// Proof: `PriceAggregator::PriceFeedStatus` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn add_tracked_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `1623`
+		// Minimum execution time: 11_742_000 picoseconds.
+		Weight::from_parts(12_105_000, 1623)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(138), added: 633, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockValues` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockValues` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockSubmitters` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockSubmitters` (`max_values`: None, `max_size`: Some(286), added: 2761, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::PriceFeedStatus` (r:0 w:1)
+	/// Proof: `PriceAggregator::PriceFeedStatus` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn remove_tracked_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1623`
+		// Minimum execution time: 13_518_000 picoseconds.
+		Weight::from_parts(13_910_000, 1623)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -86,14 +124,50 @@ impl WeightInfo for () {
 		Weight::from_parts(3_520_000, 0)
 	}
 	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:1 w:1)
-	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
 	fn process_intermediate_aggregated_values() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `102`
-		//  Estimated: `1602`
+		//  Estimated: `3607`
 		// Minimum execution time: 9_093_000 picoseconds.
-		Weight::from_parts(9_297_000, 1602)
+		Weight::from_parts(9_297_000, 3607)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(138), added: 633, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::PriceFeedStatus` (r:0 w:1)
+	/// Proof: `PriceAggregator::PriceFeedStatus` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn add_tracked_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `1623`
+		// Minimum execution time: 11_742_000 picoseconds.
+		Weight::from_parts(12_105_000, 1623)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(138), added: 633, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockValues` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockValues` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockSubmitters` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockSubmitters` (`max_values`: None, `max_size`: Some(286), added: 2761, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::PriceFeedStatus` (r:0 w:1)
+	/// Proof: `PriceAggregator::PriceFeedStatus` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn remove_tracked_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1623`
+		// Minimum execution time: 13_518_000 picoseconds.
+		Weight::from_parts(13_910_000, 1623)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
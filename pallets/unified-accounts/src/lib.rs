@@ -64,6 +64,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use astar_primitives::{
+    dapp_staking::OnIssuanceBurned,
     evm::{EvmAddress, UnifiedAddressMapper},
     Balance,
 };
@@ -122,6 +123,8 @@ pub mod pallet {
         /// respectively
         #[pallet::constant]
         type AccountMappingStorageFee: Get<Balance>;
+        /// Observer notified about the burned account mapping storage fee
+        type OnStorageFeeBurned: OnIssuanceBurned;
         /// Weight information for the extrinsics in this module
         type WeightInfo: WeightInfo;
     }
@@ -277,7 +280,10 @@ impl<T: Config> Pallet<T> {
         let balance = T::Currency::reducible_balance(who, Preserve, Polite);
         let fee = T::AccountMappingStorageFee::get();
         ensure!(balance >= fee, Error::<T>::FundsUnavailable);
-        T::Currency::burn_from(who, T::AccountMappingStorageFee::get(), Exact, Polite)
+        let burned =
+            T::Currency::burn_from(who, T::AccountMappingStorageFee::get(), Exact, Polite)?;
+        T::OnStorageFeeBurned::on_issuance_burned(burned);
+        Ok(burned)
     }
 }
 
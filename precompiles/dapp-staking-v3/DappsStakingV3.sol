@@ -35,6 +35,96 @@ interface DAppStaking {
         bytes contract_address;
     }
 
+    /// @notice Describes an amount to be staked on, or unstaked from, a smart contract.
+    /// @param smart_contract: The smart contract to be staked on or unstaked from.
+    /// @param amount: The amount of tokens.
+    struct SmartContractAmount {
+        SmartContract smart_contract;
+        uint128 amount;
+    }
+
+    /// @notice Describes stake amount, split per subperiod.
+    /// @param voting: Amount staked during the voting subperiod.
+    /// @param build_and_earn: Amount staked during the build&earn subperiod.
+    /// @param era: Era in which the stake amount is valid.
+    /// @param period: Period in which the stake amount is valid.
+    struct StakeAmount {
+        uint128 voting;
+        uint128 build_and_earn;
+        uint256 era;
+        uint256 period;
+    }
+
+    /// @notice Describes a chunk of funds undergoing the unlocking process.
+    /// @param amount: Amount being unlocked.
+    /// @param unlock_block: Block number after which the amount can be claimed.
+    struct UnlockingChunk {
+        uint128 amount;
+        uint256 unlock_block;
+    }
+
+    /// @notice Describes the dApp staking ledger of an account.
+    /// @param locked: Active locked amount, which can be used for staking.
+    /// @param unlocking: Chunks of funds undergoing the unlocking process.
+    /// @param staked: Stake amount of the account.
+    /// @param staked_future: Stake amount of the account, valid from the next era. All zeros if there is none.
+    /// @param contract_stake_count: Number of smart contracts for which the account has a staking entry.
+    struct AccountLedger {
+        uint128 locked;
+        UnlockingChunk[] unlocking;
+        StakeAmount staked;
+        StakeAmount staked_future;
+        uint256 contract_stake_count;
+    }
+
+    /// @notice Describes the stake of an account on a single smart contract.
+    /// @param staked: Stake amount of the account on the smart contract.
+    /// @param loyal_staker: `true` if the account is eligible for the bonus reward.
+    struct StakerInfo {
+        StakeAmount staked;
+        bool loyal_staker;
+    }
+
+    /// @notice Describes the tier assignment of a dApp for an era.
+    /// @param assigned: `true` if the dApp has an unclaimed tier assignment for the era.
+    /// @param tier: Tier into which the dApp was assigned.
+    /// @param rank: Rank of the dApp within the tier.
+    struct DAppTier {
+        bool assigned;
+        uint8 tier;
+        uint8 rank;
+    }
+
+    /// @notice Describes reward for a single era.
+    /// @param era: Era to which the reward belongs.
+    /// @param amount: Reward amount.
+    struct EraReward {
+        uint256 era;
+        uint128 amount;
+    }
+
+    /// @notice Describes a registered dApp.
+    /// @param owner: Owner of the dApp, as SS58 public key.
+    /// @param reward_beneficiary: Account receiving the dApp rewards, as SS58 public key.
+    /// @param id: Unique dApp Id.
+    struct DAppInfo {
+        bytes owner;
+        bytes reward_beneficiary;
+        uint256 id;
+    }
+
+    /// @notice Describes the metadata of a registered dApp.
+    /// @param name: Name of the dApp.
+    /// @param website: Website of the dApp.
+    /// @param ipfs_cid: IPFS CID of additional dApp information.
+    /// @param category: Category of the dApp. 0 - Defi, 1 - Nft, 2 - Gaming, 3 - Infrastructure, 4 - Social, 5 - Other.
+    struct DAppMetadata {
+        bytes name;
+        bytes website;
+        bytes ipfs_cid;
+        uint8 category;
+    }
+
     // Storage getters
 
     /// @notice Get the current protocol state.
@@ -45,6 +135,61 @@ interface DAppStaking {
     /// @return period: The unlocking period expressed in the number of blocks.
     function unlocking_period() external view returns (uint256);
 
+    /// @notice Get whether the given account has opted into auto-restaking of staker rewards.
+    /// @param staker: The staker address, either H160 or SS58 public key.
+    /// @return auto_restake: `true` if claimed staker rewards are automatically re-staked.
+    function auto_restake(bytes calldata staker) external view returns (bool);
+
+    /// @notice Get the dApp staking ledger of the given account.
+    /// @param staker: The staker address, either H160 or SS58 public key.
+    /// @return ledger: Locked & unlocking funds, and the stake amounts of the account.
+    function account_ledger(bytes calldata staker) external view returns (AccountLedger memory);
+
+    /// @notice Get the stake of the given account on the specified smart contract.
+    ///         Period of the stake amount must be checked, since stakes from past periods are no longer valid.
+    /// @param staker: The staker address, either H160 or SS58 public key.
+    /// @param smart_contract: The smart contract which was staked on.
+    /// @return staker_info: Stake amount of the account & whether it's a loyal staker. All zeros if there is no stake.
+    function staker_info(bytes calldata staker, SmartContract calldata smart_contract) external view returns (StakerInfo memory);
+
+    /// @notice Get the stake amount of the specified smart contract, valid in the ongoing era.
+    /// @param smart_contract: The smart contract to be checked.
+    /// @return stake: Stake amount of the smart contract. All zeros if it isn't registered.
+    function contract_stake(SmartContract calldata smart_contract) external view returns (StakeAmount memory);
+
+    /// @notice Get the tier & rank of the specified smart contract for the given era.
+    ///         Only unclaimed tier assignments are available.
+    /// @param smart_contract: The smart contract to be checked.
+    /// @param era: The era to be checked.
+    /// @return dapp_tier: Tier assignment of the smart contract.
+    function dapp_tier(SmartContract calldata smart_contract, uint256 era) external view returns (DAppTier memory);
+
+    /// @notice Get the staker rewards which would be claimed by the next `claim_staker_rewards` call.
+    /// @param staker: The staker address, either H160 or SS58 public key.
+    /// @return amount: Claimable staker reward amount.
+    function claimable_staker_rewards(bytes calldata staker) external view returns (uint128);
+
+    /// @notice Get the bonus reward the given account can claim for the specified smart contract.
+    /// @param staker: The staker address, either H160 or SS58 public key.
+    /// @param smart_contract: The smart contract for which the bonus reward would be claimed.
+    /// @return amount: Claimable bonus reward amount.
+    function claimable_bonus_reward(bytes calldata staker, SmartContract calldata smart_contract) external view returns (uint128);
+
+    /// @notice Get the unclaimed, non-expired dApp rewards of the specified smart contract.
+    /// @param smart_contract: The smart contract to be checked.
+    /// @return rewards: Claimable dApp rewards, sorted by era.
+    function claimable_dapp_rewards(SmartContract calldata smart_contract) external view returns (EraReward[] memory);
+
+    /// @notice Get the info of the specified registered smart contract. Reverts if it isn't registered.
+    /// @param smart_contract: The smart contract to be checked.
+    /// @return dapp_info: Owner, reward beneficiary & Id of the dApp.
+    function dapp_info(SmartContract calldata smart_contract) external view returns (DAppInfo memory);
+
+    /// @notice Get the metadata of the specified smart contract. Reverts if it has no metadata.
+    /// @param smart_contract: The smart contract to be checked.
+    /// @return dapp_metadata: Name, website, IPFS CID & category of the dApp.
+    function dapp_metadata(SmartContract calldata smart_contract) external view returns (DAppMetadata memory);
+
 
     // Extrinsic calls
 
@@ -71,6 +216,16 @@ interface DAppStaking {
     /// @param amount: The amount of tokens to be unstaked.
     function unstake(SmartContract calldata smart_contract, uint128 amount) external returns (bool);
 
+    /// @notice Stake the given amounts of tokens on the specified smart contracts, in a single call.
+    ///         Stakes are applied all-or-nothing, if any of them fails, none are applied.
+    /// @param stakes: The smart contracts to be staked on, together with the amounts.
+    function stake_many(SmartContractAmount[] calldata stakes) external returns (bool);
+
+    /// @notice Unstake the given amounts of tokens from the specified smart contracts, in a single call.
+    ///         Unstakes are applied all-or-nothing, if any of them fails, none are applied.
+    /// @param unstakes: The smart contracts to be unstaked from, together with the amounts.
+    function unstake_many(SmartContractAmount[] calldata unstakes) external returns (bool);
+
     /// @notice Claims one or more pending staker rewards.
     function claim_staker_rewards() external returns (bool);
 
@@ -89,4 +244,9 @@ interface DAppStaking {
 
     /// @notice Used to cleanup all expired contract stake entries from the caller.
     function cleanup_expired_entries() external returns (bool);
+
+    /// @notice Set whether claimed staker rewards should automatically be locked & re-staked.
+    ///         Rewards are re-staked on the contracts staked on during the ongoing period, proportionally to the existing stake.
+    /// @param enabled: `true` to enable auto-restaking, `false` to disable it.
+    function set_auto_restake(bool enabled) external returns (bool);
 }
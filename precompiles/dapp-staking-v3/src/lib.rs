@@ -44,11 +44,16 @@ use sp_runtime::traits::{Dispatchable, Zero};
 use sp_std::{marker::PhantomData, prelude::*};
 extern crate alloc;
 
-use astar_primitives::{dapp_staking::SmartContractHandle, AccountId, Balance, BlockNumber};
+use astar_primitives::{
+    dapp_staking::{EraNumber, SmartContractHandle},
+    AccountId, Balance, BlockNumber,
+};
 use pallet_dapp_staking_v3::{
     AccountLedgerFor, ActiveProtocolState, ContractStake, ContractStakeAmount, CurrentEraInfo,
-    DAppInfoFor, EraInfo, EraRewardSpanFor, EraRewards, IntegratedDApps, Ledger,
-    Pallet as DAppStaking, ProtocolState, SingularStakingInfo, StakerInfo, Subperiod,
+    DAppCategory, DAppInfoFor, DAppMetadataFor, DAppMetadataOf, DAppTierRewardsFor, DAppTiers,
+    EraInfo, EraRewardSpanFor, EraRewards, IntegratedDApps, Ledger, Pallet as DAppStaking,
+    PeriodEndInfo, ProtocolState, SingularStakingInfo, StakeAmount, StakerInfo, StakerPreferences,
+    StakerPreferencesOf, Subperiod,
 };
 
 pub const STAKER_BYTES_LIMIT: u32 = 32;
@@ -67,6 +72,82 @@ pub(crate) struct PrecompileProtocolState {
     subperiod: u8,
 }
 
+/// Helper struct used to encode stake amount.
+#[derive(Debug, Clone, Default, solidity::Codec)]
+pub(crate) struct PrecompileStakeAmount {
+    voting: u128,
+    build_and_earn: u128,
+    era: U256,
+    period: U256,
+}
+
+impl From<StakeAmount> for PrecompileStakeAmount {
+    fn from(stake: StakeAmount) -> Self {
+        Self {
+            voting: stake.voting,
+            build_and_earn: stake.build_and_earn,
+            era: stake.era.into(),
+            period: stake.period.into(),
+        }
+    }
+}
+
+/// Helper struct used to encode unlocking chunk.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileUnlockingChunk {
+    amount: u128,
+    unlock_block: U256,
+}
+
+/// Helper struct used to encode account ledger.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileAccountLedger {
+    locked: u128,
+    unlocking: Vec<PrecompileUnlockingChunk>,
+    staked: PrecompileStakeAmount,
+    staked_future: PrecompileStakeAmount,
+    contract_stake_count: U256,
+}
+
+/// Helper struct used to encode staker info for a single smart contract.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileStakerInfo {
+    staked: PrecompileStakeAmount,
+    loyal_staker: bool,
+}
+
+/// Helper struct used to encode dApp tier assignment for an era.
+#[derive(Debug, Clone, Default, solidity::Codec)]
+pub(crate) struct PrecompileDAppTier {
+    assigned: bool,
+    tier: u8,
+    rank: u8,
+}
+
+/// Helper struct used to encode reward for a single era.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileEraReward {
+    era: U256,
+    amount: u128,
+}
+
+/// Helper struct used to encode dApp info.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileDAppInfo {
+    owner: DynamicAddress,
+    reward_beneficiary: DynamicAddress,
+    id: U256,
+}
+
+/// Helper struct used to encode dApp metadata.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileDAppMetadata {
+    name: UnboundedBytes,
+    website: UnboundedBytes,
+    ipfs_cid: UnboundedBytes,
+    category: u8,
+}
+
 /// Helper struct used to encode different smart contract types for the v2 interface.
 #[derive(Debug, Clone, solidity::Codec)]
 pub struct SmartContractV2 {
@@ -271,8 +352,10 @@ where
                 + SingularStakingInfo::max_encoded_len(),
         )?;
 
-        let smart_contract =
-            <R as pallet_dapp_staking_v3::Config>::SmartContract::evm(contract_h160.into());
+        let smart_contract = Self::resolve_smart_contract(
+            handle,
+            <R as pallet_dapp_staking_v3::Config>::SmartContract::evm(contract_h160.into()),
+        )?;
 
         // parse the staker account
         let staker = Self::parse_input_address(staker.into())?;
@@ -312,8 +395,10 @@ where
                 + ContractStakeAmount::max_encoded_len(),
         )?;
 
-        let smart_contract =
-            <R as pallet_dapp_staking_v3::Config>::SmartContract::evm(contract_h160.into());
+        let smart_contract = Self::resolve_smart_contract(
+            handle,
+            <R as pallet_dapp_staking_v3::Config>::SmartContract::evm(contract_h160.into()),
+        )?;
 
         let current_period_number = ActiveProtocolState::<R>::get().period_number();
         let dapp_info = match IntegratedDApps::<R>::get(&smart_contract) {
@@ -679,6 +764,38 @@ where
         Ok(true)
     }
 
+    /// Attempts to stake the given amounts on the given smart contracts, all-or-nothing.
+    #[precompile::public("stake_many(((uint8,bytes),uint128)[])")]
+    fn stake_many(
+        handle: &mut impl PrecompileHandle,
+        stakes: BoundedVec<(SmartContractV2, Balance), R::MaxNumberOfStakedContracts>,
+    ) -> EvmResult<bool> {
+        let stakes = Self::decode_smart_contract_amounts(stakes)?;
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let stake_many_call = pallet_dapp_staking_v3::Call::<R>::stake_many { stakes };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), stake_many_call)?;
+
+        Ok(true)
+    }
+
+    /// Attempts to unstake the given amounts from the given smart contracts, all-or-nothing.
+    #[precompile::public("unstake_many(((uint8,bytes),uint128)[])")]
+    fn unstake_many(
+        handle: &mut impl PrecompileHandle,
+        unstakes: BoundedVec<(SmartContractV2, Balance), R::MaxNumberOfStakedContracts>,
+    ) -> EvmResult<bool> {
+        let unstakes = Self::decode_smart_contract_amounts(unstakes)?;
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let unstake_many_call = pallet_dapp_staking_v3::Call::<R>::unstake_many { unstakes };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), unstake_many_call)?;
+
+        Ok(true)
+    }
+
     /// Attempts to claim one or more pending staker rewards.
     #[precompile::public("claim_staker_rewards()")]
     fn claim_staker_rewards(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
@@ -768,8 +885,362 @@ where
         Ok(true)
     }
 
+    /// Read whether the given account has opted into auto-restaking of staker rewards.
+    #[precompile::public("auto_restake(bytes)")]
+    #[precompile::view]
+    fn auto_restake(handle: &mut impl PrecompileHandle, staker: DynamicAddress) -> EvmResult<bool> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: StakerPreferencesOf:
+        // Blake2_128Concat(16 + AccountId(32)) + StakerPreferences::max_encoded_len
+        handle.record_db_read::<R>(48 + StakerPreferences::max_encoded_len())?;
+
+        let staker = Self::parse_input_address(staker.into())?;
+
+        Ok(StakerPreferencesOf::<R>::get(&staker).auto_restake)
+    }
+
+    /// Attempts to set whether claimed staker rewards should be automatically re-staked.
+    #[precompile::public("set_auto_restake(bool)")]
+    fn set_auto_restake(handle: &mut impl PrecompileHandle, enabled: bool) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let set_auto_restake_call = pallet_dapp_staking_v3::Call::<R>::set_auto_restake { enabled };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), set_auto_restake_call)?;
+
+        Ok(true)
+    }
+
+    /// Read the account ledger of the given staker.
+    #[precompile::public("account_ledger(bytes)")]
+    #[precompile::view]
+    fn account_ledger(
+        handle: &mut impl PrecompileHandle,
+        staker: DynamicAddress,
+    ) -> EvmResult<PrecompileAccountLedger> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: Ledger:
+        // Blake2_128Concat(16 + AccountId(32)) + Ledger::max_encoded_len
+        handle.record_db_read::<R>(48 + AccountLedgerFor::<R>::max_encoded_len())?;
+
+        let staker = Self::parse_input_address(staker.into())?;
+        let ledger = Ledger::<R>::get(&staker);
+
+        Ok(PrecompileAccountLedger {
+            locked: ledger.active_locked_amount(),
+            unlocking: ledger
+                .unlocking
+                .iter()
+                .map(|chunk| PrecompileUnlockingChunk {
+                    amount: chunk.amount,
+                    unlock_block: chunk.unlock_block.into(),
+                })
+                .collect(),
+            staked: ledger.staked.into(),
+            staked_future: ledger.staked_future.map(Into::into).unwrap_or_default(),
+            contract_stake_count: ledger.contract_stake_count.into(),
+        })
+    }
+
+    /// Read the staking info of the given staker, for the given smart contract.
+    /// In case the staker has no stake on the smart contract, zero values are returned.
+    #[precompile::public("staker_info(bytes,(uint8,bytes))")]
+    #[precompile::view]
+    fn staker_info(
+        handle: &mut impl PrecompileHandle,
+        staker: DynamicAddress,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileStakerInfo> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: StakerInfo:
+        // Blake2_128Concat(16 + AccountId(32)) + Blake2_128Concat(16 + SmartContract::max_encoded_len) + SingularStakingInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            64 + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + SingularStakingInfo::max_encoded_len(),
+        )?;
+
+        let staker = Self::parse_input_address(staker.into())?;
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+
+        let staking_info = match StakerInfo::<R>::get(&staker, &smart_contract) {
+            Some(staking_info) => staking_info,
+            None => {
+                return Ok(PrecompileStakerInfo {
+                    staked: Default::default(),
+                    loyal_staker: false,
+                })
+            }
+        };
+
+        Ok(PrecompileStakerInfo {
+            staked: PrecompileStakeAmount {
+                voting: staking_info.staked_amount(Subperiod::Voting),
+                build_and_earn: staking_info.staked_amount(Subperiod::BuildAndEarn),
+                era: staking_info.era().into(),
+                period: staking_info.period_number().into(),
+            },
+            loyal_staker: staking_info.is_loyal(),
+        })
+    }
+
+    /// Read the stake amount of the given smart contract, valid for the ongoing era.
+    /// In case the smart contract isn't registered, zero values are returned.
+    #[precompile::public("contract_stake((uint8,bytes))")]
+    #[precompile::view]
+    fn contract_stake(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileStakeAmount> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        // Storage item: ContractStake:
+        // Twox64Concat(8) + EraIndex(4) + ContractStakeAmount::max_encoded_len
+        handle.record_db_read::<R>(
+            36 + ProtocolState::max_encoded_len()
+                + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len()
+                + ContractStakeAmount::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+
+        let dapp_info = match IntegratedDApps::<R>::get(&smart_contract) {
+            Some(dapp_info) => dapp_info,
+            None => return Ok(Default::default()),
+        };
+
+        let protocol_state = ActiveProtocolState::<R>::get();
+        let contract_stake = ContractStake::<R>::get(&dapp_info.id);
+
+        Ok(contract_stake
+            .get(protocol_state.era, protocol_state.period_number())
+            .map(Into::into)
+            .unwrap_or_default())
+    }
+
+    /// Read the tier & rank of the given smart contract for the given era.
+    /// Only the unclaimed tier assignments of the registered smart contracts are available.
+    #[precompile::public("dapp_tier((uint8,bytes),uint256)")]
+    #[precompile::view]
+    fn dapp_tier(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+        era: U256,
+    ) -> EvmResult<PrecompileDAppTier> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        // Storage item: DAppTiers:
+        // Twox64Concat(8) + EraIndex(4) + DAppTierRewardsFor::max_encoded_len
+        handle.record_db_read::<R>(
+            28 + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len()
+                + DAppTierRewardsFor::<R>::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+        let era: EraNumber = era
+            .try_into()
+            .map_err::<Revert, _>(|_| RevertReason::value_is_too_large("Era number.").into())
+            .in_field("era")?;
+
+        let dapp_id = match IntegratedDApps::<R>::get(&smart_contract) {
+            Some(dapp_info) => dapp_info.id,
+            None => return Ok(Default::default()),
+        };
+
+        let ranked_tier =
+            DAppTiers::<R>::get(era).and_then(|dapp_tiers| dapp_tiers.dapps.get(&dapp_id).copied());
+
+        Ok(match ranked_tier {
+            Some(ranked_tier) => PrecompileDAppTier {
+                assigned: true,
+                tier: ranked_tier.tier(),
+                rank: ranked_tier.rank(),
+            },
+            None => Default::default(),
+        })
+    }
+
+    /// Read the staker rewards which would be claimed by the next `claim_staker_rewards` call.
+    #[precompile::public("claimable_staker_rewards(bytes)")]
+    #[precompile::view]
+    fn claimable_staker_rewards(
+        handle: &mut impl PrecompileHandle,
+        staker: DynamicAddress,
+    ) -> EvmResult<u128> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: Ledger:
+        // Blake2_128Concat(16 + AccountId(32)) + Ledger::max_encoded_len
+        // Storage item: EraRewards:
+        // Twox64Concat(8) + EraIndex(4) + EraRewardSpanFor::max_encoded_len
+        // Storage item: PeriodEnd:
+        // Twox64Concat(8) + PeriodNumber(4) + PeriodEndInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            80 + ProtocolState::max_encoded_len()
+                + AccountLedgerFor::<R>::max_encoded_len()
+                + EraRewardSpanFor::<R>::max_encoded_len()
+                + PeriodEndInfo::max_encoded_len(),
+        )?;
+
+        let staker = Self::parse_input_address(staker.into())?;
+
+        Ok(DAppStaking::<R>::get_claimable_staker_rewards(&staker))
+    }
+
+    /// Read the bonus reward the given staker can claim for the given smart contract.
+    #[precompile::public("claimable_bonus_reward(bytes,(uint8,bytes))")]
+    #[precompile::view]
+    fn claimable_bonus_reward(
+        handle: &mut impl PrecompileHandle,
+        staker: DynamicAddress,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<u128> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: StakerInfo:
+        // Blake2_128Concat(16 + AccountId(32)) + Blake2_128Concat(16 + SmartContract::max_encoded_len) + SingularStakingInfo::max_encoded_len
+        // Storage item: PeriodEnd:
+        // Twox64Concat(8) + PeriodNumber(4) + PeriodEndInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            84 + ProtocolState::max_encoded_len()
+                + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + SingularStakingInfo::max_encoded_len()
+                + PeriodEndInfo::max_encoded_len(),
+        )?;
+
+        let staker = Self::parse_input_address(staker.into())?;
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+
+        Ok(DAppStaking::<R>::get_claimable_bonus_reward(
+            &staker,
+            &smart_contract,
+        ))
+    }
+
+    /// Read the unclaimed, non-expired dApp rewards of the given smart contract, per era.
+    #[precompile::public("claimable_dapp_rewards((uint8,bytes))")]
+    #[precompile::view]
+    fn claimable_dapp_rewards(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<Vec<PrecompileEraReward>> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        handle.record_db_read::<R>(
+            24 + ProtocolState::max_encoded_len()
+                + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len(),
+        )?;
+        // Storage item: DAppTiers (all the stored entries are read):
+        // Twox64Concat(8) + EraIndex(4) + DAppTierRewardsFor::max_encoded_len
+        for _ in DAppTiers::<R>::iter_keys() {
+            handle.record_db_read::<R>(12 + DAppTierRewardsFor::<R>::max_encoded_len())?;
+        }
+
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+
+        Ok(
+            DAppStaking::<R>::get_claimable_dapp_rewards(&smart_contract)
+                .into_iter()
+                .map(|(era, amount)| PrecompileEraReward {
+                    era: era.into(),
+                    amount,
+                })
+                .collect(),
+        )
+    }
+
+    /// Read the info of the given registered smart contract.
+    #[precompile::public("dapp_info((uint8,bytes))")]
+    #[precompile::view]
+    fn dapp_info(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileDAppInfo> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        handle.record_db_read::<R>(
+            16 + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+
+        let dapp_info = IntegratedDApps::<R>::get(&smart_contract)
+            .ok_or(revert("Smart contract is not registered."))?;
+
+        Ok(PrecompileDAppInfo {
+            owner: <AccountId as AsRef<[u8]>>::as_ref(&dapp_info.owner).into(),
+            reward_beneficiary: <AccountId as AsRef<[u8]>>::as_ref(dapp_info.reward_beneficiary())
+                .into(),
+            id: dapp_info.id.into(),
+        })
+    }
+
+    /// Read the metadata of the given registered smart contract.
+    #[precompile::public("dapp_metadata((uint8,bytes))")]
+    #[precompile::view]
+    fn dapp_metadata(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileDAppMetadata> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: DAppMetadataOf:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppMetadataFor::max_encoded_len
+        handle.record_db_read::<R>(
+            16 + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
+                + DAppMetadataFor::<R>::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_and_resolve_smart_contract(handle, smart_contract)?;
+
+        let metadata = DAppMetadataOf::<R>::get(&smart_contract)
+            .ok_or(revert("Smart contract has no metadata."))?;
+
+        Ok(PrecompileDAppMetadata {
+            name: metadata.name.into_inner().into(),
+            website: metadata.website.into_inner().into(),
+            ipfs_cid: metadata.ipfs_cid.into_inner().into(),
+            category: dapp_category_id(&metadata.category),
+        })
+    }
+
     // Utility functions
 
+    /// Helper method to resolve a contract alias to the registered smart contract it belongs to.
+    pub(crate) fn resolve_smart_contract(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: <R as pallet_dapp_staking_v3::Config>::SmartContract,
+    ) -> EvmResult<<R as pallet_dapp_staking_v3::Config>::SmartContract> {
+        // Storage item: ContractAliases:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + SmartContract::max_encoded_len
+        handle.record_db_read::<R>(
+            16 + 2 * <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len(),
+        )?;
+
+        Ok(DAppStaking::<R>::resolve_smart_contract(smart_contract))
+    }
+
+    /// Helper method to decode smart contract struct for v2 calls, resolving a contract alias
+    /// to the registered smart contract it belongs to.
+    pub(crate) fn decode_and_resolve_smart_contract(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<<R as pallet_dapp_staking_v3::Config>::SmartContract> {
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        Self::resolve_smart_contract(handle, smart_contract)
+    }
+
     /// Helper method to decode smart contract struct for v2 calls
     pub(crate) fn decode_smart_contract(
         smart_contract: SmartContractV2,
@@ -798,6 +1269,31 @@ where
         Ok(smart_contract)
     }
 
+    /// Helper method to decode a list of smart contract & amount pairs for batch calls
+    pub(crate) fn decode_smart_contract_amounts(
+        entries: BoundedVec<(SmartContractV2, Balance), R::MaxNumberOfStakedContracts>,
+    ) -> EvmResult<
+        frame_support::BoundedVec<
+            (
+                <R as pallet_dapp_staking_v3::Config>::SmartContract,
+                Balance,
+            ),
+            R::MaxNumberOfStakedContracts,
+        >,
+    > {
+        let entries: Vec<_> = entries.into();
+        let entries = entries
+            .into_iter()
+            .map(|(smart_contract, amount)| {
+                Ok((Self::decode_smart_contract(smart_contract)?, amount))
+            })
+            .collect::<EvmResult<Vec<_>>>()?;
+
+        entries
+            .try_into()
+            .map_err(|_| revert("Too many smart contract entries."))
+    }
+
     /// Helper method to parse H160 or SS58 address
     pub(crate) fn parse_input_address(staker_vec: Vec<u8>) -> EvmResult<R::AccountId> {
         let staker: R::AccountId = match staker_vec.len() {
@@ -832,3 +1328,15 @@ pub(crate) fn subperiod_id(subperiod: &Subperiod) -> u8 {
         Subperiod::BuildAndEarn => 1,
     }
 }
+
+/// Numeric Id of the dApp category enum value.
+pub(crate) fn dapp_category_id(category: &DAppCategory) -> u8 {
+    match category {
+        DAppCategory::Defi => 0,
+        DAppCategory::Nft => 1,
+        DAppCategory::Gaming => 2,
+        DAppCategory::Infrastructure => 3,
+        DAppCategory::Social => 4,
+        DAppCategory::Other => 5,
+    }
+}
@@ -42,8 +42,8 @@ extern crate alloc;
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, PeriodNumber, SmartContract, StakingRewardHandler,
-        StandardTierSlots,
+        CycleConfiguration, EraNumber, PeriodNumber, RewardKind, SmartContract,
+        StakingRewardHandler, StandardTierSlots,
     },
     oracle::PriceProvider,
     AccountId, Balance, BlockNumber,
@@ -211,7 +211,7 @@ impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
         Balance::from(3_000_000_u128)
     }
 
-    fn payout_reward(beneficiary: &AccountId, reward: Balance) -> Result<(), ()> {
+    fn payout_reward(beneficiary: &AccountId, reward: Balance, _: RewardKind) -> Result<(), ()> {
         let _ = Balances::mint_into(beneficiary, reward);
         Ok(())
     }
@@ -252,11 +252,13 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<MockSmartContract, AccountId>
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const DappStakingMaxClaimBountyRate: Perbill = Perbill::from_percent(1);
 }
 
 impl pallet_dapp_staking_v3::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = MockSmartContract;
     type ContractRegisterOrigin = frame_system::EnsureRoot<AccountId>;
@@ -279,6 +281,15 @@ impl pallet_dapp_staking_v3::Config for Test {
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierAssignment = pallet_dapp_staking_v3::StandardTierAssignment;
+    type MaxClaimBountyRate = DappStakingMaxClaimBountyRate;
+    type MaxClaimBounty = ConstU128<1_000_000>;
+    type MaxArchivedPeriods = ConstU32<3>;
+    type MetadataDepositBase = ConstU128<10>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type MaxMetadataFieldLength = ConstU32<32>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type MaxContractAliases = ConstU32<4>;
     type WeightInfo = pallet_dapp_staking_v3::weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -330,6 +341,7 @@ impl ExternalityBuilder {
                     },
                 ],
                 slots_per_tier: vec![10, 20, 30, 40],
+                min_stakers: vec![],
                 safeguard: None,
                 _config: PhantomData,
             },
@@ -18,7 +18,7 @@
 
 extern crate alloc;
 use crate::{test::mock::*, *};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, BoundedVec};
 use frame_system::RawOrigin;
 use precompile_utils::testing::*;
 use sp_core::H160;
@@ -29,7 +29,9 @@ use astar_primitives::{
     dapp_staking::{CycleConfiguration, EraNumber},
     BlockNumber,
 };
-use pallet_dapp_staking_v3::ActiveProtocolState;
+use pallet_dapp_staking_v3::{
+    ActiveProtocolState, ContractStake, DAppTiers, IntegratedDApps, Ledger, StakerInfo,
+};
 
 #[test]
 fn protocol_state_is_ok() {
@@ -304,6 +306,115 @@ fn unstake_is_ok() {
     });
 }
 
+#[test]
+fn stake_many_and_unstake_many_are_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a couple of dApps for staking
+        let staker_h160 = ALICE;
+        let smart_contract_addresses = [[0xAF; 32], [0xBF; 32]];
+        for smart_contract_address in smart_contract_addresses {
+            assert_ok!(DappStaking::register(
+                RawOrigin::Root.into(),
+                AddressMapper::into_account_id(staker_h160),
+                <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+                    smart_contract_address.into()
+                ),
+            ));
+        }
+
+        // Lock some amount which will be used for staking
+        let amount = 2000;
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(AddressMapper::into_account_id(staker_h160)).into(),
+            amount,
+        ));
+
+        let entries = |amount: Balance| -> Vec<(SmartContractV2, Balance)> {
+            smart_contract_addresses
+                .into_iter()
+                .map(|smart_contract_address| {
+                    (
+                        SmartContractV2 {
+                            contract_type: SmartContractTypes::Wasm,
+                            address: smart_contract_address.into(),
+                        },
+                        amount,
+                    )
+                })
+                .collect()
+        };
+
+        // Stake on both dApps and verify events
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::stake_many {
+                    stakes: entries(amount / 2).into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 2);
+        for event in events {
+            assert_matches!(
+                event,
+                pallet_dapp_staking_v3::Event::Stake { amount: stake_amount, .. }
+                    if stake_amount == amount / 2
+            );
+        }
+
+        // Unstake from both dApps and verify events
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::unstake_many {
+                    unstakes: entries(amount / 4).into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 2);
+        for event in events {
+            assert_matches!(
+                event,
+                pallet_dapp_staking_v3::Event::Unstake { amount: unstake_amount, .. }
+                    if unstake_amount == amount / 4
+            );
+        }
+
+        // Unstaking more than what's staked on one of the dApps fails, and nothing is unstaked
+        let staker = AddressMapper::into_account_id(staker_h160);
+        let pre_ledger = Ledger::<Test>::get(&staker);
+        let mut unstakes = entries(amount / 4);
+        unstakes[1].1 = amount;
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::unstake_many {
+                    unstakes: unstakes.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                alloc::str::from_utf8(&output)
+                    .unwrap()
+                    .contains("UnstakeAmountTooLarge")
+            });
+        assert_eq!(Ledger::<Test>::get(&staker), pre_ledger);
+    });
+}
+
 #[test]
 fn claim_staker_rewards_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -527,3 +638,572 @@ fn cleanup_expired_entries_is_ok() {
         );
     });
 }
+
+#[test]
+fn set_auto_restake_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let staker_h160 = ALICE;
+        let dynamic_addresses = into_dynamic_addresses(staker_h160);
+
+        // Lock some amount, so the preference can be set
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(AddressMapper::into_account_id(staker_h160)).into(),
+            1234,
+        ));
+
+        // Sanity check - auto-restake is disabled by default
+        for staker in &dynamic_addresses {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::auto_restake {
+                        staker: staker.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(false);
+        }
+
+        // Enable auto-restake and verify event
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::set_auto_restake { enabled: true },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_matches!(
+            events[0].clone(),
+            pallet_dapp_staking_v3::Event::AutoRestakeSet { enabled, .. } if enabled
+        );
+
+        for staker in &dynamic_addresses {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::auto_restake {
+                        staker: staker.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+        }
+    });
+}
+
+#[test]
+fn account_ledger_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let staker_h160 = ALICE;
+        let dynamic_addresses = into_dynamic_addresses(staker_h160);
+
+        // Empty ledger is returned for an account without any locked funds
+        for staker in &dynamic_addresses {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::account_ledger {
+                        staker: staker.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(PrecompileAccountLedger {
+                    locked: 0,
+                    unlocking: vec![],
+                    staked: Default::default(),
+                    staked_future: Default::default(),
+                    contract_stake_count: 0.into(),
+                });
+        }
+
+        // Register a dApp, stake on it & unlock some of the remaining locked amount
+        let smart_contract =
+            <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm([0xAF; 32].into());
+        let amount = 1234;
+        register_and_stake(staker_h160, smart_contract.clone(), amount);
+
+        let staker_native = AddressMapper::into_account_id(staker_h160);
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(staker_native.clone()).into(),
+            100,
+        ));
+        assert_ok!(DappStaking::unlock(
+            RawOrigin::Signed(staker_native.clone()).into(),
+            30,
+        ));
+
+        let ledger = Ledger::<Test>::get(&staker_native);
+        let expected_outcome = PrecompileAccountLedger {
+            locked: amount + 100 - 30,
+            unlocking: vec![PrecompileUnlockingChunk {
+                amount: 30,
+                unlock_block: ledger.unlocking[0].unlock_block.into(),
+            }],
+            staked: ledger.staked.into(),
+            staked_future: ledger
+                .staked_future
+                .expect("Stake is in the future.")
+                .into(),
+            contract_stake_count: 1.into(),
+        };
+
+        for staker in &dynamic_addresses {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::account_ledger {
+                        staker: staker.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(expected_outcome.clone());
+        }
+    });
+}
+
+#[test]
+fn staker_info_and_contract_stake_are_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let staker_h160 = ALICE;
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Zero values are returned for a smart contract which isn't registered
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::staker_info {
+                    staker: staker_h160.as_bytes().try_into().unwrap(),
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakerInfo {
+                staked: Default::default(),
+                loyal_staker: false,
+            });
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::contract_stake {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakeAmount::default());
+
+        // Register a dApp and stake on it during the voting subperiod
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(staker_h160, smart_contract.clone(), amount);
+
+        // Stake is only valid from the next era
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::contract_stake {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakeAmount::default());
+
+        advance_to_next_era();
+        let protocol_state = ActiveProtocolState::<Test>::get();
+
+        let staking_info = StakerInfo::<Test>::get(
+            &AddressMapper::into_account_id(staker_h160),
+            &smart_contract,
+        )
+        .expect("Entry must exist since stake was made.");
+        for staker in into_dynamic_addresses(staker_h160) {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::staker_info {
+                        staker,
+                        smart_contract: smart_contract_v2.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(PrecompileStakerInfo {
+                    staked: PrecompileStakeAmount {
+                        voting: amount,
+                        build_and_earn: 0,
+                        era: staking_info.era().into(),
+                        period: protocol_state.period_number().into(),
+                    },
+                    loyal_staker: true,
+                });
+        }
+
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        let contract_stake = ContractStake::<Test>::get(&dapp_id)
+            .get(protocol_state.era, protocol_state.period_number())
+            .expect("Stake must be valid in the ongoing era.");
+        assert_eq!(contract_stake.voting, amount);
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::contract_stake {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakeAmount::from(contract_stake));
+
+        // Same values are returned when the smart contract is queried via its alias
+        let alias_address = [0xAE; 32];
+        let alias_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: alias_address.into(),
+        };
+        assert_ok!(DappStaking::add_contract_alias(
+            RawOrigin::Root.into(),
+            smart_contract,
+            <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(alias_address.into()),
+        ));
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::staker_info {
+                    staker: staker_h160.as_bytes().try_into().unwrap(),
+                    smart_contract: alias_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakerInfo {
+                staked: PrecompileStakeAmount {
+                    voting: amount,
+                    build_and_earn: 0,
+                    era: staking_info.era().into(),
+                    period: protocol_state.period_number().into(),
+                },
+                loyal_staker: true,
+            });
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::contract_stake {
+                    smart_contract: alias_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakeAmount::from(contract_stake));
+    });
+}
+
+#[test]
+fn dapp_tier_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it
+        let staker_h160 = ALICE;
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(staker_h160, smart_contract.clone(), amount);
+
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Advance to 3rd era so the dApp has a tier assignment for the 2nd era
+        advance_to_era(3);
+        let tier_era: EraNumber = 2;
+
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        let ranked_tier = DAppTiers::<Test>::get(tier_era)
+            .and_then(|dapp_tiers| dapp_tiers.dapps.get(&dapp_id).copied())
+            .expect("dApp must be assigned into a tier.");
+
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::dapp_tier {
+                    smart_contract: smart_contract_v2.clone(),
+                    era: tier_era.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppTier {
+                assigned: true,
+                tier: ranked_tier.tier(),
+                rank: ranked_tier.rank(),
+            });
+
+        // No tier assignment exists for the ongoing era
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::dapp_tier {
+                    smart_contract: smart_contract_v2.clone(),
+                    era: 3.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppTier::default());
+
+        // Tier assignment is no longer available once the reward has been claimed
+        assert_ok!(DappStaking::claim_dapp_reward(
+            RawOrigin::Signed(AddressMapper::into_account_id(staker_h160)).into(),
+            smart_contract,
+            tier_era,
+        ));
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::dapp_tier {
+                    smart_contract: smart_contract_v2,
+                    era: tier_era.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppTier::default());
+    });
+}
+
+#[test]
+fn claimable_rewards_are_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it, loyally
+        let staker_h160 = ALICE;
+        let staker_native = AddressMapper::into_account_id(staker_h160);
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(staker_h160, smart_contract.clone(), amount);
+
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Advance to the next period, so all reward types can be claimed
+        advance_to_next_period();
+
+        let staker_reward = DappStaking::get_claimable_staker_rewards(&staker_native);
+        assert!(!staker_reward.is_zero());
+        for staker in into_dynamic_addresses(staker_h160) {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::claimable_staker_rewards { staker },
+                )
+                .expect_no_logs()
+                .execute_returns(staker_reward);
+        }
+
+        let bonus_reward = DappStaking::get_claimable_bonus_reward(&staker_native, &smart_contract);
+        assert!(!bonus_reward.is_zero());
+        for staker in into_dynamic_addresses(staker_h160) {
+            precompiles()
+                .prepare_test(
+                    staker_h160,
+                    precompile_address(),
+                    PrecompileCall::claimable_bonus_reward {
+                        staker,
+                        smart_contract: smart_contract_v2.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(bonus_reward);
+        }
+
+        let dapp_rewards = DappStaking::get_claimable_dapp_rewards(&smart_contract);
+        assert!(!dapp_rewards.is_empty());
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::claimable_dapp_rewards {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(
+                dapp_rewards
+                    .into_iter()
+                    .map(|(era, amount)| PrecompileEraReward {
+                        era: era.into(),
+                        amount,
+                    })
+                    .collect::<Vec<_>>(),
+            );
+    });
+}
+
+#[test]
+fn dapp_info_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let owner_h160 = ALICE;
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Reverts if the smart contract isn't registered
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_info {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Smart contract is not registered.");
+
+        // Register a dApp
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        register_and_stake(owner_h160, smart_contract.clone(), 1234);
+
+        let owner_native = AddressMapper::into_account_id(owner_h160);
+        let owner_bytes: DynamicAddress = <AccountId as AsRef<[u8]>>::as_ref(&owner_native)
+            .try_into()
+            .unwrap();
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // Reward beneficiary defaults to the owner
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_info {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppInfo {
+                owner: owner_bytes.clone(),
+                reward_beneficiary: owner_bytes.clone(),
+                id: dapp_id.into(),
+            });
+
+        // Set a dedicated reward beneficiary
+        let beneficiary = AddressMapper::into_account_id(H160::repeat_byte(0xBB));
+        assert_ok!(DappStaking::set_dapp_reward_beneficiary(
+            RawOrigin::Signed(owner_native).into(),
+            smart_contract,
+            Some(beneficiary.clone()),
+        ));
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_info {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppInfo {
+                owner: owner_bytes,
+                reward_beneficiary: <AccountId as AsRef<[u8]>>::as_ref(&beneficiary)
+                    .try_into()
+                    .unwrap(),
+                id: dapp_id.into(),
+            });
+    });
+}
+
+#[test]
+fn dapp_metadata_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let owner_h160 = ALICE;
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Register a dApp
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        register_and_stake(owner_h160, smart_contract.clone(), 1234);
+
+        // Reverts if the smart contract has no metadata
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_metadata {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"Smart contract has no metadata.");
+
+        // Set the metadata & check it's returned
+        let owner_native = AddressMapper::into_account_id(owner_h160);
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RawOrigin::Signed(owner_native).into(),
+            smart_contract,
+            BoundedVec::truncate_from(b"Astar dApp".to_vec()),
+            BoundedVec::truncate_from(b"https://astar.network".to_vec()),
+            BoundedVec::truncate_from(b"cid".to_vec()),
+            DAppCategory::Gaming,
+        ));
+        precompiles()
+            .prepare_test(
+                owner_h160,
+                precompile_address(),
+                PrecompileCall::dapp_metadata {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppMetadata {
+                name: b"Astar dApp".to_vec().into(),
+                website: b"https://astar.network".to_vec().into(),
+                ipfs_cid: b"cid".to_vec().into(),
+                category: 2,
+            });
+    });
+}
@@ -127,8 +127,31 @@ pub trait StakingRewardHandler<AccountId> {
     /// Returns the bonus reward pool for a period.
     fn bonus_reward_pool() -> Balance;
 
-    /// Attempts to pay out the rewards to the beneficiary.
-    fn payout_reward(beneficiary: &AccountId, reward: Balance) -> Result<(), ()>;
+    /// Attempts to pay out the rewards of the specified kind to the beneficiary.
+    fn payout_reward(beneficiary: &AccountId, reward: Balance, kind: RewardKind) -> Result<(), ()>;
+}
+
+/// Kind of the reward paid out by the dApp staking protocol.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RewardKind {
+    /// Reward for staking on dApps, including the claim bounty & the shared dApp rewards.
+    Staker,
+    /// Bonus reward for loyal stakers.
+    Bonus,
+    /// Tier reward for dApps.
+    DApp,
+}
+
+/// Observer of the burned native currency issuance.
+///
+/// Used to keep track of the issuance reduction, e.g. burned transaction fees.
+pub trait OnIssuanceBurned {
+    /// Called after `amount` of the native currency has been burned.
+    fn on_issuance_burned(amount: Balance);
+}
+
+impl OnIssuanceBurned for () {
+    fn on_issuance_burned(_amount: Balance) {}
 }
 
 /// Trait defining the interface for dApp staking `smart contract types` handler.
@@ -154,6 +177,7 @@ pub trait SmartContractHandle<AccountId> {
     Hash,
     scale_info::TypeInfo,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum SmartContract<AccountId> {
     /// EVM smart contract instance.
     Evm(H160),
@@ -202,6 +226,7 @@ impl TierSlots for StandardTierSlots {
 /// First 4 bits are used to hold rank and second 4 bits are used to hold tier.
 /// i.e: 0xa1 will hold rank: 10 and tier: 1 (0xa1 & 0xf == 1; 0xa1 >> 4 == 10;)
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct RankedTier(u8);
 
 impl RankedTier {
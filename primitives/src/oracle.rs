@@ -16,23 +16,57 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use frame_support::{pallet_prelude::*, traits::Time};
-use sp_arithmetic::fixed_point::FixedU128;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Contains, SortedMembers, Time},
+};
+use pallet_xc_asset_config::XcAssetLocation;
+use sp_arithmetic::{fixed_point::FixedU128, traits::Saturating};
 use sp_std::vec::Vec;
 
+use crate::AssetId;
+
 /// Interface for fetching price of the native token.
 pub trait PriceProvider {
     /// Get the price of the native token.
     fn average_price() -> Price;
+
+    /// `true` if the price is considered stale, i.e. the price feed hasn't been updated for a while.
+    fn is_price_stale() -> bool {
+        false
+    }
+
+    /// Get the price of the specified currency, if it's known.
+    fn price_of(_currency_id: CurrencyId) -> Option<Price> {
+        None
+    }
 }
 
 pub type Price = FixedU128;
 pub type CurrencyAmount = FixedU128;
 
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum CurrencyId {
     ASTR,
     SDN,
+    /// Cross-chain asset, registered in the `pallet-xc-asset-config`.
+    XcAsset(#[codec(compact)] AssetId),
+}
+
+/// Filter which accepts only the cross-chain assets with a known location.
+pub struct RegisteredXcAssets<AssetMapper>(PhantomData<AssetMapper>);
+impl<AssetMapper: XcAssetLocation<AssetId>> Contains<CurrencyId>
+    for RegisteredXcAssets<AssetMapper>
+{
+    fn contains(currency_id: &CurrencyId) -> bool {
+        match currency_id {
+            CurrencyId::XcAsset(asset_id) => {
+                AssetMapper::get_xc_asset_location(*asset_id).is_some()
+            }
+            _ => false,
+        }
+    }
 }
 
 type TimestampedValue<T, I = ()> =
@@ -51,3 +85,52 @@ impl<T: orml_oracle::Config<I>, I> orml_traits::CombineData<CurrencyId, Timestam
         None
     }
 }
+
+/// Combines the fresh oracle values into their median.
+///
+/// Values older than `ExpiresIn` are ignored. In case fresh values weren't provided by a majority of the oracle members,
+/// the previously combined value is kept.
+/// For an even number of fresh values, the average of the two middle values is used, together with the older timestamp.
+pub struct MedianCombineData<T, ExpiresIn, I = ()>(PhantomData<(T, ExpiresIn, I)>);
+impl<T, ExpiresIn, I> orml_traits::CombineData<CurrencyId, TimestampedValue<T, I>>
+    for MedianCombineData<T, ExpiresIn, I>
+where
+    T: orml_oracle::Config<I>,
+    ExpiresIn: Get<<<T as orml_oracle::Config<I>>::Time as Time>::Moment>,
+{
+    fn combine_data(
+        _key: &CurrencyId,
+        values: Vec<TimestampedValue<T, I>>,
+        prev_value: Option<TimestampedValue<T, I>>,
+    ) -> Option<TimestampedValue<T, I>> {
+        let now = T::Time::now();
+        let expires_in = ExpiresIn::get();
+
+        let mut fresh_values: Vec<_> = values
+            .into_iter()
+            .filter(|value| value.timestamp.saturating_add(expires_in) > now)
+            .collect();
+
+        let count = fresh_values.len();
+        let quorum = T::Members::count().saturating_div(2).saturating_add(1);
+        if count < quorum {
+            return prev_value;
+        }
+
+        fresh_values.sort_unstable_by(|a, b| a.value.cmp(&b.value));
+        let mid = count / 2;
+
+        if count % 2 == 0 {
+            let (lower, upper) = (&fresh_values[mid.saturating_sub(1)], &fresh_values[mid]);
+            Some(TimestampedValue::<T, I> {
+                value: lower
+                    .value
+                    .saturating_add(upper.value)
+                    .saturating_mul(Price::from_rational(1, 2)),
+                timestamp: lower.timestamp.min(upper.timestamp),
+            })
+        } else {
+            Some(fresh_values.swap_remove(mid))
+        }
+    }
+}
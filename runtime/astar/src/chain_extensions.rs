@@ -20,6 +20,7 @@ use super::Runtime;
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
 use pallet_contracts::chain_extension::RegisteredChainExtension;
 
 // Following impls defines chain extension IDs.
@@ -27,4 +28,8 @@ impl RegisteredChainExtension<Runtime> for AssetsExtension<Runtime> {
     const ID: u16 = 02;
 }
 
-pub type AstarChainExtensions<Runtime> = AssetsExtension<Runtime>;
+impl RegisteredChainExtension<Runtime> for DappStakingExtension<Runtime> {
+    const ID: u16 = 04;
+}
+
+pub type AstarChainExtensions<Runtime> = (AssetsExtension<Runtime>, DappStakingExtension<Runtime>);
@@ -92,4 +92,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Inflation::ScheduledInflationParams` (r:1 w:1)
+	/// Proof: `Inflation::ScheduledInflationParams` (`max_values`: Some(1), `max_size`: Some(545), added: 1040, mode: `MaxEncodedLen`)
+	fn schedule_inflation_params() -> Weight {
+		Weight::from_parts(12_742_000, 2030)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Inflation::ScheduledInflationParams` (r:1 w:1)
+	/// Proof: `Inflation::ScheduledInflationParams` (`max_values`: Some(1), `max_size`: Some(545), added: 1040, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_inflation_params() -> Weight {
+		Weight::from_parts(11_503_000, 2030)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
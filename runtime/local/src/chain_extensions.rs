@@ -20,6 +20,7 @@ use super::{Runtime, UnifiedAccounts};
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
 use pallet_contracts::chain_extension::RegisteredChainExtension;
 
 pub use pallet_chain_extension_unified_accounts::UnifiedAccountsExtension;
@@ -30,6 +31,10 @@ impl RegisteredChainExtension<Runtime> for AssetsExtension<Runtime> {
     const ID: u16 = 02;
 }
 
+impl RegisteredChainExtension<Runtime> for DappStakingExtension<Runtime> {
+    const ID: u16 = 04;
+}
+
 impl RegisteredChainExtension<Runtime> for UnifiedAccountsExtension<Runtime, UnifiedAccounts> {
     const ID: u16 = 03;
 }
@@ -37,4 +42,5 @@ impl RegisteredChainExtension<Runtime> for UnifiedAccountsExtension<Runtime, Uni
 pub type LocalChainExtensions<Runtime, UnifiedAccounts> = (
     AssetsExtension<Runtime>,
     UnifiedAccountsExtension<Runtime, UnifiedAccounts>,
+    DappStakingExtension<Runtime>,
 );
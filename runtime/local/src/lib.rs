@@ -312,7 +312,7 @@ impl pallet_balances::Config for Runtime {
     type Balance = Balance;
     /// The ubiquitous event type.
     type RuntimeEvent = RuntimeEvent;
-    type DustRemoval = ();
+    type DustRemoval = pallet_inflation::BurnAndRecord<Runtime>;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = weights::pallet_balances::SubstrateWeight<Runtime>;
@@ -393,7 +393,7 @@ impl WeightToFeePolynomial for WeightToFee {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+    type OnChargeTransaction = FungibleAdapter<Balances, pallet_inflation::BurnAndRecord<Runtime>>;
     type WeightToFee = WeightToFee;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type FeeMultiplierUpdate = TargetedFeeAdjustment<
@@ -460,11 +460,16 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<SmartContract<AccountId>, AccountId
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const DappStakingMaxClaimBountyRate: Perbill = Perbill::from_percent(1);
+    pub const DappStakingMaxClaimBounty: Balance = 1 * AST;
+    pub const DappStakingMetadataDepositBase: Balance = deposit(1, 49);
+    pub const DappStakingMetadataDepositPerByte: Balance = deposit(0, 1);
 }
 
 impl pallet_dapp_staking_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ContractRegisterOrigin = EnsureRootOrTwoThirdsCommunityCouncil;
@@ -487,6 +492,15 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MinimumStakeAmount = ConstU128<AST>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierAssignment = pallet_dapp_staking_v3::StandardTierAssignment;
+    type MaxClaimBountyRate = DappStakingMaxClaimBountyRate;
+    type MaxClaimBounty = DappStakingMaxClaimBounty;
+    type MaxArchivedPeriods = ConstU32<16>;
+    type MetadataDepositBase = DappStakingMetadataDepositBase;
+    type MetadataDepositPerByte = DappStakingMetadataDepositPerByte;
+    type MaxMetadataFieldLength = ConstU32<128>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type MaxContractAliases = ConstU32<8>;
     type WeightInfo = pallet_dapp_staking_v3::weights::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -527,6 +541,9 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type ScheduleOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type MaxScheduledParams = ConstU32<8>;
+    type TargetNetInflation = ConstBool<false>;
     type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
 }
 
@@ -548,6 +565,7 @@ impl pallet_unified_accounts::Config for Runtime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type OnStorageFeeBurned = Inflation;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -626,7 +644,9 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesType = Precompiles;
     type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
-    type OnChargeTransaction = pallet_evm::EVMFungibleAdapter<Balances, ()>;
+    // Base fee is burned.
+    type OnChargeTransaction =
+        pallet_evm::EVMFungibleAdapter<Balances, pallet_inflation::BurnAndRecord<Runtime>>;
     type BlockGasLimit = BlockGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
@@ -1014,7 +1034,7 @@ impl pallet_democracy::Config for Runtime {
     type PalletsOrigin = OriginCaller;
     type Preimages = Preimage;
     type Scheduler = Scheduler;
-    type Slash = ();
+    type Slash = pallet_inflation::BurnImbalanceAndRecord<Runtime>;
     type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1775,7 +1795,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
+    impl dapp_staking_v3_runtime_api::DappStakingApi<Block, AccountId, SmartContract<AccountId>> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
             InflationCycleConfig::periods_per_cycle()
         }
@@ -1795,6 +1815,102 @@ impl_runtime_apis! {
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn protocol_state() -> pallet_dapp_staking_v3::ProtocolState {
+            pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get()
+        }
+
+        fn account_ledger(account: AccountId) -> dapp_staking_v3_runtime_api::AccountLedgerInfo {
+            dapp_staking_v3_runtime_api::AccountLedgerInfo::new(
+                pallet_dapp_staking_v3::Ledger::<Runtime>::get(&account),
+                pallet_dapp_staking_v3::StakerPreferencesOf::<Runtime>::get(&account),
+            )
+        }
+
+        fn staker_info(
+            account: AccountId,
+            smart_contract: SmartContract<AccountId>,
+        ) -> Option<pallet_dapp_staking_v3::SingularStakingInfo> {
+            let smart_contract = DappStaking::resolve_smart_contract(smart_contract);
+            pallet_dapp_staking_v3::StakerInfo::<Runtime>::get(&account, &smart_contract)
+        }
+
+        fn contract_stake(
+            smart_contract: SmartContract<AccountId>,
+        ) -> Option<pallet_dapp_staking_v3::ContractStakeAmount> {
+            let smart_contract = DappStaking::resolve_smart_contract(smart_contract);
+            pallet_dapp_staking_v3::IntegratedDApps::<Runtime>::get(&smart_contract)
+                .map(|dapp_info| {
+                    pallet_dapp_staking_v3::ContractStake::<Runtime>::get(&dapp_info.id)
+                })
+        }
+
+        fn claimable_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::get_claimable_staker_rewards(&account)
+        }
+
+        fn claimable_staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)> {
+            DappStaking::get_claimable_staker_rewards_per_era(&account)
+        }
+
+        fn claimable_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::get_claimable_bonus_rewards(&account)
+        }
+
+        fn claimable_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            DappStaking::get_claimable_dapp_rewards(&smart_contract)
+        }
+
+        fn contract_staker_count(smart_contract: SmartContract<AccountId>) -> u32 {
+            DappStaking::get_contract_staker_count(&smart_contract)
+        }
+
+        fn dapp_period_archive(dapp_id: DAppId) -> Vec<dapp_staking_v3_runtime_api::DAppPeriodSummaryInfo> {
+            DappStaking::get_dapp_period_archive(dapp_id).into_iter().map(Into::into).collect()
+        }
+
+        fn simulate_dapp_tier_assignment(stake_deltas: Vec<(SmartContract<AccountId>, i128)>) -> dapp_staking_v3_runtime_api::DAppTierRewardsInfo {
+            DappStaking::simulate_dapp_tier_assignment(stake_deltas).into()
+        }
+
+        fn dapp_metadata(smart_contract: SmartContract<AccountId>) -> Option<dapp_staking_v3_runtime_api::DAppMetadataInfo> {
+            DappStaking::get_dapp_metadata(&smart_contract).map(Into::into)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn inflation_parameters() -> InflationParameters {
+            pallet_inflation::InflationParams::<Runtime>::get()
+        }
+
+        fn projected_inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            Inflation::projected_inflation_configuration()
+        }
+
+        fn staker_apr(total_value_staked: Balance) -> Perquintill {
+            Inflation::staker_apr(total_value_staked)
+        }
+
+        fn cycle_issuance() -> pallet_inflation::CycleIssuance {
+            pallet_inflation::CurrentCycleIssuance::<Runtime>::get()
+        }
+    }
+
+    // Price aggregator isn't part of the local runtime, which uses a static price instead.
+    impl price_aggregator_runtime_api::PriceAggregatorApi<Block> for Runtime {
+        fn tracked_currencies() -> Vec<astar_primitives::oracle::CurrencyId> {
+            Vec::new()
+        }
+
+        fn aggregation_state(
+            _currency_id: astar_primitives::oracle::CurrencyId,
+        ) -> Option<price_aggregator_runtime_api::AggregationState> {
+            None
+        }
     }
 
 
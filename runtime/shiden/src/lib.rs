@@ -80,7 +80,7 @@ use astar_primitives::{
     },
     evm::EvmRevertCodeHandler,
     governance::OracleMembershipInst,
-    oracle::{CurrencyId, DummyCombineData, Price},
+    oracle::{CurrencyId, MedianCombineData, Price, RegisteredXcAssets},
     xcm::AssetLocationIdConverter,
     Address, AssetId, BlockNumber, Hash, Header, Nonce,
 };
@@ -356,7 +356,7 @@ impl pallet_identity::Config for Runtime {
     type MaxSubAccounts = MaxSubAccounts;
     type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
     type MaxRegistrars = MaxRegistrars;
-    type Slashed = ();
+    type Slashed = pallet_inflation::BurnImbalanceAndRecord<Runtime>;
     type ForceOrigin = EnsureRoot<<Self as frame_system::Config>::AccountId>;
     type RegistrarOrigin = EnsureRoot<<Self as frame_system::Config>::AccountId>;
     type OffchainSignature = Signature;
@@ -425,11 +425,16 @@ impl TierSlotsFunc for ShidenTierSlots {
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 50 * SDN;
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const DappStakingMaxClaimBountyRate: Perbill = Perbill::from_percent(1);
+    pub const DappStakingMaxClaimBounty: Balance = 1 * SDN;
+    pub const DappStakingMetadataDepositBase: Balance = deposit(1, 49);
+    pub const DappStakingMetadataDepositPerByte: Balance = deposit(0, 1);
 }
 
 impl pallet_dapp_staking_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ContractRegisterOrigin = frame_system::EnsureRoot<AccountId>;
@@ -452,6 +457,15 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierAssignment = pallet_dapp_staking_v3::StandardTierAssignment;
+    type MaxClaimBountyRate = DappStakingMaxClaimBountyRate;
+    type MaxClaimBounty = DappStakingMaxClaimBounty;
+    type MaxArchivedPeriods = ConstU32<16>;
+    type MetadataDepositBase = DappStakingMetadataDepositBase;
+    type MetadataDepositPerByte = DappStakingMetadataDepositPerByte;
+    type MaxMetadataFieldLength = ConstU32<128>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type MaxContractAliases = ConstU32<8>;
     type WeightInfo = weights::pallet_dapp_staking_v3::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = DAppStakingBenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -492,6 +506,9 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxScheduledParams = ConstU32<8>;
+    type TargetNetInflation = ConstBool<false>;
     type WeightInfo = weights::pallet_inflation::SubstrateWeight<Runtime>;
 }
 
@@ -619,7 +636,7 @@ parameter_types! {
 
 impl pallet_balances::Config for Runtime {
     type Balance = Balance;
-    type DustRemoval = ();
+    type DustRemoval = pallet_inflation::BurnAndRecord<Runtime>;
     type RuntimeEvent = RuntimeEvent;
     type MaxLocks = MaxLocks;
     type MaxReserves = MaxReserves;
@@ -811,7 +828,7 @@ impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithFees {
             }
 
             // burn part of the fees
-            drop(to_burn);
+            <pallet_inflation::BurnAndRecord<Runtime> as OnUnbalanced<_>>::on_unbalanced(to_burn);
 
             // pay fees to collator
             <ToStakingPot as OnUnbalanced<_>>::on_unbalanced(collator);
@@ -1103,6 +1120,8 @@ parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::SDN;
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = 7200;
+    // Accept values between half and double the moving average.
+    pub const PriceMaxDeviation: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_price_aggregator::Config for Runtime {
@@ -1112,10 +1131,38 @@ impl pallet_price_aggregator::Config for Runtime {
     type NativeCurrencyId = NativeCurrencyId;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
+    type CalculateMovingAverage = pallet_price_aggregator::SimpleMovingAverage;
     type AggregationDuration = AggregationDuration;
+    type MaxDeviation = PriceMaxDeviation;
+    type MinSubmitters = ConstU32<1>;
+    // Price feed is stale if no value has been processed for one day.
+    type StalenessThreshold = AggregationDuration;
+    // Keep using the last moving average while the price feed is stale.
+    type StalePriceFallback = ();
+    // Any registered cross-chain asset can be tracked.
+    type SupportedCurrencies = RegisteredXcAssets<XcAssetConfig>;
+    type MaxTrackedCurrencies = ConstU32<8>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PriceAggregatorBenchmarkHelper;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PriceAggregatorBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_price_aggregator::BenchmarkHelper for PriceAggregatorBenchmarkHelper {
+    fn get_supported_currency_id(index: u32) -> CurrencyId {
+        let asset_id = AssetId::from(index);
+        pallet_xc_asset_config::AssetIdToLocation::<Runtime>::insert(
+            asset_id,
+            VersionedLocation::V4(XcmLocation::new(1, [xcm::v4::Junction::Parachain(index)])),
+        );
+
+        CurrencyId::XcAsset(asset_id)
+    }
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct OracleBenchmarkHelper;
 #[cfg(feature = "runtime-benchmarks")]
@@ -1132,12 +1179,14 @@ impl orml_oracle::BenchmarkHelper<CurrencyId, Price, ConstU32<2>> for OracleBenc
 parameter_types! {
     // Cannot specify `Root` so need to do it like this, unfortunately.
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
+    // Oracle values older than one hour are not combined, expressed in milliseconds.
+    pub const OracleValuesExpireIn: u64 = 60 * 60 * 1000;
 }
 
 impl orml_oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnNewData = PriceAggregator;
-    type CombineData = DummyCombineData<Runtime>;
+    type CombineData = MedianCombineData<Runtime, OracleValuesExpireIn>;
     type Time = Timestamp;
     type OracleKey = CurrencyId;
     type OracleValue = Price;
@@ -1312,6 +1361,10 @@ pub type Migrations = (
         TierThresholds,
         ThresholdVariationPercentage,
     >,
+    // dapp-staking minimum stakers tier requirement migration
+    pallet_dapp_staking_v3::migration::versioned_migrations::V8ToV9<Runtime>,
+    // price-aggregator multi-currency support & covered blocks migrations
+    pallet_price_aggregator::migration::versioned_migrations::V1ToV3<Runtime>,
     frame_support::migrations::RemovePallet<
         DmpQueuePalletName,
         <Runtime as frame_system::Config>::DbWeight,
@@ -1870,7 +1923,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
+    impl dapp_staking_v3_runtime_api::DappStakingApi<Block, AccountId, SmartContract<AccountId>> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
             InflationCycleConfig::periods_per_cycle()
         }
@@ -1890,6 +1943,99 @@ impl_runtime_apis! {
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn protocol_state() -> pallet_dapp_staking_v3::ProtocolState {
+            pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get()
+        }
+
+        fn account_ledger(account: AccountId) -> dapp_staking_v3_runtime_api::AccountLedgerInfo {
+            dapp_staking_v3_runtime_api::AccountLedgerInfo::new(
+                pallet_dapp_staking_v3::Ledger::<Runtime>::get(&account),
+                pallet_dapp_staking_v3::StakerPreferencesOf::<Runtime>::get(&account),
+            )
+        }
+
+        fn staker_info(
+            account: AccountId,
+            smart_contract: SmartContract<AccountId>,
+        ) -> Option<pallet_dapp_staking_v3::SingularStakingInfo> {
+            let smart_contract = DappStaking::resolve_smart_contract(smart_contract);
+            pallet_dapp_staking_v3::StakerInfo::<Runtime>::get(&account, &smart_contract)
+        }
+
+        fn contract_stake(
+            smart_contract: SmartContract<AccountId>,
+        ) -> Option<pallet_dapp_staking_v3::ContractStakeAmount> {
+            let smart_contract = DappStaking::resolve_smart_contract(smart_contract);
+            pallet_dapp_staking_v3::IntegratedDApps::<Runtime>::get(&smart_contract)
+                .map(|dapp_info| {
+                    pallet_dapp_staking_v3::ContractStake::<Runtime>::get(&dapp_info.id)
+                })
+        }
+
+        fn claimable_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::get_claimable_staker_rewards(&account)
+        }
+
+        fn claimable_staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)> {
+            DappStaking::get_claimable_staker_rewards_per_era(&account)
+        }
+
+        fn claimable_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::get_claimable_bonus_rewards(&account)
+        }
+
+        fn claimable_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            DappStaking::get_claimable_dapp_rewards(&smart_contract)
+        }
+
+        fn contract_staker_count(smart_contract: SmartContract<AccountId>) -> u32 {
+            DappStaking::get_contract_staker_count(&smart_contract)
+        }
+
+        fn dapp_period_archive(dapp_id: DAppId) -> Vec<dapp_staking_v3_runtime_api::DAppPeriodSummaryInfo> {
+            DappStaking::get_dapp_period_archive(dapp_id).into_iter().map(Into::into).collect()
+        }
+
+        fn simulate_dapp_tier_assignment(stake_deltas: Vec<(SmartContract<AccountId>, i128)>) -> dapp_staking_v3_runtime_api::DAppTierRewardsInfo {
+            DappStaking::simulate_dapp_tier_assignment(stake_deltas).into()
+        }
+
+        fn dapp_metadata(smart_contract: SmartContract<AccountId>) -> Option<dapp_staking_v3_runtime_api::DAppMetadataInfo> {
+            DappStaking::get_dapp_metadata(&smart_contract).map(Into::into)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn inflation_parameters() -> InflationParameters {
+            pallet_inflation::InflationParams::<Runtime>::get()
+        }
+
+        fn projected_inflation_configuration() -> pallet_inflation::InflationConfiguration {
+            Inflation::projected_inflation_configuration()
+        }
+
+        fn staker_apr(total_value_staked: Balance) -> Perquintill {
+            Inflation::staker_apr(total_value_staked)
+        }
+
+        fn cycle_issuance() -> pallet_inflation::CycleIssuance {
+            pallet_inflation::CurrentCycleIssuance::<Runtime>::get()
+        }
+    }
+
+    impl price_aggregator_runtime_api::PriceAggregatorApi<Block> for Runtime {
+        fn tracked_currencies() -> Vec<CurrencyId> {
+            PriceAggregator::tracked_currencies().collect()
+        }
+
+        fn aggregation_state(currency_id: CurrencyId) -> Option<pallet_price_aggregator::AggregationState> {
+            PriceAggregator::aggregation_state(currency_id)
+        }
     }
 
     impl xcm_fee_payment_runtime_api::XcmPaymentApi<Block> for Runtime {
@@ -0,0 +1,98 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Minimal contract which forwards its input to the dApp staking chain extension.
+//!
+//! Input is the `u16` chain extension function id, followed by the SCALE encoded function arguments.
+//! Output is `Ok((ret_val, output))`, where `ret_val` is the `u32` value returned by the chain extension,
+//! and `output` are the raw bytes written into the output buffer by the chain extension.
+//!
+//! Built with:
+//! ```sh
+//! rustc --target wasm32-unknown-unknown --crate-type cdylib -C opt-level=z -C panic=abort \
+//!     -C target-cpu=mvp -C link-arg=--import-memory -C link-arg=-zstack-size=16384 \
+//!     -C link-arg=--initial-memory=131072 -C link-arg=--max-memory=131072 \
+//!     dapp_staking_extension.rs -o dapp_staking_extension.wasm
+//! ```
+//!
+//! Afterwards, the `__heap_base` & `__data_end` global exports added by `rustc` and the custom sections are stripped,
+//! since `pallet-contracts` only accepts the `call` & `deploy` exports.
+
+#![no_std]
+#![no_main]
+
+/// Chain extension id of dApp staking, as registered in the runtimes.
+const EXTENSION_ID: u32 = 4;
+const BUFFER_SIZE: usize = 1024;
+
+static mut INPUT: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+static mut OUTPUT: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
+
+#[link(wasm_import_module = "seal0")]
+extern "C" {
+    fn input(buf_ptr: *mut u8, buf_len_ptr: *mut u32);
+    fn seal_return(flags: u32, data_ptr: *const u8, data_len: u32) -> !;
+    fn call_chain_extension(
+        id: u32,
+        input_ptr: *const u8,
+        input_len: u32,
+        output_ptr: *mut u8,
+        output_len_ptr: *mut u32,
+    ) -> u32;
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    core::arch::wasm32::unreachable()
+}
+
+#[no_mangle]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    unsafe {
+        let input_ptr = core::ptr::addr_of_mut!(INPUT) as *mut u8;
+        let output_ptr = core::ptr::addr_of_mut!(OUTPUT) as *mut u8;
+
+        let mut input_len = BUFFER_SIZE as u32;
+        input(input_ptr, &mut input_len);
+        if input_len < 2 {
+            core::arch::wasm32::unreachable();
+        }
+        let func_id = u32::from(*input_ptr) | (u32::from(*input_ptr.add(1)) << 8);
+
+        // Output layout: `Ok` variant index, `u32` return value, chain extension output.
+        let mut output_len = (BUFFER_SIZE - 5) as u32;
+        let ret_val = call_chain_extension(
+            (EXTENSION_ID << 16) | func_id,
+            input_ptr.add(2),
+            input_len - 2,
+            output_ptr.add(5),
+            &mut output_len,
+        );
+
+        *output_ptr = 0;
+        let ret_val = ret_val.to_le_bytes();
+        for (idx, byte) in ret_val.iter().enumerate() {
+            *output_ptr.add(1 + idx) = *byte;
+        }
+
+        seal_return(0, output_ptr, 5 + output_len);
+    }
+}
@@ -0,0 +1,279 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use dapp_staking_chain_extension_types::{
+    Command, Outcome, ProtocolState as ProtocolStateInfo, Subperiod as SubperiodInfo,
+};
+use pallet_dapp_staking_v3::{ActiveProtocolState, Subperiod};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::DispatchError;
+
+const DAPP_STAKING_CE: &'static str = "dapp_staking_extension";
+
+/// Calls the dApp staking chain extension command via the proxy contract.
+///
+/// Returns the outcome, and the decoded output written by the chain extension.
+fn call_extension<V: Decode>(
+    contract_id: &AccountId32,
+    command: Command,
+    input: impl Encode,
+) -> (u32, V) {
+    call_wasm_contract_method::<(u32, V)>(
+        ALICE,
+        contract_id.clone(),
+        [u16::from(command).encode(), input.encode()].concat(),
+    )
+}
+
+/// Deploys the proxy contract, funds it, and registers it as a dApp.
+fn deploy_and_register_proxy_contract(funds: Balance) -> AccountId32 {
+    let contract_id = deploy_wasm_contract(DAPP_STAKING_CE);
+    assert_ok!(Balances::transfer_allow_death(
+        RuntimeOrigin::signed(ALICE),
+        contract_id.clone().into(),
+        funds,
+    ));
+    assert_ok!(DappStaking::register(
+        RuntimeOrigin::root(),
+        ALICE.clone(),
+        <Runtime as pallet_dapp_staking_v3::Config>::SmartContract::wasm(contract_id.clone()),
+    ));
+
+    contract_id
+}
+
+#[test]
+fn lock_stake_unstake_and_unlock_works() {
+    new_test_ext().execute_with(|| {
+        let lock_amount =
+            2 * <Runtime as pallet_dapp_staking_v3::Config>::MinimumLockedAmount::get();
+        let stake_amount = <Runtime as pallet_dapp_staking_v3::Config>::MinimumStakeAmount::get();
+        let contract_id = deploy_and_register_proxy_contract(lock_amount + 10 * UNIT);
+
+        // Lock
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Lock, lock_amount),
+            (Outcome::Success as u32, ())
+        );
+        assert_eq!(
+            call_extension::<Balance>(&contract_id, Command::LockedAmount, contract_id.clone()),
+            (Outcome::Success as u32, lock_amount)
+        );
+
+        // Stake on the proxy contract itself
+        assert_eq!(
+            call_extension::<()>(
+                &contract_id,
+                Command::Stake,
+                (contract_id.clone(), stake_amount)
+            ),
+            (Outcome::Success as u32, ())
+        );
+        assert_eq!(
+            call_extension::<Balance>(&contract_id, Command::StakedAmount, contract_id.clone()),
+            (Outcome::Success as u32, stake_amount)
+        );
+        assert_eq!(
+            call_extension::<Balance>(
+                &contract_id,
+                Command::StakedAmountOnContract,
+                (contract_id.clone(), contract_id.clone())
+            ),
+            (Outcome::Success as u32, stake_amount)
+        );
+        assert_eq!(
+            call_extension::<Balance>(&contract_id, Command::ContractStake, contract_id.clone()),
+            (Outcome::Success as u32, stake_amount)
+        );
+
+        // Unstake everything
+        assert_eq!(
+            call_extension::<()>(
+                &contract_id,
+                Command::Unstake,
+                (contract_id.clone(), stake_amount)
+            ),
+            (Outcome::Success as u32, ())
+        );
+        assert_eq!(
+            call_extension::<Balance>(
+                &contract_id,
+                Command::StakedAmountOnContract,
+                (contract_id.clone(), contract_id.clone())
+            ),
+            (Outcome::Success as u32, 0)
+        );
+        assert_eq!(
+            call_extension::<Balance>(&contract_id, Command::ContractStake, contract_id.clone()),
+            (Outcome::Success as u32, 0)
+        );
+
+        // Unlock everything
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Unlock, lock_amount),
+            (Outcome::Success as u32, ())
+        );
+        assert_eq!(
+            call_extension::<Balance>(&contract_id, Command::LockedAmount, contract_id.clone()),
+            (Outcome::Success as u32, 0)
+        );
+    });
+}
+
+#[test]
+fn stake_views_resolve_contract_alias() {
+    new_test_ext().execute_with(|| {
+        let lock_amount = <Runtime as pallet_dapp_staking_v3::Config>::MinimumLockedAmount::get();
+        let stake_amount = <Runtime as pallet_dapp_staking_v3::Config>::MinimumStakeAmount::get();
+        let contract_id = deploy_and_register_proxy_contract(lock_amount + 10 * UNIT);
+
+        // Add an alias to the proxy contract
+        let alias = AccountId32::new([7_u8; 32]);
+        assert_ok!(DappStaking::add_contract_alias(
+            RuntimeOrigin::root(),
+            <Runtime as pallet_dapp_staking_v3::Config>::SmartContract::wasm(contract_id.clone()),
+            <Runtime as pallet_dapp_staking_v3::Config>::SmartContract::wasm(alias.clone()),
+        ));
+
+        // Stake on the registered contract
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Lock, lock_amount),
+            (Outcome::Success as u32, ())
+        );
+        assert_eq!(
+            call_extension::<()>(
+                &contract_id,
+                Command::Stake,
+                (contract_id.clone(), stake_amount)
+            ),
+            (Outcome::Success as u32, ())
+        );
+
+        // Stake is visible when queried via the alias
+        assert_eq!(
+            call_extension::<Balance>(
+                &contract_id,
+                Command::StakedAmountOnContract,
+                (contract_id.clone(), alias.clone())
+            ),
+            (Outcome::Success as u32, stake_amount)
+        );
+        assert_eq!(
+            call_extension::<Balance>(&contract_id, Command::ContractStake, alias),
+            (Outcome::Success as u32, stake_amount)
+        );
+    });
+}
+
+#[test]
+fn protocol_state_works() {
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_and_register_proxy_contract(10 * UNIT);
+
+        let protocol_state = ActiveProtocolState::<Runtime>::get();
+        let subperiod = match protocol_state.subperiod() {
+            Subperiod::Voting => SubperiodInfo::Voting,
+            Subperiod::BuildAndEarn => SubperiodInfo::BuildAndEarn,
+        };
+        assert_eq!(
+            call_extension::<ProtocolStateInfo>(&contract_id, Command::ProtocolState, ()),
+            (
+                Outcome::Success as u32,
+                ProtocolStateInfo {
+                    era: protocol_state.era,
+                    period: protocol_state.period_number(),
+                    subperiod,
+                }
+            )
+        );
+    });
+}
+
+#[test]
+fn errors_are_mapped_to_outcome() {
+    new_test_ext().execute_with(|| {
+        let lock_amount = <Runtime as pallet_dapp_staking_v3::Config>::MinimumLockedAmount::get();
+        let contract_id = deploy_and_register_proxy_contract(lock_amount + 10 * UNIT);
+
+        // Zero amount
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Lock, 0 as Balance),
+            (Outcome::ZeroAmount as u32, ())
+        );
+
+        // Unregistered contract
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Lock, lock_amount),
+            (Outcome::Success as u32, ())
+        );
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Stake, (BOB, lock_amount)),
+            (Outcome::ContractNotFound as u32, ())
+        );
+
+        // Nothing staked on the contract
+        assert_eq!(
+            call_extension::<()>(
+                &contract_id,
+                Command::Unstake,
+                (contract_id.clone(), lock_amount)
+            ),
+            (Outcome::NoStakingInfo as u32, ())
+        );
+
+        // Nothing to claim
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::ClaimStakerRewards, ()),
+            (Outcome::NoClaimableRewards as u32, ())
+        );
+
+        // Maintenance mode
+        assert_ok!(DappStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert_eq!(
+            call_extension::<()>(&contract_id, Command::Unlock, lock_amount),
+            (Outcome::Disabled as u32, ())
+        );
+    });
+}
+
+#[test]
+fn later_pallet_errors_are_mapped_to_outcome() {
+    use pallet_dapp_staking_v3::Error;
+
+    for (error, outcome) in [
+        (
+            Error::<Runtime>::SameSourceAndDestination,
+            Outcome::SameSourceAndDestination,
+        ),
+        (
+            Error::<Runtime>::TooManyContractAliases,
+            Outcome::TooManyContractAliases,
+        ),
+        (
+            Error::<Runtime>::ContractAliasNotFound,
+            Outcome::ContractAliasNotFound,
+        ),
+        (
+            Error::<Runtime>::ClaimBountyTooLarge,
+            Outcome::ClaimBountyTooLarge,
+        ),
+    ] {
+        assert_eq!(Outcome::from(DispatchError::from(error)), outcome);
+    }
+}
@@ -20,6 +20,8 @@
 
 use crate::setup::*;
 
+use dapp_staking_v3_runtime_api::runtime_decl_for_dapp_staking_api::DappStakingApi;
+use frame_support::BoundedVec;
 use pallet_collator_selection::{CandidateInfo, Candidates};
 use pallet_dapp_staking_v3::*;
 
@@ -124,3 +126,68 @@ fn collator_selection_candidacy_not_possible_for_dapp_staking_participant() {
         );
     });
 }
+
+#[test]
+fn runtime_api_queries_resolve_contract_alias() {
+    new_test_ext().execute_with(|| {
+        // Register smart contract with an alias & set its metadata
+        let smart_contract = <Runtime as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            AccountId32::new([11_u8; 32]),
+        );
+        let alias = <Runtime as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            AccountId32::new([12_u8; 32]),
+        );
+        assert_ok!(DappStaking::register(
+            RuntimeOrigin::root(),
+            ALICE.clone(),
+            smart_contract.clone()
+        ));
+        assert_ok!(DappStaking::add_contract_alias(
+            RuntimeOrigin::root(),
+            smart_contract.clone(),
+            alias.clone()
+        ));
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(ALICE.clone()),
+            smart_contract.clone(),
+            BoundedVec::truncate_from(b"Astar dApp".to_vec()),
+            BoundedVec::truncate_from(b"https://astar.network".to_vec()),
+            BoundedVec::truncate_from(b"cid".to_vec()),
+            DAppCategory::Defi,
+        ));
+
+        // Lock & stake on the registered smart contract, and generate some rewards
+        let amount = 600 * UNIT;
+        assert_ok!(DappStaking::lock(RuntimeOrigin::signed(BOB), amount));
+        assert_ok!(DappStaking::stake(
+            RuntimeOrigin::signed(BOB),
+            smart_contract.clone(),
+            amount,
+        ));
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_to_block(System::block_number() + 1);
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_to_block(System::block_number() + 1);
+
+        // Querying via the alias must return the same info as querying the registered smart contract
+        let staker_info = Runtime::staker_info(BOB, smart_contract.clone());
+        assert!(staker_info.is_some(), "Sanity check.");
+        assert_eq!(Runtime::staker_info(BOB, alias.clone()), staker_info);
+
+        let contract_stake = Runtime::contract_stake(smart_contract.clone());
+        assert!(contract_stake.is_some(), "Sanity check.");
+        assert_eq!(Runtime::contract_stake(alias.clone()), contract_stake);
+
+        assert_eq!(Runtime::contract_staker_count(smart_contract.clone()), 1);
+        assert_eq!(Runtime::contract_staker_count(alias.clone()), 1);
+
+        assert_eq!(
+            Runtime::claimable_dapp_rewards(alias.clone()),
+            Runtime::claimable_dapp_rewards(smart_contract.clone())
+        );
+
+        let metadata = Runtime::dapp_metadata(smart_contract);
+        assert!(metadata.is_some(), "Sanity check.");
+        assert_eq!(Runtime::dapp_metadata(alias), metadata);
+    });
+}
@@ -41,6 +41,9 @@ mod dapp_staking_v3;
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod assets_chain_extensions;
 
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod dapp_staking_chain_extension;
+
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod oracle;
 
@@ -30,7 +30,10 @@ fn price_submission_works() {
 
         // 0. Need to set limit block to something sensible, otherwise we'll waste time on many redundant iterations
         let limit_block = 10;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         // 1. Submit a price for a valid asset - the native currency
         let price_1 = Price::from_rational(15, 100);
@@ -50,7 +53,7 @@ fn price_submission_works() {
         run_for_blocks(1);
         let expected_average = (price_1 + price_2) * Price::from_rational(1, 2);
         assert_eq!(
-            IntermediateValueAggregator::<Runtime>::get().average(),
+            IntermediateValueAggregator::<Runtime>::get(native_currency_id).average(),
             expected_average
         );
 
@@ -82,9 +85,65 @@ fn price_submission_works() {
 
         // 5. Run until next limit block without any transactions, don't expect any changes
         let limit_block = limit_block * 2;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         run_to_block(limit_block + 1);
         assert_eq!(PriceAggregator::average_price(), expected_moving_average);
     })
 }
+
+#[test]
+fn combined_price_works() {
+    new_test_ext().execute_with(|| {
+        let native_currency_id =
+            <Runtime as pallet_price_aggregator::Config>::NativeCurrencyId::get();
+        assert!(Oracle::get(&native_currency_id).is_none(), "Sanity check");
+
+        // 1. Single fresh value isn't enough, majority of the oracle members must provide a value
+        let price_1 = Price::from_rational(15, 100);
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(ALICE.clone()),
+            vec![(native_currency_id, price_1)].try_into().unwrap()
+        ));
+        assert!(Oracle::get(&native_currency_id).is_none());
+
+        // 2. For an even number of fresh values, the average of the two middle values is used
+        let price_2 = Price::from_rational(17, 100);
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(BOB.clone()),
+            vec![(native_currency_id, price_2)].try_into().unwrap()
+        ));
+        let combined_price = (price_1 + price_2) * Price::from_rational(1, 2);
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|combined| combined.value),
+            Some(combined_price)
+        );
+
+        // 3. Expired values are ignored when combining, previous combined value is kept until there's a quorum again
+        run_for_blocks(1);
+        pallet_timestamp::Now::<Runtime>::mutate(|now| *now += OracleValuesExpireIn::get());
+
+        let price_3 = Price::from_rational(20, 100);
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(ALICE.clone()),
+            vec![(native_currency_id, price_3)].try_into().unwrap()
+        ));
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|combined| combined.value),
+            Some(combined_price)
+        );
+
+        let price_4 = Price::from_rational(22, 100);
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(BOB.clone()),
+            vec![(native_currency_id, price_4)].try_into().unwrap()
+        ));
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|combined| combined.value),
+            Some((price_3 + price_4) * Price::from_rational(1, 2))
+        );
+    })
+}
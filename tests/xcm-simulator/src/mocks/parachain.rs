@@ -62,7 +62,9 @@ use orml_xcm_support::DisabledParachainFee;
 use xcm_executor::{traits::JustTry, XcmExecutor};
 
 use astar_primitives::{
-    dapp_staking::{AccountCheck, CycleConfiguration, SmartContract, StakingRewardHandler},
+    dapp_staking::{
+        AccountCheck, CycleConfiguration, RewardKind, SmartContract, StakingRewardHandler,
+    },
     oracle::PriceProvider,
     xcm::{
         AllowTopLevelPaidExecutionFrom, AssetLocationIdConverter, FixedRateOfForeignAsset,
@@ -667,7 +669,7 @@ impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
         Balance::from(3_000_000_u128)
     }
 
-    fn payout_reward(_: &AccountId, _: Balance) -> Result<(), ()> {
+    fn payout_reward(_: &AccountId, _: Balance, _: RewardKind) -> Result<(), ()> {
         Ok(())
     }
 }
@@ -700,11 +702,13 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<MockSmartContract, AccountId>
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const DappStakingMaxClaimBountyRate: Perbill = Perbill::from_percent(1);
 }
 
 impl pallet_dapp_staking_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = MockSmartContract;
     type ContractRegisterOrigin = frame_system::EnsureRoot<AccountId>;
@@ -727,6 +731,15 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type TierAssignment = pallet_dapp_staking_v3::StandardTierAssignment;
+    type MaxClaimBountyRate = DappStakingMaxClaimBountyRate;
+    type MaxClaimBounty = ConstU128<1_000_000_000_000_000_000>;
+    type MaxArchivedPeriods = ConstU32<8>;
+    type MetadataDepositBase = ConstU128<10>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type MaxMetadataFieldLength = ConstU32<32>;
+    type MaxIpfsCidLength = ConstU32<64>;
+    type MaxContractAliases = ConstU32<4>;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;